@@ -1,9 +1,8 @@
-use std::mem::MaybeUninit;
-
 use cac_gl_bindings::gl43 as gl;
+use cac_gl_bindings::safe;
 use glfw::{Action, Context, Key};
 
-fn create_vertex_buffer(gl: &gl::Api) -> gl::Buffer {
+fn create_vertex_buffer(context: &safe::Context) -> safe::Buffer {
     #[rustfmt::skip]
     let triangle_data = [
         -0.5_f32,  0.5,    //TL
@@ -24,25 +23,13 @@ fn create_vertex_buffer(gl: &gl::Api) -> gl::Buffer {
         .flat_map(|(pos, color)| [pos[0], pos[1], color[0], color[1], color[2]])
         .collect();
 
-    unsafe {
-        let mut buffer = MaybeUninit::zeroed();
-        gl.gen_buffers(2, buffer.as_mut_ptr());
-
-        let vertex_buffer = buffer.assume_init();
-
-        gl.bind_buffer(gl::BufferTarget::ARRAY_BUFFER, vertex_buffer);
-
-        gl.buffer_data(
-            gl::BufferTarget::ARRAY_BUFFER,
-            (vertex_data.len() * std::mem::size_of::<f32>())
-                .try_into()
-                .expect("we won't have more than 15 floats"),
-            vertex_data.as_ptr().cast(),
-            gl::BufferUsage::STATIC_DRAW,
-        );
-
-        vertex_buffer
-    }
+    let vertex_buffer = safe::Buffer::new(context);
+    vertex_buffer.data(
+        gl::BufferTarget::ARRAY_BUFFER,
+        &vertex_data,
+        gl::BufferUsage::STATIC_DRAW,
+    );
+    vertex_buffer
 }
 
 fn main() -> Result<(), Box<dyn std::error::Error>> {
@@ -70,78 +57,32 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
     window.make_current();
     window.set_key_polling(true);
 
-    let gl = unsafe { gl::Api::with_loader(&|s| glfw.get_proc_address_raw(s))? };
-    unsafe {
-        gl.enable(gl::Capability::DEBUG_OUTPUT);
-        gl.debug_message_callback(Some(debug_message_callback), std::ptr::null_mut());
-    }
-    let vao = unsafe {
-        let mut vao = std::mem::MaybeUninit::zeroed();
-        gl.gen_vertex_arrays(1, vao.as_mut_ptr());
-        vao.assume_init()
-    };
-
-    unsafe {
-        gl.clear_color(0.2, 0.2, 0.2, 1.0);
-    }
-
-    let vertex_buffer = create_vertex_buffer(&gl);
-
-    unsafe {
-        //vertex attributes
-        gl.bind_vertex_array(vao);
-        gl.enable_vertex_attrib_array(0);
-        gl.vertex_attrib_pointer(
-            0,
-            2,
-            gl::VertexAttributeKind::FLOAT,
-            0,
-            (std::mem::size_of::<f32>() * 5)
-                .try_into()
-                .expect("we won't have more than 20 floats"),
-            std::ptr::null(),
-        );
-
-        gl.enable_vertex_attrib_array(1);
-        gl.vertex_attrib_pointer(
-            1,
-            3,
-            gl::VertexAttributeKind::FLOAT,
-            0,
-            (std::mem::size_of::<f32>() * 5)
-                .try_into()
-                .expect("we only have 5 floats"),
-            (std::mem::size_of::<f32>() * 2) as _,
-        );
-    };
-
-    let program = unsafe {
-        let program = gl.create_program();
-
-        let vs = gl.create_shader(gl::ShaderKind::VERTEX);
-        let fs = gl.create_shader(gl::ShaderKind::FRAGMENT);
-
-        gl.shader_source(vs, 1, [VS_SOURCE].as_ptr().cast(), std::ptr::null());
-        gl.shader_source(fs, 1, [FS_SOURCE].as_ptr().cast(), std::ptr::null());
+    // the context stays current on this thread until the window is dropped at the end of `main`,
+    // after all objects are gone
+    let context =
+        unsafe { safe::Context::new(gl::Api::with_loader(&|s| glfw.get_proc_address_raw(s))?) };
 
-        gl.compile_shader(vs);
-        gl.compile_shader(fs);
+    let _debug = unsafe { context.api().log_debug_messages() };
 
-        gl.attach_shader(program, vs);
-        gl.attach_shader(program, fs);
+    context.clear_color(0.2, 0.2, 0.2, 1.0);
 
-        gl.link_program(program);
+    let vertex_buffer = create_vertex_buffer(&context);
 
-        gl.detach_shader(program, vs);
-        gl.detach_shader(program, fs);
+    //vertex attributes
+    let vertex_array = safe::VertexArray::new(&context);
+    vertex_array.attach(
+        &vertex_buffer,
+        &safe::VertexLayout::new()
+            .float(0, 2, gl::VertexAttributeKind::FLOAT)
+            .float(1, 3, gl::VertexAttributeKind::FLOAT),
+    );
 
-        gl.use_program(program);
-
-        gl.delete_shader(vs);
-        gl.delete_shader(fs);
-
-        program
+    let program = {
+        let vs = safe::Shader::new(&context, gl::ShaderKind::VERTEX, VS_SOURCE)?;
+        let fs = safe::Shader::new(&context, gl::ShaderKind::FRAGMENT, FS_SOURCE)?;
+        safe::Program::new(&context, &[&vs, &fs])?
     };
+    program.bind();
 
     // Loop until the user closes the window
     while !window.should_close() {
@@ -153,45 +94,16 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
             }
         }
 
-        unsafe {
-            gl.clear(gl::ClearMask::COLOR);
-            gl.draw_arrays(gl::Primitive::TRIANGLE_STRIP, 0, 3);
-        }
+        context.clear(gl::ClearMask::COLOR);
+        vertex_array.draw_arrays_instanced(gl::Primitive::TRIANGLE_STRIP, 0, 3, 1)?;
 
         // Swap front and back buffers
         window.swap_buffers();
     }
-    unsafe {
-        gl.delete_program(program);
-        gl.delete_buffer(vertex_buffer);
-    }
 
     Ok(())
 }
 
-extern "system" fn debug_message_callback(
-    source: gl::DebugSource,
-    kind: gl::DebugType,
-    id: gl::GLuint,
-    severity: gl::DebugSeverity,
-    _length: gl::GLsizei,
-    message: *const gl::GLchar,
-    _user_param: *mut std::ffi::c_void,
-) {
-    let error_message = unsafe {
-        std::ffi::CStr::from_ptr(message.cast())
-            .to_str()
-            .unwrap_or("[FAILED TO READ GL ERROR MESSAGE]")
-    };
-
-    match severity {
-        gl::DebugSeverity::HIGH => log::error!("{id}: {kind} from {source}: {error_message}"),
-        gl::DebugSeverity::MEDIUM => log::warn!("{id}: {kind} from {source}: {error_message}"),
-        gl::DebugSeverity::LOW => log::info!("{id}: {kind} from {source}: {error_message}"),
-        _ => log::trace!("{id}: {kind} from {source}: {error_message}"),
-    }
-}
-
 const VS_SOURCE: &str = "#version 330
 
 layout(location = 0) in vec2 pos;
@@ -202,7 +114,7 @@ out vec3 vec_color;
 void main() {
     vec_color = color;
     gl_Position = vec4(pos.x, pos.y, 0.0, 1.0);
-}\0";
+}";
 
 const FS_SOURCE: &str = "#version 330 
 precision mediump float;
@@ -212,4 +124,4 @@ in vec3 vec_color;
 out vec4 color; 
 void main() {
     color = vec4(vec_color, 1.0);
-}\0";
+}";
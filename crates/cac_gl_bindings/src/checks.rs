@@ -0,0 +1,122 @@
+// Hand-written helpers on top of the generated wrappers. This is included by the lowest module of
+// every family (`gl33`, `gles30`), the higher ones reach it through `Deref`.
+
+use crate::ShaderCompileError;
+
+/// There is one flag per kind of error, a driver that keeps returning errors after that many is
+/// stuck, e.g. on `CONTEXT_LOST`.
+const MAX_ERROR_FLAGS: usize = 16;
+
+impl Api {
+    /// The info log of `shader`, usually empty after a successful compile.
+    ///
+    /// # Safety
+    /// See the safety note in `with_loader`
+    #[must_use]
+    pub unsafe fn shader_info_log(&self, shader: Shader) -> String {
+        unsafe {
+            let mut length = 0;
+            self.get_shaderiv(shader, ShaderParameter::INFO_LOG_LENGTH, &raw mut length);
+            read_log(length, |size, written, log| {
+                self.get_shader_info_log(shader, size, written, log);
+            })
+        }
+    }
+
+    /// The info log of `program`, usually empty after a successful link.
+    ///
+    /// # Safety
+    /// See the safety note in `with_loader`
+    #[must_use]
+    pub unsafe fn program_info_log(&self, program: Program) -> String {
+        unsafe {
+            let mut length = 0;
+            self.get_programiv(program, ProgramParameter::INFO_LOG_LENGTH, &raw mut length);
+            read_log(length, |size, written, log| {
+                self.get_program_info_log(program, size, written, log);
+            })
+        }
+    }
+
+    /// Checks the outcome of the last `compile_shader` of `shader`.
+    ///
+    /// # Errors
+    /// `ShaderCompileError` with the parsed info log if the compilation failed.
+    ///
+    /// # Safety
+    /// See the safety note in `with_loader`
+    pub unsafe fn check_compile_status(&self, shader: Shader) -> Result<(), ShaderCompileError> {
+        unsafe {
+            let mut status = 0;
+            self.get_shaderiv(shader, ShaderParameter::COMPILE_STATUS, &raw mut status);
+            if status == 0 {
+                return Err(ShaderCompileError::from_log(self.shader_info_log(shader)));
+            }
+        }
+        Ok(())
+    }
+
+    /// Checks the outcome of the last `link_program` of `program`.
+    ///
+    /// # Errors
+    /// `ShaderCompileError` with the parsed info log if linking failed.
+    ///
+    /// # Safety
+    /// See the safety note in `with_loader`
+    pub unsafe fn check_link_status(&self, program: Program) -> Result<(), ShaderCompileError> {
+        unsafe {
+            let mut status = 0;
+            self.get_programiv(program, ProgramParameter::LINK_STATUS, &raw mut status);
+            if status == 0 {
+                return Err(ShaderCompileError::from_log(self.program_info_log(program)));
+            }
+        }
+        Ok(())
+    }
+
+    /// Clears every recorded error flag, e.g. once per frame or after loading resources.
+    ///
+    /// # Errors
+    /// `Error::Gl` with the cleared flags if any were set.
+    ///
+    /// # Safety
+    /// See the safety note in `with_loader`
+    pub unsafe fn check_errors(&self) -> Result<(), Error> {
+        let errors: Vec<_> = std::iter::from_fn(|| unsafe { self.get_error() }.error())
+            .take(MAX_ERROR_FLAGS)
+            .collect();
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(Error::Gl(errors))
+        }
+    }
+
+    /// Checks that the framebuffer bound to `target` can be rendered to.
+    ///
+    /// # Errors
+    /// `Error::IncompleteFramebuffer` with the reason otherwise.
+    ///
+    /// # Safety
+    /// See the safety note in `with_loader`
+    pub unsafe fn check_framebuffer_complete(&self, target: FramebufferTarget) -> Result<(), Error> {
+        let status = unsafe { self.check_framebuffer_status(target) };
+        if status != FramebufferStatus::COMPLETE {
+            return Err(Error::IncompleteFramebuffer(status.into()));
+        }
+        Ok(())
+    }
+}
+
+/// Reads an info log of `length` bytes including the nul terminator through `get`.
+fn read_log(length: GLint, get: impl FnOnce(GLsizei, *mut GLsizei, *mut GLchar)) -> String {
+    let mut log = vec![0u8; usize::try_from(length).unwrap_or_default()];
+    if log.is_empty() {
+        return String::new();
+    }
+
+    let mut written = 0;
+    get(length, &raw mut written, log.as_mut_ptr().cast());
+    log.truncate(usize::try_from(written).unwrap_or_default());
+    String::from_utf8_lossy(&log).into_owned()
+}
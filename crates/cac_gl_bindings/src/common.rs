@@ -0,0 +1,295 @@
+// Hand-written additions to the generated handles and enums. This is included by every family
+// module (`gl`, `gles`), as the newtypes are generated once per family.
+
+use std::fmt::Display;
+
+impl ClearMask {
+    pub const NONE: Self = Self(0);
+    //TODO: replace with const impl of bitor once it stabilizes
+    pub const ALL: Self = Self(Self::COLOR.0 | Self::DEPTH.0 | Self::STENCIL.0);
+}
+
+impl MapAccess {
+    /// Whether all bits of `other` are set.
+    #[must_use]
+    pub const fn contains(self, other: Self) -> bool {
+        self.0 & other.0 == other.0
+    }
+}
+
+impl TextureUnit {
+    /// The unit `TEXTURE0 + index`, the registry only names the first 32 of them.
+    #[must_use]
+    pub const fn nth(index: GLuint) -> Self {
+        Self(Self::TEXTURE0.0 + index)
+    }
+}
+
+// texture parameter values are passed through `tex_parameteri`, every value fits into a GLint
+#[allow(clippy::cast_possible_wrap)]
+impl From<TextureFilter> for GLint {
+    fn from(filter: TextureFilter) -> Self {
+        filter.0 as Self
+    }
+}
+
+#[allow(clippy::cast_possible_wrap)]
+impl From<TextureWrap> for GLint {
+    fn from(wrap: TextureWrap) -> Self {
+        wrap.0 as Self
+    }
+}
+
+impl Attachment {
+    /// The attachment `COLOR_ATTACHMENT0 + index`.
+    #[must_use]
+    pub const fn color(index: GLuint) -> Self {
+        Self(Self::COLOR0.0 + index)
+    }
+}
+
+impl ColorBuffer {
+    /// The buffer `COLOR_ATTACHMENT0 + index` of the bound framebuffer object.
+    #[must_use]
+    pub const fn color_attachment(index: GLuint) -> Self {
+        Self(Self::COLOR_ATTACHMENT0.0 + index)
+    }
+}
+
+impl ErrorCode {
+    /// The family independent error, `None` for `NO_ERROR`.
+    #[must_use]
+    pub const fn error(self) -> Option<crate::GlError> {
+        crate::GlError::from_code(self.0)
+    }
+}
+
+impl FramebufferStatus {
+    /// The name of a raw status, for the `Display` of `Error::IncompleteFramebuffer`.
+    pub(crate) const fn name_of(status: GLenum) -> Option<&'static str> {
+        Self(status).name()
+    }
+}
+
+/// For comparing with `Error::IncompleteFramebuffer`.
+impl From<FramebufferStatus> for GLenum {
+    fn from(status: FramebufferStatus) -> Self {
+        status.0
+    }
+}
+
+/// A handle to an object `label_object` can name.
+pub trait Object: Copy {
+    const IDENTIFIER: ObjectIdentifier;
+
+    /// The raw object name, as passed to `object_label`.
+    fn name(self) -> GLuint;
+}
+
+macro_rules! objects {
+    ($($handle:ident => $identifier:ident),* $(,)?) => {$(
+        impl Object for $handle {
+            const IDENTIFIER: ObjectIdentifier = ObjectIdentifier::$identifier;
+
+            fn name(self) -> GLuint {
+                self.0
+            }
+        }
+    )*};
+}
+
+objects!(
+    Shader => SHADER,
+    Program => PROGRAM,
+    VertexArray => VERTEX_ARRAY,
+    Buffer => BUFFER,
+    Texture => TEXTURE,
+    Framebuffer => FRAMEBUFFER,
+    Renderbuffer => RENDERBUFFER,
+);
+
+/// The location of a uniform in a program, as returned by `get_uniform_location`.
+#[repr(transparent)]
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub struct UniformLocation(GLint);
+impl UniformLocation {
+    /// Returned for names that aren't active uniforms, setting it is silently ignored.
+    pub const NONE: Self = Self(-1);
+
+    #[must_use]
+    pub const fn is_none(self) -> bool {
+        self.0 == Self::NONE.0
+    }
+}
+
+/// One draw of `draw_arrays_indirect`, laid out as GL reads it from the `DRAW_INDIRECT_BUFFER`.
+/// OpenGL ES has no base instance, `base_instance` must be `0` there.
+#[repr(C)]
+#[derive(Copy, Clone, Eq, PartialEq, Debug, Default)]
+pub struct DrawArraysIndirectCommand {
+    pub count: GLuint,
+    pub instance_count: GLuint,
+    pub first: GLuint,
+    pub base_instance: GLuint,
+}
+
+/// One draw of `draw_elements_indirect`, laid out as GL reads it from the `DRAW_INDIRECT_BUFFER`.
+/// OpenGL ES has no base instance, `base_instance` must be `0` there.
+#[repr(C)]
+#[derive(Copy, Clone, Eq, PartialEq, Debug, Default)]
+pub struct DrawElementsIndirectCommand {
+    pub count: GLuint,
+    pub instance_count: GLuint,
+    pub first_index: GLuint,
+    pub base_vertex: GLint,
+    pub base_instance: GLuint,
+}
+
+/// The work group counts of `dispatch_compute_indirect`, read from the
+/// `DISPATCH_INDIRECT_BUFFER`.
+#[repr(C)]
+#[derive(Copy, Clone, Eq, PartialEq, Debug, Default)]
+pub struct DispatchIndirectCommand {
+    pub num_groups_x: GLuint,
+    pub num_groups_y: GLuint,
+    pub num_groups_z: GLuint,
+}
+
+/// The work group limits of compute shaders, see `compute_limits`.
+#[derive(Copy, Clone, Eq, PartialEq, Debug, Default)]
+pub struct ComputeLimits {
+    /// Work groups per `dispatch_compute` along x, y and z.
+    pub work_group_count: [GLint; 3],
+    /// The `local_size` of a work group along x, y and z.
+    pub work_group_size: [GLint; 3],
+    /// Invocations per work group, the product of its `local_size`.
+    pub work_group_invocations: GLint,
+    /// Bytes of `shared` variables per work group.
+    pub shared_memory_size: GLint,
+}
+
+pub type DebugMessageCallback = extern "system" fn(
+    source: DebugSource,
+    kind: DebugType,
+    id: GLuint,
+    severity: DebugSeverity,
+    length: GLsizei,
+    message: *const GLchar,
+    user_param: *mut std::ffi::c_void,
+);
+
+/// One message of the debug output, as passed to the closure of `set_debug_handler`.
+#[derive(Copy, Clone, Debug)]
+pub struct DebugMessage<'a> {
+    pub source: DebugSource,
+    pub kind: DebugType,
+    /// Identifies the message together with `source` and `kind`, the values are up to the driver.
+    pub id: GLuint,
+    pub severity: DebugSeverity,
+    pub text: &'a str,
+}
+
+impl DebugMessage<'_> {
+    /// Logs the message, `HIGH` severity as an error down to notifications at `trace` level.
+    /// This is the handler installed by `log_debug_messages`.
+    pub fn log(&self) {
+        let level = match self.severity {
+            DebugSeverity::HIGH => log::Level::Error,
+            DebugSeverity::MEDIUM => log::Level::Warn,
+            DebugSeverity::LOW => log::Level::Info,
+            _ => log::Level::Trace,
+        };
+        log::log!(level, "{self}");
+    }
+}
+
+/// A `DebugMessage` that owns its text, as drained from the log by `debug_message_log`.
+#[derive(Clone, Debug)]
+pub struct OwnedDebugMessage {
+    pub source: DebugSource,
+    pub kind: DebugType,
+    pub id: GLuint,
+    pub severity: DebugSeverity,
+    pub text: String,
+}
+
+impl OwnedDebugMessage {
+    #[must_use]
+    pub fn as_message(&self) -> DebugMessage<'_> {
+        DebugMessage {
+            source: self.source,
+            kind: self.kind,
+            id: self.id,
+            severity: self.severity,
+            text: &self.text,
+        }
+    }
+}
+
+impl Display for OwnedDebugMessage {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_message().fmt(f)
+    }
+}
+
+impl Display for DebugMessage<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}: {} from {}: {}",
+            self.id, self.kind, self.source, self.text
+        )
+    }
+}
+
+impl Display for FramebufferStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if let Some(name) = self.name() {
+            return write!(f, "{name}");
+        }
+        write!(f, "{:#06X}", self.0)
+    }
+}
+
+impl Display for DebugSource {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match *self {
+            Self::SHADER_COMPILER => write!(f, "SHADER_COMPILER"),
+            Self::API => write!(f, "API"),
+            Self::OTHER => write!(f, "OTHER"),
+            Self::THIRD_PARTY => write!(f, "THIRD_PARTY"),
+            Self::APPLICATION => write!(f, "APPLICATION"),
+            Self::WINDOW_SYSTEM => write!(f, "WINDOW_SYSTEM"),
+            _ => write!(f, "UNKNOWN"),
+        }
+    }
+}
+
+impl Display for DebugType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match *self {
+            Self::OTHER => write!(f, "OTHER"),
+            Self::ERROR => write!(f, "ERROR"),
+            Self::PORTABILITY => write!(f, "PORTABILITY"),
+            Self::PERFORMANCE => write!(f, "PERFORMANCE"),
+            Self::UNDEFINED_BEHAVIOUR => write!(f, "UNDEFINED_BEHAVIOUR"),
+            Self::DEPRECATED_BEHAVIOUR => write!(f, "DEPRECATED"),
+            Self::MARKER => write!(f, "MARKER"),
+            Self::PUSH_GROUP => write!(f, "PUSH_GROUP"),
+            Self::POP_GROUP => write!(f, "POP_GROUP"),
+            _ => write!(f, "UNKNOWN"),
+        }
+    }
+}
+
+impl Display for DebugSeverity {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match *self {
+            Self::HIGH => write!(f, "HIGH"),
+            Self::MEDIUM => write!(f, "MEDIUM"),
+            Self::LOW => write!(f, "LOW"),
+            Self::NOTIFICATION => write!(f, "NOTIFICATION"),
+            _ => write!(f, "UNKNOWN"),
+        }
+    }
+}
@@ -0,0 +1,33 @@
+// Hand-written helpers for compute shaders. This is included by the first module of every family
+// that has them (`gl43`, `gles31`).
+
+impl Api {
+    /// Queries the work group limits of compute shaders.
+    ///
+    /// # Safety
+    /// See the safety note in `with_loader`
+    #[must_use]
+    pub unsafe fn compute_limits(&self) -> ComputeLimits {
+        let mut limits = ComputeLimits::default();
+        unsafe {
+            for (index, (count, size)) in (0..).zip(
+                limits
+                    .work_group_count
+                    .iter_mut()
+                    .zip(&mut limits.work_group_size),
+            ) {
+                self.get_integeri_v(IndexedParameter::MAX_COMPUTE_WORK_GROUP_COUNT, index, count);
+                self.get_integeri_v(IndexedParameter::MAX_COMPUTE_WORK_GROUP_SIZE, index, size);
+            }
+            self.get_integerv(
+                IntegerParameter::MAX_COMPUTE_WORK_GROUP_INVOCATIONS,
+                &raw mut limits.work_group_invocations,
+            );
+            self.get_integerv(
+                IntegerParameter::MAX_COMPUTE_SHARED_MEMORY_SIZE,
+                &raw mut limits.shared_memory_size,
+            );
+        }
+        limits
+    }
+}
@@ -0,0 +1,213 @@
+// Hand-written debug output on top of `debug_message_callback`. This is included by the first
+// module of every family that has it (`gl43`, `gles32`), which also declare the `debug_handler`
+// field of their `Api` in the allow-list.
+
+use std::cell::RefCell;
+use std::panic::{catch_unwind, AssertUnwindSafe};
+use std::sync::atomic::{AtomicPtr, Ordering};
+
+type Handler = RefCell<Box<dyn FnMut(DebugMessage<'_>)>>;
+
+/// The iterator of `debug_message_log`, it fetches one message per `next`.
+pub struct DebugMessageLog<'a> {
+    api: &'a Api,
+    buffer: Vec<u8>,
+}
+
+/// Keeps the closure of `set_debug_handler` registered, and unregisters it when dropped.
+#[must_use = "the handler is unregistered as soon as the guard is dropped"]
+pub struct DebugHandler<'a> {
+    api: &'a Api,
+    /// GL holds a pointer to it as the `user_param`, `None` once leaked.
+    handler: Option<Box<Handler>>,
+}
+
+/// The handler GL currently calls, so a guard only unregisters its own. An atomic keeps the
+/// `Api` `Send` and `Sync`, the closure itself lives in the guard.
+#[derive(Default)]
+struct DebugRegistration(AtomicPtr<Handler>);
+
+impl Api {
+    /// Calls `handler` for every message of the debug output until the returned guard is
+    /// dropped. A later call replaces the handler, the guard of the earlier one then does nothing.
+    ///
+    /// This enables `DEBUG_OUTPUT` and `DEBUG_OUTPUT_SYNCHRONOUS`, so `handler` runs on this
+    /// thread, during the call that caused the message. It must not call GL itself. A panic in
+    /// `handler` is caught and logged instead of unwinding into the driver. Most drivers only
+    /// report messages for contexts created with the debug flag.
+    ///
+    /// # Safety
+    /// See the safety note in `with_loader`, which also applies to dropping the guard.
+    /// `DEBUG_OUTPUT_SYNCHRONOUS` must stay enabled while the guard lives, as `handler` is
+    /// neither `Send` nor locked and the driver may call it from another thread otherwise.
+    /// `safe::Context` refuses to disable it.
+    pub unsafe fn set_debug_handler(
+        &self,
+        handler: Box<dyn FnMut(DebugMessage<'_>)>,
+    ) -> DebugHandler<'_> {
+        let mut handler = Box::new(RefCell::new(handler));
+        let pointer: *mut Handler = &raw mut *handler;
+        unsafe {
+            self.enable(Capability::DEBUG_OUTPUT);
+            self.enable(Capability::DEBUG_OUTPUT_SYNCHRONOUS);
+            self.debug_message_callback(Some(debug_trampoline), pointer.cast_const().cast());
+        }
+        self.debug_handler.0.store(pointer, Ordering::Relaxed);
+        DebugHandler {
+            api: self,
+            handler: Some(handler),
+        }
+    }
+
+    /// Whether the guard of a `set_debug_handler` is alive and its handler registered.
+    #[must_use]
+    pub fn has_debug_handler(&self) -> bool {
+        !self.debug_handler.0.load(Ordering::Relaxed).is_null()
+    }
+
+    /// Routes the debug output to `log` until the guard is dropped, see `DebugMessage::log`.
+    ///
+    /// # Safety
+    /// See `set_debug_handler`
+    pub unsafe fn log_debug_messages(&self) -> DebugHandler<'_> {
+        unsafe { self.set_debug_handler(Box::new(|message| message.log())) }
+    }
+
+    /// Drains the messages logged while no handler is set, oldest first. Call it e.g. once per
+    /// frame on drivers that don't deliver messages to `set_debug_handler`.
+    ///
+    /// # Safety
+    /// See the safety note in `with_loader`, the iterator calls GL on every `next`.
+    #[must_use]
+    pub const unsafe fn debug_message_log(&self) -> DebugMessageLog<'_> {
+        DebugMessageLog {
+            api: self,
+            buffer: Vec::new(),
+        }
+    }
+
+    /// Names `object` in debug messages and graphics debuggers, see `object_label`.
+    ///
+    /// # Safety
+    /// See the safety note in `with_loader`
+    ///
+    /// # Panics
+    /// If `label` is longer than `GLsizei::MAX` bytes.
+    pub unsafe fn label_object<O: Object>(&self, object: O, label: &str) {
+        let length = label
+            .len()
+            .try_into()
+            .expect("label length doesn't fit into a GLsizei");
+        unsafe { self.object_label(O::IDENTIFIER, object.name(), length, label.as_ptr().cast()) }
+    }
+}
+
+impl Drop for DebugHandler<'_> {
+    fn drop(&mut self) {
+        let Some(handler) = &mut self.handler else {
+            return;
+        };
+        let pointer: *mut Handler = &raw mut **handler;
+        let registered = self.api.debug_handler.0.compare_exchange(
+            pointer,
+            std::ptr::null_mut(),
+            Ordering::Relaxed,
+            Ordering::Relaxed,
+        );
+        if registered.is_ok() {
+            unsafe { self.api.debug_message_callback(None, std::ptr::null()) };
+        }
+        // dropped from inside itself, which has to finish running first
+        if handler.try_borrow_mut().is_err() {
+            log::warn!("the debug handler dropped its own guard, leaking it");
+            std::mem::forget(self.handler.take());
+        }
+    }
+}
+
+impl Iterator for DebugMessageLog<'_> {
+    type Item = OwnedDebugMessage;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let mut length = 0;
+        unsafe {
+            self.api.get_integerv(
+                IntegerParameter::DEBUG_NEXT_LOGGED_MESSAGE_LENGTH,
+                &raw mut length,
+            );
+        }
+        // the length includes the nul terminator, it is 0 once the log is empty
+        let size = usize::try_from(length).ok().filter(|&size| size > 0)?;
+        self.buffer.resize(size, 0);
+
+        let mut message = OwnedDebugMessage {
+            source: DebugSource::OTHER,
+            kind: DebugType::OTHER,
+            id: 0,
+            severity: DebugSeverity::NOTIFICATION,
+            text: String::new(),
+        };
+        let mut written = 0;
+        let fetched = unsafe {
+            self.api.get_debug_message_log(
+                1,
+                length,
+                &raw mut message.source,
+                &raw mut message.kind,
+                &raw mut message.id,
+                &raw mut message.severity,
+                &raw mut written,
+                self.buffer.as_mut_ptr().cast(),
+            )
+        };
+        if fetched == 0 {
+            return None;
+        }
+
+        let text = usize::try_from(written).map_or(&[][..], |written| {
+            &self.buffer[..written.saturating_sub(1).min(size)]
+        });
+        message.text = String::from_utf8_lossy(text).into_owned();
+        Some(message)
+    }
+}
+
+extern "system" fn debug_trampoline(
+    source: DebugSource,
+    kind: DebugType,
+    id: GLuint,
+    severity: DebugSeverity,
+    length: GLsizei,
+    message: *const GLchar,
+    user_param: *mut std::ffi::c_void,
+) {
+    // `user_param` is the handler owned by a `DebugHandler`, which unregisters it first
+    let handler = unsafe { &*user_param.cast::<Handler>() };
+    // a message caused by the handler itself
+    let Ok(mut handler) = handler.try_borrow_mut() else {
+        return;
+    };
+
+    let bytes = usize::try_from(length).map_or_else(
+        // some drivers only nul terminate the message
+        |_| unsafe { std::ffi::CStr::from_ptr(message) }.to_bytes(),
+        |length| unsafe { std::slice::from_raw_parts(message.cast::<u8>(), length) },
+    );
+    let text = String::from_utf8_lossy(bytes);
+    let message = DebugMessage {
+        source,
+        kind,
+        id,
+        severity,
+        text: &text,
+    };
+
+    if let Err(panic) = catch_unwind(AssertUnwindSafe(|| handler(message))) {
+        let reason = panic
+            .downcast_ref::<&str>()
+            .copied()
+            .or_else(|| panic.downcast_ref::<String>().map(String::as_str))
+            .unwrap_or("unknown reason");
+        log::error!("the debug handler panicked on message {id}: {reason}");
+    }
+}
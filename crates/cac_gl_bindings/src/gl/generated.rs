@@ -0,0 +1,2091 @@
+// This file is generated by `cac_gl_generator` from the Khronos registry, do not edit it by hand.
+// Change `crates/cac_gl_generator/allowlist.toml` and run `cargo run -p cac_gl_generator` instead.
+
+#[repr(transparent)]
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub struct Shader(GLuint);
+impl Shader {
+    pub const NONE: Self = Self(0);
+}
+
+#[repr(transparent)]
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub struct Program(GLuint);
+impl Program {
+    pub const NONE: Self = Self(0);
+}
+
+#[repr(transparent)]
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub struct VertexArray(GLuint);
+impl VertexArray {
+    pub const NONE: Self = Self(0);
+}
+
+#[repr(transparent)]
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub struct Buffer(GLuint);
+impl Buffer {
+    pub const NONE: Self = Self(0);
+}
+
+#[repr(transparent)]
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub struct Texture(GLuint);
+impl Texture {
+    pub const NONE: Self = Self(0);
+}
+
+#[repr(transparent)]
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub struct Framebuffer(GLuint);
+impl Framebuffer {
+    pub const NONE: Self = Self(0);
+}
+
+#[repr(transparent)]
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub struct Renderbuffer(GLuint);
+impl Renderbuffer {
+    pub const NONE: Self = Self(0);
+}
+
+#[repr(transparent)]
+#[derive(Copy, Clone, Eq, PartialEq)]
+pub struct Capability(GLenum);
+impl Capability {
+    //GL 3.3
+    pub const BLEND: Self = Self(0x0BE2);
+    pub const CLIP_DISTANCE0: Self = Self(0x3000);
+    pub const COLOR_LOGIC_OP: Self = Self(0x0BF2);
+    pub const CULL_FACE: Self = Self(0x0B44);
+    pub const DEPTH_CLAMP: Self = Self(0x864F);
+    pub const DEPTH_TEST: Self = Self(0x0B71);
+    pub const DITHER: Self = Self(0x0BD0);
+    pub const FRAMEBUFFER_SRGB: Self = Self(0x8DB9);
+    pub const LINE_SMOOTH: Self = Self(0x0B20);
+    pub const MULTISAMPLE: Self = Self(0x809D);
+    pub const POLYGON_OFFSET_FILL: Self = Self(0x8037);
+    pub const POLYGON_OFFSET_LINE: Self = Self(0x2A02);
+    pub const POLYGON_OFFSET_POINT: Self = Self(0x2A01);
+    pub const POLYGON_SMOOTH: Self = Self(0x0B41);
+    pub const PRIMITIVE_RESTART: Self = Self(0x8F9D);
+    pub const PROGRAM_POINT_SIZE: Self = Self(0x8642);
+    pub const RASTERIZER_DISCARD: Self = Self(0x8C89);
+    pub const SAMPLE_ALPHA_TO_COVERAGE: Self = Self(0x809E);
+    pub const SAMPLE_ALPHA_TO_ONE: Self = Self(0x809F);
+    pub const SAMPLE_COVERAGE: Self = Self(0x80A0);
+    pub const SAMPLE_MASK: Self = Self(0x8E51);
+    pub const SCISSOR_TEST: Self = Self(0x0C11);
+    pub const STENCIL_TEST: Self = Self(0x0B90);
+    pub const TEXTURE_CUBE_MAP_SEAMLESS: Self = Self(0x884F);
+
+    //since 4.0
+    pub const SAMPLE_SHADING: Self = Self(0x8C36);
+
+    //since 4.3
+    pub const DEBUG_OUTPUT: Self = Self(0x92E0);
+    pub const DEBUG_OUTPUT_SYNCHRONOUS: Self = Self(0x8242);
+    pub const PRIMITIVE_RESTART_FIXED_INDEX: Self = Self(0x8D69);
+}
+
+impl std::fmt::Debug for Capability {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if let Some(name) = self.name() {
+            return write!(f, "Capability::{name}");
+        }
+        write!(f, "Capability({:#06X})", self.0)
+    }
+}
+
+impl Capability {
+    /// The name of the constant, `None` for unknown values.
+    const fn name(self) -> Option<&'static str> {
+        Some(match self {
+            Self::BLEND => "BLEND",
+            Self::CLIP_DISTANCE0 => "CLIP_DISTANCE0",
+            Self::COLOR_LOGIC_OP => "COLOR_LOGIC_OP",
+            Self::CULL_FACE => "CULL_FACE",
+            Self::DEPTH_CLAMP => "DEPTH_CLAMP",
+            Self::DEPTH_TEST => "DEPTH_TEST",
+            Self::DITHER => "DITHER",
+            Self::FRAMEBUFFER_SRGB => "FRAMEBUFFER_SRGB",
+            Self::LINE_SMOOTH => "LINE_SMOOTH",
+            Self::MULTISAMPLE => "MULTISAMPLE",
+            Self::POLYGON_OFFSET_FILL => "POLYGON_OFFSET_FILL",
+            Self::POLYGON_OFFSET_LINE => "POLYGON_OFFSET_LINE",
+            Self::POLYGON_OFFSET_POINT => "POLYGON_OFFSET_POINT",
+            Self::POLYGON_SMOOTH => "POLYGON_SMOOTH",
+            Self::PRIMITIVE_RESTART => "PRIMITIVE_RESTART",
+            Self::PROGRAM_POINT_SIZE => "PROGRAM_POINT_SIZE",
+            Self::RASTERIZER_DISCARD => "RASTERIZER_DISCARD",
+            Self::SAMPLE_ALPHA_TO_COVERAGE => "SAMPLE_ALPHA_TO_COVERAGE",
+            Self::SAMPLE_ALPHA_TO_ONE => "SAMPLE_ALPHA_TO_ONE",
+            Self::SAMPLE_COVERAGE => "SAMPLE_COVERAGE",
+            Self::SAMPLE_MASK => "SAMPLE_MASK",
+            Self::SCISSOR_TEST => "SCISSOR_TEST",
+            Self::STENCIL_TEST => "STENCIL_TEST",
+            Self::TEXTURE_CUBE_MAP_SEAMLESS => "TEXTURE_CUBE_MAP_SEAMLESS",
+            Self::SAMPLE_SHADING => "SAMPLE_SHADING",
+            Self::DEBUG_OUTPUT => "DEBUG_OUTPUT",
+            Self::DEBUG_OUTPUT_SYNCHRONOUS => "DEBUG_OUTPUT_SYNCHRONOUS",
+            Self::PRIMITIVE_RESTART_FIXED_INDEX => "PRIMITIVE_RESTART_FIXED_INDEX",
+            _ => return None,
+        })
+    }
+}
+
+#[repr(transparent)]
+#[derive(Copy, Clone, Eq, PartialEq)]
+pub struct BufferTarget(GLenum);
+impl BufferTarget {
+    //GL 3.3
+    pub const ARRAY_BUFFER: Self = Self(0x8892);
+    pub const COPY_READ_BUFFER: Self = Self(0x8F36);
+    pub const COPY_WRITE_BUFFER: Self = Self(0x8F37);
+    pub const ELEMENT_ARRAY_BUFFER: Self = Self(0x8893);
+    pub const UNIFORM_BUFFER: Self = Self(0x8A11);
+    pub const TEXTURE_BUFFER: Self = Self(0x8C2A);
+    pub const TRANSFORM_FEEDBACK_BUFFER: Self = Self(0x8C8E);
+    pub const PIXEL_PACK_BUFFER: Self = Self(0x88EB);
+    pub const PIXEL_UNPACK_BUFFER: Self = Self(0x88EC);
+
+    //since 4.0
+    pub const DRAW_INDIRECT_BUFFER: Self = Self(0x8F3F);
+
+    //since 4.2
+    pub const ATOMIC_COUNTER_BUFFER: Self = Self(0x92C0);
+
+    //since 4.3
+    pub const SHADER_STORAGE_BUFFER: Self = Self(0x90D2);
+    pub const DISPATCH_INDIRECT_BUFFER: Self = Self(0x90EE);
+}
+
+impl std::fmt::Debug for BufferTarget {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if let Some(name) = self.name() {
+            return write!(f, "BufferTarget::{name}");
+        }
+        write!(f, "BufferTarget({:#06X})", self.0)
+    }
+}
+
+impl BufferTarget {
+    /// The name of the constant, `None` for unknown values.
+    const fn name(self) -> Option<&'static str> {
+        Some(match self {
+            Self::ARRAY_BUFFER => "ARRAY_BUFFER",
+            Self::COPY_READ_BUFFER => "COPY_READ_BUFFER",
+            Self::COPY_WRITE_BUFFER => "COPY_WRITE_BUFFER",
+            Self::ELEMENT_ARRAY_BUFFER => "ELEMENT_ARRAY_BUFFER",
+            Self::UNIFORM_BUFFER => "UNIFORM_BUFFER",
+            Self::TEXTURE_BUFFER => "TEXTURE_BUFFER",
+            Self::TRANSFORM_FEEDBACK_BUFFER => "TRANSFORM_FEEDBACK_BUFFER",
+            Self::PIXEL_PACK_BUFFER => "PIXEL_PACK_BUFFER",
+            Self::PIXEL_UNPACK_BUFFER => "PIXEL_UNPACK_BUFFER",
+            Self::DRAW_INDIRECT_BUFFER => "DRAW_INDIRECT_BUFFER",
+            Self::ATOMIC_COUNTER_BUFFER => "ATOMIC_COUNTER_BUFFER",
+            Self::SHADER_STORAGE_BUFFER => "SHADER_STORAGE_BUFFER",
+            Self::DISPATCH_INDIRECT_BUFFER => "DISPATCH_INDIRECT_BUFFER",
+            _ => return None,
+        })
+    }
+}
+
+#[repr(transparent)]
+#[derive(Copy, Clone, Eq, PartialEq)]
+pub struct BufferUsage(GLenum);
+impl BufferUsage {
+    pub const STREAM_DRAW: Self = Self(0x88E0);
+    pub const STREAM_READ: Self = Self(0x88E1);
+    pub const STREAM_COPY: Self = Self(0x88E2);
+    pub const STATIC_DRAW: Self = Self(0x88E4);
+    pub const STATIC_READ: Self = Self(0x88E5);
+    pub const STATIC_COPY: Self = Self(0x88E6);
+    pub const DYNAMIC_DRAW: Self = Self(0x88E8);
+    pub const DYNAMIC_READ: Self = Self(0x88E9);
+    pub const DYNAMIC_COPY: Self = Self(0x88EA);
+}
+
+impl std::fmt::Debug for BufferUsage {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if let Some(name) = self.name() {
+            return write!(f, "BufferUsage::{name}");
+        }
+        write!(f, "BufferUsage({:#06X})", self.0)
+    }
+}
+
+impl BufferUsage {
+    /// The name of the constant, `None` for unknown values.
+    const fn name(self) -> Option<&'static str> {
+        Some(match self {
+            Self::STREAM_DRAW => "STREAM_DRAW",
+            Self::STREAM_READ => "STREAM_READ",
+            Self::STREAM_COPY => "STREAM_COPY",
+            Self::STATIC_DRAW => "STATIC_DRAW",
+            Self::STATIC_READ => "STATIC_READ",
+            Self::STATIC_COPY => "STATIC_COPY",
+            Self::DYNAMIC_DRAW => "DYNAMIC_DRAW",
+            Self::DYNAMIC_READ => "DYNAMIC_READ",
+            Self::DYNAMIC_COPY => "DYNAMIC_COPY",
+            _ => return None,
+        })
+    }
+}
+
+#[repr(transparent)]
+#[derive(Copy, Clone, Eq, PartialEq)]
+pub struct VertexAttributeKind(GLenum);
+impl VertexAttributeKind {
+    //GL 3.3
+    pub const BYTE: Self = Self(0x1400);
+    pub const UNSIGNED_BYTE: Self = Self(0x1401);
+    pub const SHORT: Self = Self(0x1402);
+    pub const UNSIGNED_SHORT: Self = Self(0x1403);
+    pub const INT: Self = Self(0x1404);
+    pub const UNSIGNED_INT: Self = Self(0x1405);
+    pub const HALF_FLOAT: Self = Self(0x140B);
+    pub const FLOAT: Self = Self(0x1406);
+    pub const DOUBLE: Self = Self(0x140A);
+    pub const INT_2_10_10_10_REV: Self = Self(0x8D9F);
+    pub const UNSIGNED_INT_2_10_10_10_REV: Self = Self(0x8368);
+    pub const UNSIGNED_INT_10F_11F_11F_REV: Self = Self(0x8C3B);
+
+    //since 4.1
+    pub const FIXED: Self = Self(0x140C);
+}
+
+impl std::fmt::Debug for VertexAttributeKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if let Some(name) = self.name() {
+            return write!(f, "VertexAttributeKind::{name}");
+        }
+        write!(f, "VertexAttributeKind({:#06X})", self.0)
+    }
+}
+
+impl VertexAttributeKind {
+    /// The name of the constant, `None` for unknown values.
+    const fn name(self) -> Option<&'static str> {
+        Some(match self {
+            Self::BYTE => "BYTE",
+            Self::UNSIGNED_BYTE => "UNSIGNED_BYTE",
+            Self::SHORT => "SHORT",
+            Self::UNSIGNED_SHORT => "UNSIGNED_SHORT",
+            Self::INT => "INT",
+            Self::UNSIGNED_INT => "UNSIGNED_INT",
+            Self::HALF_FLOAT => "HALF_FLOAT",
+            Self::FLOAT => "FLOAT",
+            Self::DOUBLE => "DOUBLE",
+            Self::INT_2_10_10_10_REV => "INT_2_10_10_10_REV",
+            Self::UNSIGNED_INT_2_10_10_10_REV => "UNSIGNED_INT_2_10_10_10_REV",
+            Self::UNSIGNED_INT_10F_11F_11F_REV => "UNSIGNED_INT_10F_11F_11F_REV",
+            Self::FIXED => "FIXED",
+            _ => return None,
+        })
+    }
+}
+
+#[repr(transparent)]
+#[derive(Copy, Clone, Eq, PartialEq)]
+pub struct MapAccess(GLbitField);
+impl MapAccess {
+    //GL 3.3
+    pub const READ: Self = Self(0x0001);
+    pub const WRITE: Self = Self(0x0002);
+    pub const INVALIDATE_RANGE: Self = Self(0x0004);
+    pub const INVALIDATE_BUFFER: Self = Self(0x0008);
+    pub const FLUSH_EXPLICIT: Self = Self(0x0010);
+    pub const UNSYNCHRONIZED: Self = Self(0x0020);
+
+    //since 4.4
+    pub const PERSISTENT: Self = Self(0x0040);
+    pub const COHERENT: Self = Self(0x0080);
+}
+
+impl std::fmt::Debug for MapAccess {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let constants = [
+            ("READ", Self::READ),
+            ("WRITE", Self::WRITE),
+            ("INVALIDATE_RANGE", Self::INVALIDATE_RANGE),
+            ("INVALIDATE_BUFFER", Self::INVALIDATE_BUFFER),
+            ("FLUSH_EXPLICIT", Self::FLUSH_EXPLICIT),
+            ("UNSYNCHRONIZED", Self::UNSYNCHRONIZED),
+            ("PERSISTENT", Self::PERSISTENT),
+            ("COHERENT", Self::COHERENT),
+        ];
+        if let Some((name, _)) = constants.iter().find(|(_, c)| *c == *self) {
+            return write!(f, "MapAccess::{name}");
+        }
+
+        let mut rest = self.0;
+        let mut separator = "";
+        for (name, constant) in constants {
+            if constant.0 != 0 && rest & constant.0 == constant.0 {
+                write!(f, "{separator}MapAccess::{name}")?;
+                rest &= !constant.0;
+                separator = " | ";
+            }
+        }
+        if rest != 0 || separator.is_empty() {
+            write!(f, "{separator}MapAccess({rest:#X})")?;
+        }
+        Ok(())
+    }
+}
+
+impl std::ops::BitOr for MapAccess {
+    type Output = Self;
+
+    fn bitor(self, rhs: Self) -> Self {
+        Self(self.0 | rhs.0)
+    }
+}
+
+impl std::ops::BitOrAssign for MapAccess {
+    fn bitor_assign(&mut self, rhs: Self) {
+        self.0 |= rhs.0;
+    }
+}
+
+#[repr(transparent)]
+#[derive(Copy, Clone, Eq, PartialEq)]
+pub struct ClearMask(GLbitField);
+impl ClearMask {
+    pub const COLOR: Self = Self(0x4000);
+    pub const DEPTH: Self = Self(0x0100);
+    pub const STENCIL: Self = Self(0x0400);
+}
+
+impl std::fmt::Debug for ClearMask {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let constants = [
+            ("COLOR", Self::COLOR),
+            ("DEPTH", Self::DEPTH),
+            ("STENCIL", Self::STENCIL),
+        ];
+        if let Some((name, _)) = constants.iter().find(|(_, c)| *c == *self) {
+            return write!(f, "ClearMask::{name}");
+        }
+
+        let mut rest = self.0;
+        let mut separator = "";
+        for (name, constant) in constants {
+            if constant.0 != 0 && rest & constant.0 == constant.0 {
+                write!(f, "{separator}ClearMask::{name}")?;
+                rest &= !constant.0;
+                separator = " | ";
+            }
+        }
+        if rest != 0 || separator.is_empty() {
+            write!(f, "{separator}ClearMask({rest:#X})")?;
+        }
+        Ok(())
+    }
+}
+
+impl std::ops::BitOr for ClearMask {
+    type Output = Self;
+
+    fn bitor(self, rhs: Self) -> Self {
+        Self(self.0 | rhs.0)
+    }
+}
+
+impl std::ops::BitOrAssign for ClearMask {
+    fn bitor_assign(&mut self, rhs: Self) {
+        self.0 |= rhs.0;
+    }
+}
+
+#[repr(transparent)]
+#[derive(Copy, Clone, Eq, PartialEq)]
+pub struct Primitive(GLenum);
+impl Primitive {
+    //GL 3.3
+    pub const POINTS: Self = Self(0x0000);
+    pub const LINES: Self = Self(0x0001);
+    pub const LINE_LOOP: Self = Self(0x0002);
+    pub const LINE_STRIP: Self = Self(0x0003);
+    pub const TRIANGLES: Self = Self(0x0004);
+    pub const TRIANGLE_STRIP: Self = Self(0x0005);
+    pub const TRIANGLE_FAN: Self = Self(0x0006);
+    pub const LINES_ADJACENCY: Self = Self(0x000A);
+    pub const LINE_STRIP_ADJACENCY: Self = Self(0x000B);
+    pub const TRIANGLES_ADJACENCY: Self = Self(0x000C);
+    pub const TRIANGLE_STRIP_ADJACENCY: Self = Self(0x000D);
+
+    //since 4.0
+    pub const PATCHES: Self = Self(0x000E);
+}
+
+impl std::fmt::Debug for Primitive {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if let Some(name) = self.name() {
+            return write!(f, "Primitive::{name}");
+        }
+        write!(f, "Primitive({:#06X})", self.0)
+    }
+}
+
+impl Primitive {
+    /// The name of the constant, `None` for unknown values.
+    const fn name(self) -> Option<&'static str> {
+        Some(match self {
+            Self::POINTS => "POINTS",
+            Self::LINES => "LINES",
+            Self::LINE_LOOP => "LINE_LOOP",
+            Self::LINE_STRIP => "LINE_STRIP",
+            Self::TRIANGLES => "TRIANGLES",
+            Self::TRIANGLE_STRIP => "TRIANGLE_STRIP",
+            Self::TRIANGLE_FAN => "TRIANGLE_FAN",
+            Self::LINES_ADJACENCY => "LINES_ADJACENCY",
+            Self::LINE_STRIP_ADJACENCY => "LINE_STRIP_ADJACENCY",
+            Self::TRIANGLES_ADJACENCY => "TRIANGLES_ADJACENCY",
+            Self::TRIANGLE_STRIP_ADJACENCY => "TRIANGLE_STRIP_ADJACENCY",
+            Self::PATCHES => "PATCHES",
+            _ => return None,
+        })
+    }
+}
+
+#[repr(transparent)]
+#[derive(Copy, Clone, Eq, PartialEq)]
+pub struct IndexKind(GLenum);
+impl IndexKind {
+    pub const UNSIGNED_BYTE: Self = Self(0x1401);
+    pub const UNSIGNED_SHORT: Self = Self(0x1403);
+    pub const UNSIGNED_INT: Self = Self(0x1405);
+}
+
+impl std::fmt::Debug for IndexKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if let Some(name) = self.name() {
+            return write!(f, "IndexKind::{name}");
+        }
+        write!(f, "IndexKind({:#06X})", self.0)
+    }
+}
+
+impl IndexKind {
+    /// The name of the constant, `None` for unknown values.
+    const fn name(self) -> Option<&'static str> {
+        Some(match self {
+            Self::UNSIGNED_BYTE => "UNSIGNED_BYTE",
+            Self::UNSIGNED_SHORT => "UNSIGNED_SHORT",
+            Self::UNSIGNED_INT => "UNSIGNED_INT",
+            _ => return None,
+        })
+    }
+}
+
+#[repr(transparent)]
+#[derive(Copy, Clone, Eq, PartialEq)]
+pub struct ShaderKind(GLenum);
+impl ShaderKind {
+    //GL 3.3
+    pub const FRAGMENT: Self = Self(0x8B30);
+    pub const VERTEX: Self = Self(0x8B31);
+    pub const GEOMETRY: Self = Self(0x8DD9);
+
+    //since 4.0
+    pub const TESS_CONTROL: Self = Self(0x8E88);
+    pub const TESS_EVALUATION: Self = Self(0x8E87);
+
+    //since 4.3
+    pub const COMPUTE: Self = Self(0x91B9);
+}
+
+impl std::fmt::Debug for ShaderKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if let Some(name) = self.name() {
+            return write!(f, "ShaderKind::{name}");
+        }
+        write!(f, "ShaderKind({:#06X})", self.0)
+    }
+}
+
+impl ShaderKind {
+    /// The name of the constant, `None` for unknown values.
+    const fn name(self) -> Option<&'static str> {
+        Some(match self {
+            Self::FRAGMENT => "FRAGMENT",
+            Self::VERTEX => "VERTEX",
+            Self::GEOMETRY => "GEOMETRY",
+            Self::TESS_CONTROL => "TESS_CONTROL",
+            Self::TESS_EVALUATION => "TESS_EVALUATION",
+            Self::COMPUTE => "COMPUTE",
+            _ => return None,
+        })
+    }
+}
+
+#[repr(transparent)]
+#[derive(Copy, Clone, Eq, PartialEq)]
+pub struct BarrierBits(GLbitField);
+impl BarrierBits {
+    //GL 4.2
+    pub const VERTEX_ATTRIB_ARRAY: Self = Self(0x0001);
+    pub const ELEMENT_ARRAY: Self = Self(0x0002);
+    pub const UNIFORM: Self = Self(0x0004);
+    pub const TEXTURE_FETCH: Self = Self(0x0008);
+    pub const SHADER_IMAGE_ACCESS: Self = Self(0x0020);
+    pub const COMMAND: Self = Self(0x0040);
+    pub const PIXEL_BUFFER: Self = Self(0x0080);
+    pub const TEXTURE_UPDATE: Self = Self(0x0100);
+    pub const BUFFER_UPDATE: Self = Self(0x0200);
+    pub const FRAMEBUFFER: Self = Self(0x0400);
+    pub const TRANSFORM_FEEDBACK: Self = Self(0x0800);
+    pub const ATOMIC_COUNTER: Self = Self(0x1000);
+    pub const ALL: Self = Self(0xFFFF_FFFF);
+
+    //since 4.3
+    pub const SHADER_STORAGE: Self = Self(0x2000);
+
+    //since 4.4
+    pub const CLIENT_MAPPED_BUFFER: Self = Self(0x4000);
+    pub const QUERY_BUFFER: Self = Self(0x8000);
+}
+
+impl std::fmt::Debug for BarrierBits {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let constants = [
+            ("VERTEX_ATTRIB_ARRAY", Self::VERTEX_ATTRIB_ARRAY),
+            ("ELEMENT_ARRAY", Self::ELEMENT_ARRAY),
+            ("UNIFORM", Self::UNIFORM),
+            ("TEXTURE_FETCH", Self::TEXTURE_FETCH),
+            ("SHADER_IMAGE_ACCESS", Self::SHADER_IMAGE_ACCESS),
+            ("COMMAND", Self::COMMAND),
+            ("PIXEL_BUFFER", Self::PIXEL_BUFFER),
+            ("TEXTURE_UPDATE", Self::TEXTURE_UPDATE),
+            ("BUFFER_UPDATE", Self::BUFFER_UPDATE),
+            ("FRAMEBUFFER", Self::FRAMEBUFFER),
+            ("TRANSFORM_FEEDBACK", Self::TRANSFORM_FEEDBACK),
+            ("ATOMIC_COUNTER", Self::ATOMIC_COUNTER),
+            ("ALL", Self::ALL),
+            ("SHADER_STORAGE", Self::SHADER_STORAGE),
+            ("CLIENT_MAPPED_BUFFER", Self::CLIENT_MAPPED_BUFFER),
+            ("QUERY_BUFFER", Self::QUERY_BUFFER),
+        ];
+        if let Some((name, _)) = constants.iter().find(|(_, c)| *c == *self) {
+            return write!(f, "BarrierBits::{name}");
+        }
+
+        let mut rest = self.0;
+        let mut separator = "";
+        for (name, constant) in constants {
+            if constant.0 != 0 && rest & constant.0 == constant.0 {
+                write!(f, "{separator}BarrierBits::{name}")?;
+                rest &= !constant.0;
+                separator = " | ";
+            }
+        }
+        if rest != 0 || separator.is_empty() {
+            write!(f, "{separator}BarrierBits({rest:#X})")?;
+        }
+        Ok(())
+    }
+}
+
+impl std::ops::BitOr for BarrierBits {
+    type Output = Self;
+
+    fn bitor(self, rhs: Self) -> Self {
+        Self(self.0 | rhs.0)
+    }
+}
+
+impl std::ops::BitOrAssign for BarrierBits {
+    fn bitor_assign(&mut self, rhs: Self) {
+        self.0 |= rhs.0;
+    }
+}
+
+#[repr(transparent)]
+#[derive(Copy, Clone, Eq, PartialEq)]
+pub struct IntegerParameter(GLenum);
+impl IntegerParameter {
+    //GL 3.3
+    pub const MAJOR_VERSION: Self = Self(0x821B);
+    pub const MINOR_VERSION: Self = Self(0x821C);
+    pub const MAX_VERTEX_ATTRIBS: Self = Self(0x8869);
+    pub const MAX_TEXTURE_SIZE: Self = Self(0x0D33);
+    pub const MAX_TEXTURE_IMAGE_UNITS: Self = Self(0x8872);
+    pub const MAX_COMBINED_TEXTURE_IMAGE_UNITS: Self = Self(0x8B4D);
+    pub const MAX_COLOR_ATTACHMENTS: Self = Self(0x8CDF);
+    pub const MAX_DRAW_BUFFERS: Self = Self(0x8824);
+    pub const MAX_SAMPLES: Self = Self(0x8D57);
+    pub const MAX_UNIFORM_BUFFER_BINDINGS: Self = Self(0x8A2F);
+    pub const UNIFORM_BUFFER_OFFSET_ALIGNMENT: Self = Self(0x8A34);
+
+    //since 4.3
+    pub const MAX_SHADER_STORAGE_BUFFER_BINDINGS: Self = Self(0x90DD);
+    pub const SHADER_STORAGE_BUFFER_OFFSET_ALIGNMENT: Self = Self(0x90DF);
+    pub const MAX_COMPUTE_WORK_GROUP_INVOCATIONS: Self = Self(0x90EB);
+    pub const MAX_COMPUTE_SHARED_MEMORY_SIZE: Self = Self(0x8262);
+    pub const MAX_COMPUTE_UNIFORM_BLOCKS: Self = Self(0x91BB);
+    pub const MAX_COMPUTE_SHADER_STORAGE_BLOCKS: Self = Self(0x90DB);
+    pub const MAX_DEBUG_MESSAGE_LENGTH: Self = Self(0x9143);
+    pub const MAX_DEBUG_LOGGED_MESSAGES: Self = Self(0x9144);
+    pub const MAX_DEBUG_GROUP_STACK_DEPTH: Self = Self(0x826C);
+    pub const DEBUG_LOGGED_MESSAGES: Self = Self(0x9145);
+    pub const DEBUG_NEXT_LOGGED_MESSAGE_LENGTH: Self = Self(0x8243);
+}
+
+impl std::fmt::Debug for IntegerParameter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if let Some(name) = self.name() {
+            return write!(f, "IntegerParameter::{name}");
+        }
+        write!(f, "IntegerParameter({:#06X})", self.0)
+    }
+}
+
+impl IntegerParameter {
+    /// The name of the constant, `None` for unknown values.
+    const fn name(self) -> Option<&'static str> {
+        Some(match self {
+            Self::MAJOR_VERSION => "MAJOR_VERSION",
+            Self::MINOR_VERSION => "MINOR_VERSION",
+            Self::MAX_VERTEX_ATTRIBS => "MAX_VERTEX_ATTRIBS",
+            Self::MAX_TEXTURE_SIZE => "MAX_TEXTURE_SIZE",
+            Self::MAX_TEXTURE_IMAGE_UNITS => "MAX_TEXTURE_IMAGE_UNITS",
+            Self::MAX_COMBINED_TEXTURE_IMAGE_UNITS => "MAX_COMBINED_TEXTURE_IMAGE_UNITS",
+            Self::MAX_COLOR_ATTACHMENTS => "MAX_COLOR_ATTACHMENTS",
+            Self::MAX_DRAW_BUFFERS => "MAX_DRAW_BUFFERS",
+            Self::MAX_SAMPLES => "MAX_SAMPLES",
+            Self::MAX_UNIFORM_BUFFER_BINDINGS => "MAX_UNIFORM_BUFFER_BINDINGS",
+            Self::UNIFORM_BUFFER_OFFSET_ALIGNMENT => "UNIFORM_BUFFER_OFFSET_ALIGNMENT",
+            Self::MAX_SHADER_STORAGE_BUFFER_BINDINGS => "MAX_SHADER_STORAGE_BUFFER_BINDINGS",
+            Self::SHADER_STORAGE_BUFFER_OFFSET_ALIGNMENT => {
+                "SHADER_STORAGE_BUFFER_OFFSET_ALIGNMENT"
+            }
+            Self::MAX_COMPUTE_WORK_GROUP_INVOCATIONS => "MAX_COMPUTE_WORK_GROUP_INVOCATIONS",
+            Self::MAX_COMPUTE_SHARED_MEMORY_SIZE => "MAX_COMPUTE_SHARED_MEMORY_SIZE",
+            Self::MAX_COMPUTE_UNIFORM_BLOCKS => "MAX_COMPUTE_UNIFORM_BLOCKS",
+            Self::MAX_COMPUTE_SHADER_STORAGE_BLOCKS => "MAX_COMPUTE_SHADER_STORAGE_BLOCKS",
+            Self::MAX_DEBUG_MESSAGE_LENGTH => "MAX_DEBUG_MESSAGE_LENGTH",
+            Self::MAX_DEBUG_LOGGED_MESSAGES => "MAX_DEBUG_LOGGED_MESSAGES",
+            Self::MAX_DEBUG_GROUP_STACK_DEPTH => "MAX_DEBUG_GROUP_STACK_DEPTH",
+            Self::DEBUG_LOGGED_MESSAGES => "DEBUG_LOGGED_MESSAGES",
+            Self::DEBUG_NEXT_LOGGED_MESSAGE_LENGTH => "DEBUG_NEXT_LOGGED_MESSAGE_LENGTH",
+            _ => return None,
+        })
+    }
+}
+
+#[repr(transparent)]
+#[derive(Copy, Clone, Eq, PartialEq)]
+pub struct IndexedParameter(GLenum);
+impl IndexedParameter {
+    //GL 3.3
+    pub const UNIFORM_BUFFER_BINDING: Self = Self(0x8A28);
+
+    //since 4.3
+    pub const MAX_COMPUTE_WORK_GROUP_COUNT: Self = Self(0x91BE);
+    pub const MAX_COMPUTE_WORK_GROUP_SIZE: Self = Self(0x91BF);
+    pub const SHADER_STORAGE_BUFFER_BINDING: Self = Self(0x90D3);
+}
+
+impl std::fmt::Debug for IndexedParameter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if let Some(name) = self.name() {
+            return write!(f, "IndexedParameter::{name}");
+        }
+        write!(f, "IndexedParameter({:#06X})", self.0)
+    }
+}
+
+impl IndexedParameter {
+    /// The name of the constant, `None` for unknown values.
+    const fn name(self) -> Option<&'static str> {
+        Some(match self {
+            Self::UNIFORM_BUFFER_BINDING => "UNIFORM_BUFFER_BINDING",
+            Self::MAX_COMPUTE_WORK_GROUP_COUNT => "MAX_COMPUTE_WORK_GROUP_COUNT",
+            Self::MAX_COMPUTE_WORK_GROUP_SIZE => "MAX_COMPUTE_WORK_GROUP_SIZE",
+            Self::SHADER_STORAGE_BUFFER_BINDING => "SHADER_STORAGE_BUFFER_BINDING",
+            _ => return None,
+        })
+    }
+}
+
+#[repr(transparent)]
+#[derive(Copy, Clone, Eq, PartialEq)]
+pub struct BlendFactor(GLenum);
+impl BlendFactor {
+    pub const ZERO: Self = Self(0);
+    pub const ONE: Self = Self(1);
+    pub const SRC_COLOR: Self = Self(0x0300);
+    pub const ONE_MINUS_SRC_COLOR: Self = Self(0x0301);
+    pub const DST_COLOR: Self = Self(0x0306);
+    pub const ONE_MINUS_DST_COLOR: Self = Self(0x0307);
+    pub const SRC_ALPHA: Self = Self(0x0302);
+    pub const ONE_MINUS_SRC_ALPHA: Self = Self(0x0303);
+    pub const DST_ALPHA: Self = Self(0x0304);
+    pub const ONE_MINUS_DST_ALPHA: Self = Self(0x0305);
+    pub const CONSTANT_COLOR: Self = Self(0x8001);
+    pub const ONE_MINUS_CONSTANT_COLOR: Self = Self(0x8002);
+    pub const CONSTANT_ALPHA: Self = Self(0x8003);
+    pub const ONE_MINUS_CONSTANT_ALPHA: Self = Self(0x8004);
+    pub const SRC_ALPHA_SATURATE: Self = Self(0x0308);
+    pub const SRC1_COLOR: Self = Self(0x88F9);
+    pub const ONE_MINUS_SRC1_COLOR: Self = Self(0x88FA);
+    pub const SRC1_ALPHA: Self = Self(0x8589);
+    pub const ONE_MINUS_SRC1_ALPHA: Self = Self(0x88FB);
+}
+
+impl std::fmt::Debug for BlendFactor {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if let Some(name) = self.name() {
+            return write!(f, "BlendFactor::{name}");
+        }
+        write!(f, "BlendFactor({:#06X})", self.0)
+    }
+}
+
+impl BlendFactor {
+    /// The name of the constant, `None` for unknown values.
+    const fn name(self) -> Option<&'static str> {
+        Some(match self {
+            Self::ZERO => "ZERO",
+            Self::ONE => "ONE",
+            Self::SRC_COLOR => "SRC_COLOR",
+            Self::ONE_MINUS_SRC_COLOR => "ONE_MINUS_SRC_COLOR",
+            Self::DST_COLOR => "DST_COLOR",
+            Self::ONE_MINUS_DST_COLOR => "ONE_MINUS_DST_COLOR",
+            Self::SRC_ALPHA => "SRC_ALPHA",
+            Self::ONE_MINUS_SRC_ALPHA => "ONE_MINUS_SRC_ALPHA",
+            Self::DST_ALPHA => "DST_ALPHA",
+            Self::ONE_MINUS_DST_ALPHA => "ONE_MINUS_DST_ALPHA",
+            Self::CONSTANT_COLOR => "CONSTANT_COLOR",
+            Self::ONE_MINUS_CONSTANT_COLOR => "ONE_MINUS_CONSTANT_COLOR",
+            Self::CONSTANT_ALPHA => "CONSTANT_ALPHA",
+            Self::ONE_MINUS_CONSTANT_ALPHA => "ONE_MINUS_CONSTANT_ALPHA",
+            Self::SRC_ALPHA_SATURATE => "SRC_ALPHA_SATURATE",
+            Self::SRC1_COLOR => "SRC1_COLOR",
+            Self::ONE_MINUS_SRC1_COLOR => "ONE_MINUS_SRC1_COLOR",
+            Self::SRC1_ALPHA => "SRC1_ALPHA",
+            Self::ONE_MINUS_SRC1_ALPHA => "ONE_MINUS_SRC1_ALPHA",
+            _ => return None,
+        })
+    }
+}
+
+#[repr(transparent)]
+#[derive(Copy, Clone, Eq, PartialEq)]
+pub struct BlendEquation(GLenum);
+impl BlendEquation {
+    pub const ADD: Self = Self(0x8006);
+    pub const SUBTRACT: Self = Self(0x800A);
+    pub const REVERSE_SUBTRACT: Self = Self(0x800B);
+    pub const MIN: Self = Self(0x8007);
+    pub const MAX: Self = Self(0x8008);
+}
+
+impl std::fmt::Debug for BlendEquation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if let Some(name) = self.name() {
+            return write!(f, "BlendEquation::{name}");
+        }
+        write!(f, "BlendEquation({:#06X})", self.0)
+    }
+}
+
+impl BlendEquation {
+    /// The name of the constant, `None` for unknown values.
+    const fn name(self) -> Option<&'static str> {
+        Some(match self {
+            Self::ADD => "ADD",
+            Self::SUBTRACT => "SUBTRACT",
+            Self::REVERSE_SUBTRACT => "REVERSE_SUBTRACT",
+            Self::MIN => "MIN",
+            Self::MAX => "MAX",
+            _ => return None,
+        })
+    }
+}
+
+#[repr(transparent)]
+#[derive(Copy, Clone, Eq, PartialEq)]
+pub struct CompareFunc(GLenum);
+impl CompareFunc {
+    pub const NEVER: Self = Self(0x0200);
+    pub const LESS: Self = Self(0x0201);
+    pub const EQUAL: Self = Self(0x0202);
+    pub const LEQUAL: Self = Self(0x0203);
+    pub const GREATER: Self = Self(0x0204);
+    pub const NOTEQUAL: Self = Self(0x0205);
+    pub const GEQUAL: Self = Self(0x0206);
+    pub const ALWAYS: Self = Self(0x0207);
+}
+
+impl std::fmt::Debug for CompareFunc {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if let Some(name) = self.name() {
+            return write!(f, "CompareFunc::{name}");
+        }
+        write!(f, "CompareFunc({:#06X})", self.0)
+    }
+}
+
+impl CompareFunc {
+    /// The name of the constant, `None` for unknown values.
+    const fn name(self) -> Option<&'static str> {
+        Some(match self {
+            Self::NEVER => "NEVER",
+            Self::LESS => "LESS",
+            Self::EQUAL => "EQUAL",
+            Self::LEQUAL => "LEQUAL",
+            Self::GREATER => "GREATER",
+            Self::NOTEQUAL => "NOTEQUAL",
+            Self::GEQUAL => "GEQUAL",
+            Self::ALWAYS => "ALWAYS",
+            _ => return None,
+        })
+    }
+}
+
+#[repr(transparent)]
+#[derive(Copy, Clone, Eq, PartialEq)]
+pub struct StencilOp(GLenum);
+impl StencilOp {
+    pub const KEEP: Self = Self(0x1E00);
+    pub const ZERO: Self = Self(0);
+    pub const REPLACE: Self = Self(0x1E01);
+    pub const INCR: Self = Self(0x1E02);
+    pub const INCR_WRAP: Self = Self(0x8507);
+    pub const DECR: Self = Self(0x1E03);
+    pub const DECR_WRAP: Self = Self(0x8508);
+    pub const INVERT: Self = Self(0x150A);
+}
+
+impl std::fmt::Debug for StencilOp {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if let Some(name) = self.name() {
+            return write!(f, "StencilOp::{name}");
+        }
+        write!(f, "StencilOp({:#06X})", self.0)
+    }
+}
+
+impl StencilOp {
+    /// The name of the constant, `None` for unknown values.
+    const fn name(self) -> Option<&'static str> {
+        Some(match self {
+            Self::KEEP => "KEEP",
+            Self::ZERO => "ZERO",
+            Self::REPLACE => "REPLACE",
+            Self::INCR => "INCR",
+            Self::INCR_WRAP => "INCR_WRAP",
+            Self::DECR => "DECR",
+            Self::DECR_WRAP => "DECR_WRAP",
+            Self::INVERT => "INVERT",
+            _ => return None,
+        })
+    }
+}
+
+#[repr(transparent)]
+#[derive(Copy, Clone, Eq, PartialEq)]
+pub struct Face(GLenum);
+impl Face {
+    pub const FRONT: Self = Self(0x0404);
+    pub const BACK: Self = Self(0x0405);
+    pub const FRONT_AND_BACK: Self = Self(0x0408);
+}
+
+impl std::fmt::Debug for Face {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if let Some(name) = self.name() {
+            return write!(f, "Face::{name}");
+        }
+        write!(f, "Face({:#06X})", self.0)
+    }
+}
+
+impl Face {
+    /// The name of the constant, `None` for unknown values.
+    const fn name(self) -> Option<&'static str> {
+        Some(match self {
+            Self::FRONT => "FRONT",
+            Self::BACK => "BACK",
+            Self::FRONT_AND_BACK => "FRONT_AND_BACK",
+            _ => return None,
+        })
+    }
+}
+
+#[repr(transparent)]
+#[derive(Copy, Clone, Eq, PartialEq)]
+pub struct FrontFace(GLenum);
+impl FrontFace {
+    pub const CW: Self = Self(0x0900);
+    pub const CCW: Self = Self(0x0901);
+}
+
+impl std::fmt::Debug for FrontFace {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if let Some(name) = self.name() {
+            return write!(f, "FrontFace::{name}");
+        }
+        write!(f, "FrontFace({:#06X})", self.0)
+    }
+}
+
+impl FrontFace {
+    /// The name of the constant, `None` for unknown values.
+    const fn name(self) -> Option<&'static str> {
+        Some(match self {
+            Self::CW => "CW",
+            Self::CCW => "CCW",
+            _ => return None,
+        })
+    }
+}
+
+#[repr(transparent)]
+#[derive(Copy, Clone, Eq, PartialEq)]
+pub struct PolygonMode(GLenum);
+impl PolygonMode {
+    pub const POINT: Self = Self(0x1B00);
+    pub const LINE: Self = Self(0x1B01);
+    pub const FILL: Self = Self(0x1B02);
+}
+
+impl std::fmt::Debug for PolygonMode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if let Some(name) = self.name() {
+            return write!(f, "PolygonMode::{name}");
+        }
+        write!(f, "PolygonMode({:#06X})", self.0)
+    }
+}
+
+impl PolygonMode {
+    /// The name of the constant, `None` for unknown values.
+    const fn name(self) -> Option<&'static str> {
+        Some(match self {
+            Self::POINT => "POINT",
+            Self::LINE => "LINE",
+            Self::FILL => "FILL",
+            _ => return None,
+        })
+    }
+}
+
+#[repr(transparent)]
+#[derive(Copy, Clone, Eq, PartialEq)]
+pub struct TextureTarget(GLenum);
+impl TextureTarget {
+    //GL 3.3
+    pub const TEXTURE_1D: Self = Self(0x0DE0);
+    pub const TEXTURE_2D: Self = Self(0x0DE1);
+    pub const TEXTURE_3D: Self = Self(0x806F);
+    pub const TEXTURE_1D_ARRAY: Self = Self(0x8C18);
+    pub const TEXTURE_2D_ARRAY: Self = Self(0x8C1A);
+    pub const TEXTURE_RECTANGLE: Self = Self(0x84F5);
+    pub const TEXTURE_CUBE_MAP: Self = Self(0x8513);
+    pub const TEXTURE_CUBE_MAP_POSITIVE_X: Self = Self(0x8515);
+    pub const TEXTURE_CUBE_MAP_NEGATIVE_X: Self = Self(0x8516);
+    pub const TEXTURE_CUBE_MAP_POSITIVE_Y: Self = Self(0x8517);
+    pub const TEXTURE_CUBE_MAP_NEGATIVE_Y: Self = Self(0x8518);
+    pub const TEXTURE_CUBE_MAP_POSITIVE_Z: Self = Self(0x8519);
+    pub const TEXTURE_CUBE_MAP_NEGATIVE_Z: Self = Self(0x851A);
+    pub const TEXTURE_BUFFER: Self = Self(0x8C2A);
+    pub const TEXTURE_2D_MULTISAMPLE: Self = Self(0x9100);
+    pub const TEXTURE_2D_MULTISAMPLE_ARRAY: Self = Self(0x9102);
+
+    //since 4.0
+    pub const TEXTURE_CUBE_MAP_ARRAY: Self = Self(0x9009);
+}
+
+impl std::fmt::Debug for TextureTarget {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if let Some(name) = self.name() {
+            return write!(f, "TextureTarget::{name}");
+        }
+        write!(f, "TextureTarget({:#06X})", self.0)
+    }
+}
+
+impl TextureTarget {
+    /// The name of the constant, `None` for unknown values.
+    const fn name(self) -> Option<&'static str> {
+        Some(match self {
+            Self::TEXTURE_1D => "TEXTURE_1D",
+            Self::TEXTURE_2D => "TEXTURE_2D",
+            Self::TEXTURE_3D => "TEXTURE_3D",
+            Self::TEXTURE_1D_ARRAY => "TEXTURE_1D_ARRAY",
+            Self::TEXTURE_2D_ARRAY => "TEXTURE_2D_ARRAY",
+            Self::TEXTURE_RECTANGLE => "TEXTURE_RECTANGLE",
+            Self::TEXTURE_CUBE_MAP => "TEXTURE_CUBE_MAP",
+            Self::TEXTURE_CUBE_MAP_POSITIVE_X => "TEXTURE_CUBE_MAP_POSITIVE_X",
+            Self::TEXTURE_CUBE_MAP_NEGATIVE_X => "TEXTURE_CUBE_MAP_NEGATIVE_X",
+            Self::TEXTURE_CUBE_MAP_POSITIVE_Y => "TEXTURE_CUBE_MAP_POSITIVE_Y",
+            Self::TEXTURE_CUBE_MAP_NEGATIVE_Y => "TEXTURE_CUBE_MAP_NEGATIVE_Y",
+            Self::TEXTURE_CUBE_MAP_POSITIVE_Z => "TEXTURE_CUBE_MAP_POSITIVE_Z",
+            Self::TEXTURE_CUBE_MAP_NEGATIVE_Z => "TEXTURE_CUBE_MAP_NEGATIVE_Z",
+            Self::TEXTURE_BUFFER => "TEXTURE_BUFFER",
+            Self::TEXTURE_2D_MULTISAMPLE => "TEXTURE_2D_MULTISAMPLE",
+            Self::TEXTURE_2D_MULTISAMPLE_ARRAY => "TEXTURE_2D_MULTISAMPLE_ARRAY",
+            Self::TEXTURE_CUBE_MAP_ARRAY => "TEXTURE_CUBE_MAP_ARRAY",
+            _ => return None,
+        })
+    }
+}
+
+#[repr(transparent)]
+#[derive(Copy, Clone, Eq, PartialEq)]
+pub struct TextureUnit(GLenum);
+impl TextureUnit {
+    pub const TEXTURE0: Self = Self(0x84C0);
+}
+
+impl std::fmt::Debug for TextureUnit {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if let Some(name) = self.name() {
+            return write!(f, "TextureUnit::{name}");
+        }
+        write!(f, "TextureUnit({:#06X})", self.0)
+    }
+}
+
+impl TextureUnit {
+    /// The name of the constant, `None` for unknown values.
+    const fn name(self) -> Option<&'static str> {
+        Some(match self {
+            Self::TEXTURE0 => "TEXTURE0",
+            _ => return None,
+        })
+    }
+}
+
+#[repr(transparent)]
+#[derive(Copy, Clone, Eq, PartialEq)]
+pub struct InternalFormat(GLenum);
+impl InternalFormat {
+    pub const RED: Self = Self(0x1903);
+    pub const RG: Self = Self(0x8227);
+    pub const RGB: Self = Self(0x1907);
+    pub const RGBA: Self = Self(0x1908);
+    pub const DEPTH_COMPONENT: Self = Self(0x1902);
+    pub const DEPTH_STENCIL: Self = Self(0x84F9);
+    pub const R8: Self = Self(0x8229);
+    pub const RG8: Self = Self(0x822B);
+    pub const RGB8: Self = Self(0x8051);
+    pub const RGBA8: Self = Self(0x8058);
+    pub const SRGB8: Self = Self(0x8C41);
+    pub const SRGB8_ALPHA8: Self = Self(0x8C43);
+    pub const RGB10_A2: Self = Self(0x8059);
+    pub const R11F_G11F_B10F: Self = Self(0x8C3A);
+    pub const R16F: Self = Self(0x822D);
+    pub const RG16F: Self = Self(0x822F);
+    pub const RGB16F: Self = Self(0x881B);
+    pub const RGBA16F: Self = Self(0x881A);
+    pub const R32F: Self = Self(0x822E);
+    pub const RG32F: Self = Self(0x8230);
+    pub const RGB32F: Self = Self(0x8815);
+    pub const RGBA32F: Self = Self(0x8814);
+    pub const R8UI: Self = Self(0x8232);
+    pub const RGBA8UI: Self = Self(0x8D7C);
+    pub const R32UI: Self = Self(0x8236);
+    pub const R32I: Self = Self(0x8235);
+    pub const DEPTH_COMPONENT16: Self = Self(0x81A5);
+    pub const DEPTH_COMPONENT24: Self = Self(0x81A6);
+    pub const DEPTH_COMPONENT32F: Self = Self(0x8CAC);
+    pub const DEPTH24_STENCIL8: Self = Self(0x88F0);
+    pub const DEPTH32F_STENCIL8: Self = Self(0x8CAD);
+    pub const STENCIL_INDEX8: Self = Self(0x8D48);
+}
+
+impl std::fmt::Debug for InternalFormat {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if let Some(name) = self.name() {
+            return write!(f, "InternalFormat::{name}");
+        }
+        write!(f, "InternalFormat({:#06X})", self.0)
+    }
+}
+
+impl InternalFormat {
+    /// The name of the constant, `None` for unknown values.
+    const fn name(self) -> Option<&'static str> {
+        Some(match self {
+            Self::RED => "RED",
+            Self::RG => "RG",
+            Self::RGB => "RGB",
+            Self::RGBA => "RGBA",
+            Self::DEPTH_COMPONENT => "DEPTH_COMPONENT",
+            Self::DEPTH_STENCIL => "DEPTH_STENCIL",
+            Self::R8 => "R8",
+            Self::RG8 => "RG8",
+            Self::RGB8 => "RGB8",
+            Self::RGBA8 => "RGBA8",
+            Self::SRGB8 => "SRGB8",
+            Self::SRGB8_ALPHA8 => "SRGB8_ALPHA8",
+            Self::RGB10_A2 => "RGB10_A2",
+            Self::R11F_G11F_B10F => "R11F_G11F_B10F",
+            Self::R16F => "R16F",
+            Self::RG16F => "RG16F",
+            Self::RGB16F => "RGB16F",
+            Self::RGBA16F => "RGBA16F",
+            Self::R32F => "R32F",
+            Self::RG32F => "RG32F",
+            Self::RGB32F => "RGB32F",
+            Self::RGBA32F => "RGBA32F",
+            Self::R8UI => "R8UI",
+            Self::RGBA8UI => "RGBA8UI",
+            Self::R32UI => "R32UI",
+            Self::R32I => "R32I",
+            Self::DEPTH_COMPONENT16 => "DEPTH_COMPONENT16",
+            Self::DEPTH_COMPONENT24 => "DEPTH_COMPONENT24",
+            Self::DEPTH_COMPONENT32F => "DEPTH_COMPONENT32F",
+            Self::DEPTH24_STENCIL8 => "DEPTH24_STENCIL8",
+            Self::DEPTH32F_STENCIL8 => "DEPTH32F_STENCIL8",
+            Self::STENCIL_INDEX8 => "STENCIL_INDEX8",
+            _ => return None,
+        })
+    }
+}
+
+#[repr(transparent)]
+#[derive(Copy, Clone, Eq, PartialEq)]
+pub struct PixelFormat(GLenum);
+impl PixelFormat {
+    pub const RED: Self = Self(0x1903);
+    pub const RG: Self = Self(0x8227);
+    pub const RGB: Self = Self(0x1907);
+    pub const BGR: Self = Self(0x80E0);
+    pub const RGBA: Self = Self(0x1908);
+    pub const BGRA: Self = Self(0x80E1);
+    pub const RED_INTEGER: Self = Self(0x8D94);
+    pub const RG_INTEGER: Self = Self(0x8228);
+    pub const RGB_INTEGER: Self = Self(0x8D98);
+    pub const RGBA_INTEGER: Self = Self(0x8D99);
+    pub const DEPTH_COMPONENT: Self = Self(0x1902);
+    pub const DEPTH_STENCIL: Self = Self(0x84F9);
+    pub const STENCIL_INDEX: Self = Self(0x1901);
+}
+
+impl std::fmt::Debug for PixelFormat {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if let Some(name) = self.name() {
+            return write!(f, "PixelFormat::{name}");
+        }
+        write!(f, "PixelFormat({:#06X})", self.0)
+    }
+}
+
+impl PixelFormat {
+    /// The name of the constant, `None` for unknown values.
+    const fn name(self) -> Option<&'static str> {
+        Some(match self {
+            Self::RED => "RED",
+            Self::RG => "RG",
+            Self::RGB => "RGB",
+            Self::BGR => "BGR",
+            Self::RGBA => "RGBA",
+            Self::BGRA => "BGRA",
+            Self::RED_INTEGER => "RED_INTEGER",
+            Self::RG_INTEGER => "RG_INTEGER",
+            Self::RGB_INTEGER => "RGB_INTEGER",
+            Self::RGBA_INTEGER => "RGBA_INTEGER",
+            Self::DEPTH_COMPONENT => "DEPTH_COMPONENT",
+            Self::DEPTH_STENCIL => "DEPTH_STENCIL",
+            Self::STENCIL_INDEX => "STENCIL_INDEX",
+            _ => return None,
+        })
+    }
+}
+
+#[repr(transparent)]
+#[derive(Copy, Clone, Eq, PartialEq)]
+pub struct PixelType(GLenum);
+impl PixelType {
+    pub const UNSIGNED_BYTE: Self = Self(0x1401);
+    pub const BYTE: Self = Self(0x1400);
+    pub const UNSIGNED_SHORT: Self = Self(0x1403);
+    pub const SHORT: Self = Self(0x1402);
+    pub const UNSIGNED_INT: Self = Self(0x1405);
+    pub const INT: Self = Self(0x1404);
+    pub const HALF_FLOAT: Self = Self(0x140B);
+    pub const FLOAT: Self = Self(0x1406);
+    pub const UNSIGNED_INT_24_8: Self = Self(0x84FA);
+    pub const UNSIGNED_INT_2_10_10_10_REV: Self = Self(0x8368);
+    pub const UNSIGNED_INT_10F_11F_11F_REV: Self = Self(0x8C3B);
+    pub const FLOAT_32_UNSIGNED_INT_24_8_REV: Self = Self(0x8DAD);
+}
+
+impl std::fmt::Debug for PixelType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if let Some(name) = self.name() {
+            return write!(f, "PixelType::{name}");
+        }
+        write!(f, "PixelType({:#06X})", self.0)
+    }
+}
+
+impl PixelType {
+    /// The name of the constant, `None` for unknown values.
+    const fn name(self) -> Option<&'static str> {
+        Some(match self {
+            Self::UNSIGNED_BYTE => "UNSIGNED_BYTE",
+            Self::BYTE => "BYTE",
+            Self::UNSIGNED_SHORT => "UNSIGNED_SHORT",
+            Self::SHORT => "SHORT",
+            Self::UNSIGNED_INT => "UNSIGNED_INT",
+            Self::INT => "INT",
+            Self::HALF_FLOAT => "HALF_FLOAT",
+            Self::FLOAT => "FLOAT",
+            Self::UNSIGNED_INT_24_8 => "UNSIGNED_INT_24_8",
+            Self::UNSIGNED_INT_2_10_10_10_REV => "UNSIGNED_INT_2_10_10_10_REV",
+            Self::UNSIGNED_INT_10F_11F_11F_REV => "UNSIGNED_INT_10F_11F_11F_REV",
+            Self::FLOAT_32_UNSIGNED_INT_24_8_REV => "FLOAT_32_UNSIGNED_INT_24_8_REV",
+            _ => return None,
+        })
+    }
+}
+
+#[repr(transparent)]
+#[derive(Copy, Clone, Eq, PartialEq)]
+pub struct TextureParameter(GLenum);
+impl TextureParameter {
+    //GL 3.3
+    pub const MIN_FILTER: Self = Self(0x2801);
+    pub const MAG_FILTER: Self = Self(0x2800);
+    pub const WRAP_S: Self = Self(0x2802);
+    pub const WRAP_T: Self = Self(0x2803);
+    pub const WRAP_R: Self = Self(0x8072);
+    pub const MIN_LOD: Self = Self(0x813A);
+    pub const MAX_LOD: Self = Self(0x813B);
+    pub const LOD_BIAS: Self = Self(0x8501);
+    pub const BASE_LEVEL: Self = Self(0x813C);
+    pub const MAX_LEVEL: Self = Self(0x813D);
+    pub const COMPARE_MODE: Self = Self(0x884C);
+    pub const COMPARE_FUNC: Self = Self(0x884D);
+    pub const SWIZZLE_R: Self = Self(0x8E42);
+    pub const SWIZZLE_G: Self = Self(0x8E43);
+    pub const SWIZZLE_B: Self = Self(0x8E44);
+    pub const SWIZZLE_A: Self = Self(0x8E45);
+
+    //since 4.6
+    pub const MAX_ANISOTROPY: Self = Self(0x84FE);
+}
+
+impl std::fmt::Debug for TextureParameter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if let Some(name) = self.name() {
+            return write!(f, "TextureParameter::{name}");
+        }
+        write!(f, "TextureParameter({:#06X})", self.0)
+    }
+}
+
+impl TextureParameter {
+    /// The name of the constant, `None` for unknown values.
+    const fn name(self) -> Option<&'static str> {
+        Some(match self {
+            Self::MIN_FILTER => "MIN_FILTER",
+            Self::MAG_FILTER => "MAG_FILTER",
+            Self::WRAP_S => "WRAP_S",
+            Self::WRAP_T => "WRAP_T",
+            Self::WRAP_R => "WRAP_R",
+            Self::MIN_LOD => "MIN_LOD",
+            Self::MAX_LOD => "MAX_LOD",
+            Self::LOD_BIAS => "LOD_BIAS",
+            Self::BASE_LEVEL => "BASE_LEVEL",
+            Self::MAX_LEVEL => "MAX_LEVEL",
+            Self::COMPARE_MODE => "COMPARE_MODE",
+            Self::COMPARE_FUNC => "COMPARE_FUNC",
+            Self::SWIZZLE_R => "SWIZZLE_R",
+            Self::SWIZZLE_G => "SWIZZLE_G",
+            Self::SWIZZLE_B => "SWIZZLE_B",
+            Self::SWIZZLE_A => "SWIZZLE_A",
+            Self::MAX_ANISOTROPY => "MAX_ANISOTROPY",
+            _ => return None,
+        })
+    }
+}
+
+#[repr(transparent)]
+#[derive(Copy, Clone, Eq, PartialEq)]
+pub struct TextureFilter(GLenum);
+impl TextureFilter {
+    pub const NEAREST: Self = Self(0x2600);
+    pub const LINEAR: Self = Self(0x2601);
+    pub const NEAREST_MIPMAP_NEAREST: Self = Self(0x2700);
+    pub const LINEAR_MIPMAP_NEAREST: Self = Self(0x2701);
+    pub const NEAREST_MIPMAP_LINEAR: Self = Self(0x2702);
+    pub const LINEAR_MIPMAP_LINEAR: Self = Self(0x2703);
+}
+
+impl std::fmt::Debug for TextureFilter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if let Some(name) = self.name() {
+            return write!(f, "TextureFilter::{name}");
+        }
+        write!(f, "TextureFilter({:#06X})", self.0)
+    }
+}
+
+impl TextureFilter {
+    /// The name of the constant, `None` for unknown values.
+    const fn name(self) -> Option<&'static str> {
+        Some(match self {
+            Self::NEAREST => "NEAREST",
+            Self::LINEAR => "LINEAR",
+            Self::NEAREST_MIPMAP_NEAREST => "NEAREST_MIPMAP_NEAREST",
+            Self::LINEAR_MIPMAP_NEAREST => "LINEAR_MIPMAP_NEAREST",
+            Self::NEAREST_MIPMAP_LINEAR => "NEAREST_MIPMAP_LINEAR",
+            Self::LINEAR_MIPMAP_LINEAR => "LINEAR_MIPMAP_LINEAR",
+            _ => return None,
+        })
+    }
+}
+
+#[repr(transparent)]
+#[derive(Copy, Clone, Eq, PartialEq)]
+pub struct TextureWrap(GLenum);
+impl TextureWrap {
+    pub const REPEAT: Self = Self(0x2901);
+    pub const MIRRORED_REPEAT: Self = Self(0x8370);
+    pub const CLAMP_TO_EDGE: Self = Self(0x812F);
+    pub const CLAMP_TO_BORDER: Self = Self(0x812D);
+}
+
+impl std::fmt::Debug for TextureWrap {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if let Some(name) = self.name() {
+            return write!(f, "TextureWrap::{name}");
+        }
+        write!(f, "TextureWrap({:#06X})", self.0)
+    }
+}
+
+impl TextureWrap {
+    /// The name of the constant, `None` for unknown values.
+    const fn name(self) -> Option<&'static str> {
+        Some(match self {
+            Self::REPEAT => "REPEAT",
+            Self::MIRRORED_REPEAT => "MIRRORED_REPEAT",
+            Self::CLAMP_TO_EDGE => "CLAMP_TO_EDGE",
+            Self::CLAMP_TO_BORDER => "CLAMP_TO_BORDER",
+            _ => return None,
+        })
+    }
+}
+
+#[repr(transparent)]
+#[derive(Copy, Clone, Eq, PartialEq)]
+pub struct FramebufferTarget(GLenum);
+impl FramebufferTarget {
+    pub const FRAMEBUFFER: Self = Self(0x8D40);
+    pub const DRAW_FRAMEBUFFER: Self = Self(0x8CA9);
+    pub const READ_FRAMEBUFFER: Self = Self(0x8CA8);
+}
+
+impl std::fmt::Debug for FramebufferTarget {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if let Some(name) = self.name() {
+            return write!(f, "FramebufferTarget::{name}");
+        }
+        write!(f, "FramebufferTarget({:#06X})", self.0)
+    }
+}
+
+impl FramebufferTarget {
+    /// The name of the constant, `None` for unknown values.
+    const fn name(self) -> Option<&'static str> {
+        Some(match self {
+            Self::FRAMEBUFFER => "FRAMEBUFFER",
+            Self::DRAW_FRAMEBUFFER => "DRAW_FRAMEBUFFER",
+            Self::READ_FRAMEBUFFER => "READ_FRAMEBUFFER",
+            _ => return None,
+        })
+    }
+}
+
+#[repr(transparent)]
+#[derive(Copy, Clone, Eq, PartialEq)]
+pub struct RenderbufferTarget(GLenum);
+impl RenderbufferTarget {
+    pub const RENDERBUFFER: Self = Self(0x8D41);
+}
+
+impl std::fmt::Debug for RenderbufferTarget {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if let Some(name) = self.name() {
+            return write!(f, "RenderbufferTarget::{name}");
+        }
+        write!(f, "RenderbufferTarget({:#06X})", self.0)
+    }
+}
+
+impl RenderbufferTarget {
+    /// The name of the constant, `None` for unknown values.
+    const fn name(self) -> Option<&'static str> {
+        Some(match self {
+            Self::RENDERBUFFER => "RENDERBUFFER",
+            _ => return None,
+        })
+    }
+}
+
+#[repr(transparent)]
+#[derive(Copy, Clone, Eq, PartialEq)]
+pub struct Attachment(GLenum);
+impl Attachment {
+    pub const COLOR0: Self = Self(0x8CE0);
+    pub const DEPTH: Self = Self(0x8D00);
+    pub const STENCIL: Self = Self(0x8D20);
+    pub const DEPTH_STENCIL: Self = Self(0x821A);
+}
+
+impl std::fmt::Debug for Attachment {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if let Some(name) = self.name() {
+            return write!(f, "Attachment::{name}");
+        }
+        write!(f, "Attachment({:#06X})", self.0)
+    }
+}
+
+impl Attachment {
+    /// The name of the constant, `None` for unknown values.
+    const fn name(self) -> Option<&'static str> {
+        Some(match self {
+            Self::COLOR0 => "COLOR0",
+            Self::DEPTH => "DEPTH",
+            Self::STENCIL => "STENCIL",
+            Self::DEPTH_STENCIL => "DEPTH_STENCIL",
+            _ => return None,
+        })
+    }
+}
+
+#[repr(transparent)]
+#[derive(Copy, Clone, Eq, PartialEq)]
+pub struct ColorBuffer(GLenum);
+impl ColorBuffer {
+    pub const NONE: Self = Self(0);
+    pub const FRONT: Self = Self(0x0404);
+    pub const BACK: Self = Self(0x0405);
+    pub const LEFT: Self = Self(0x0406);
+    pub const RIGHT: Self = Self(0x0407);
+    pub const FRONT_LEFT: Self = Self(0x0400);
+    pub const FRONT_RIGHT: Self = Self(0x0401);
+    pub const BACK_LEFT: Self = Self(0x0402);
+    pub const BACK_RIGHT: Self = Self(0x0403);
+    pub const COLOR_ATTACHMENT0: Self = Self(0x8CE0);
+}
+
+impl std::fmt::Debug for ColorBuffer {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if let Some(name) = self.name() {
+            return write!(f, "ColorBuffer::{name}");
+        }
+        write!(f, "ColorBuffer({:#06X})", self.0)
+    }
+}
+
+impl ColorBuffer {
+    /// The name of the constant, `None` for unknown values.
+    const fn name(self) -> Option<&'static str> {
+        Some(match self {
+            Self::NONE => "NONE",
+            Self::FRONT => "FRONT",
+            Self::BACK => "BACK",
+            Self::LEFT => "LEFT",
+            Self::RIGHT => "RIGHT",
+            Self::FRONT_LEFT => "FRONT_LEFT",
+            Self::FRONT_RIGHT => "FRONT_RIGHT",
+            Self::BACK_LEFT => "BACK_LEFT",
+            Self::BACK_RIGHT => "BACK_RIGHT",
+            Self::COLOR_ATTACHMENT0 => "COLOR_ATTACHMENT0",
+            _ => return None,
+        })
+    }
+}
+
+#[repr(transparent)]
+#[derive(Copy, Clone, Eq, PartialEq)]
+pub struct FramebufferStatus(GLenum);
+impl FramebufferStatus {
+    pub const COMPLETE: Self = Self(0x8CD5);
+    pub const UNDEFINED: Self = Self(0x8219);
+    pub const INCOMPLETE_ATTACHMENT: Self = Self(0x8CD6);
+    pub const INCOMPLETE_MISSING_ATTACHMENT: Self = Self(0x8CD7);
+    pub const INCOMPLETE_DRAW_BUFFER: Self = Self(0x8CDB);
+    pub const INCOMPLETE_READ_BUFFER: Self = Self(0x8CDC);
+    pub const UNSUPPORTED: Self = Self(0x8CDD);
+    pub const INCOMPLETE_MULTISAMPLE: Self = Self(0x8D56);
+    pub const INCOMPLETE_LAYER_TARGETS: Self = Self(0x8DA8);
+}
+
+impl std::fmt::Debug for FramebufferStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if let Some(name) = self.name() {
+            return write!(f, "FramebufferStatus::{name}");
+        }
+        write!(f, "FramebufferStatus({:#06X})", self.0)
+    }
+}
+
+impl FramebufferStatus {
+    /// The name of the constant, `None` for unknown values.
+    const fn name(self) -> Option<&'static str> {
+        Some(match self {
+            Self::COMPLETE => "COMPLETE",
+            Self::UNDEFINED => "UNDEFINED",
+            Self::INCOMPLETE_ATTACHMENT => "INCOMPLETE_ATTACHMENT",
+            Self::INCOMPLETE_MISSING_ATTACHMENT => "INCOMPLETE_MISSING_ATTACHMENT",
+            Self::INCOMPLETE_DRAW_BUFFER => "INCOMPLETE_DRAW_BUFFER",
+            Self::INCOMPLETE_READ_BUFFER => "INCOMPLETE_READ_BUFFER",
+            Self::UNSUPPORTED => "UNSUPPORTED",
+            Self::INCOMPLETE_MULTISAMPLE => "INCOMPLETE_MULTISAMPLE",
+            Self::INCOMPLETE_LAYER_TARGETS => "INCOMPLETE_LAYER_TARGETS",
+            _ => return None,
+        })
+    }
+}
+
+#[repr(transparent)]
+#[derive(Copy, Clone, Eq, PartialEq)]
+pub struct ShaderParameter(GLenum);
+impl ShaderParameter {
+    //GL 3.3
+    pub const SHADER_TYPE: Self = Self(0x8B4F);
+    pub const DELETE_STATUS: Self = Self(0x8B80);
+    pub const COMPILE_STATUS: Self = Self(0x8B81);
+    pub const INFO_LOG_LENGTH: Self = Self(0x8B84);
+    pub const SHADER_SOURCE_LENGTH: Self = Self(0x8B88);
+
+    //since 4.6
+    pub const SPIR_V_BINARY: Self = Self(0x9552);
+}
+
+impl std::fmt::Debug for ShaderParameter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if let Some(name) = self.name() {
+            return write!(f, "ShaderParameter::{name}");
+        }
+        write!(f, "ShaderParameter({:#06X})", self.0)
+    }
+}
+
+impl ShaderParameter {
+    /// The name of the constant, `None` for unknown values.
+    const fn name(self) -> Option<&'static str> {
+        Some(match self {
+            Self::SHADER_TYPE => "SHADER_TYPE",
+            Self::DELETE_STATUS => "DELETE_STATUS",
+            Self::COMPILE_STATUS => "COMPILE_STATUS",
+            Self::INFO_LOG_LENGTH => "INFO_LOG_LENGTH",
+            Self::SHADER_SOURCE_LENGTH => "SHADER_SOURCE_LENGTH",
+            Self::SPIR_V_BINARY => "SPIR_V_BINARY",
+            _ => return None,
+        })
+    }
+}
+
+#[repr(transparent)]
+#[derive(Copy, Clone, Eq, PartialEq)]
+pub struct ProgramParameter(GLenum);
+impl ProgramParameter {
+    //GL 3.3
+    pub const DELETE_STATUS: Self = Self(0x8B80);
+    pub const LINK_STATUS: Self = Self(0x8B82);
+    pub const VALIDATE_STATUS: Self = Self(0x8B83);
+    pub const INFO_LOG_LENGTH: Self = Self(0x8B84);
+    pub const ATTACHED_SHADERS: Self = Self(0x8B85);
+    pub const ACTIVE_ATTRIBUTES: Self = Self(0x8B89);
+    pub const ACTIVE_ATTRIBUTE_MAX_LENGTH: Self = Self(0x8B8A);
+    pub const ACTIVE_UNIFORMS: Self = Self(0x8B86);
+    pub const ACTIVE_UNIFORM_MAX_LENGTH: Self = Self(0x8B87);
+    pub const ACTIVE_UNIFORM_BLOCKS: Self = Self(0x8A36);
+    pub const ACTIVE_UNIFORM_BLOCK_MAX_NAME_LENGTH: Self = Self(0x8A35);
+    pub const TRANSFORM_FEEDBACK_BUFFER_MODE: Self = Self(0x8C7F);
+    pub const TRANSFORM_FEEDBACK_VARYINGS: Self = Self(0x8C83);
+    pub const TRANSFORM_FEEDBACK_VARYING_MAX_LENGTH: Self = Self(0x8C76);
+
+    //since 4.1
+    pub const PROGRAM_BINARY_LENGTH: Self = Self(0x8741);
+
+    //since 4.3
+    pub const COMPUTE_WORK_GROUP_SIZE: Self = Self(0x8267);
+}
+
+impl std::fmt::Debug for ProgramParameter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if let Some(name) = self.name() {
+            return write!(f, "ProgramParameter::{name}");
+        }
+        write!(f, "ProgramParameter({:#06X})", self.0)
+    }
+}
+
+impl ProgramParameter {
+    /// The name of the constant, `None` for unknown values.
+    const fn name(self) -> Option<&'static str> {
+        Some(match self {
+            Self::DELETE_STATUS => "DELETE_STATUS",
+            Self::LINK_STATUS => "LINK_STATUS",
+            Self::VALIDATE_STATUS => "VALIDATE_STATUS",
+            Self::INFO_LOG_LENGTH => "INFO_LOG_LENGTH",
+            Self::ATTACHED_SHADERS => "ATTACHED_SHADERS",
+            Self::ACTIVE_ATTRIBUTES => "ACTIVE_ATTRIBUTES",
+            Self::ACTIVE_ATTRIBUTE_MAX_LENGTH => "ACTIVE_ATTRIBUTE_MAX_LENGTH",
+            Self::ACTIVE_UNIFORMS => "ACTIVE_UNIFORMS",
+            Self::ACTIVE_UNIFORM_MAX_LENGTH => "ACTIVE_UNIFORM_MAX_LENGTH",
+            Self::ACTIVE_UNIFORM_BLOCKS => "ACTIVE_UNIFORM_BLOCKS",
+            Self::ACTIVE_UNIFORM_BLOCK_MAX_NAME_LENGTH => "ACTIVE_UNIFORM_BLOCK_MAX_NAME_LENGTH",
+            Self::TRANSFORM_FEEDBACK_BUFFER_MODE => "TRANSFORM_FEEDBACK_BUFFER_MODE",
+            Self::TRANSFORM_FEEDBACK_VARYINGS => "TRANSFORM_FEEDBACK_VARYINGS",
+            Self::TRANSFORM_FEEDBACK_VARYING_MAX_LENGTH => "TRANSFORM_FEEDBACK_VARYING_MAX_LENGTH",
+            Self::PROGRAM_BINARY_LENGTH => "PROGRAM_BINARY_LENGTH",
+            Self::COMPUTE_WORK_GROUP_SIZE => "COMPUTE_WORK_GROUP_SIZE",
+            _ => return None,
+        })
+    }
+}
+
+#[repr(transparent)]
+#[derive(Copy, Clone, Eq, PartialEq)]
+pub struct UniformKind(GLenum);
+impl UniformKind {
+    //GL 3.3
+    pub const FLOAT: Self = Self(0x1406);
+    pub const FLOAT_VEC2: Self = Self(0x8B50);
+    pub const FLOAT_VEC3: Self = Self(0x8B51);
+    pub const FLOAT_VEC4: Self = Self(0x8B52);
+    pub const DOUBLE: Self = Self(0x140A);
+    pub const INT: Self = Self(0x1404);
+    pub const INT_VEC2: Self = Self(0x8B53);
+    pub const INT_VEC3: Self = Self(0x8B54);
+    pub const INT_VEC4: Self = Self(0x8B55);
+    pub const UNSIGNED_INT: Self = Self(0x1405);
+    pub const UNSIGNED_INT_VEC2: Self = Self(0x8DC6);
+    pub const UNSIGNED_INT_VEC3: Self = Self(0x8DC7);
+    pub const UNSIGNED_INT_VEC4: Self = Self(0x8DC8);
+    pub const BOOL: Self = Self(0x8B56);
+    pub const BOOL_VEC2: Self = Self(0x8B57);
+    pub const BOOL_VEC3: Self = Self(0x8B58);
+    pub const BOOL_VEC4: Self = Self(0x8B59);
+    pub const FLOAT_MAT2: Self = Self(0x8B5A);
+    pub const FLOAT_MAT3: Self = Self(0x8B5B);
+    pub const FLOAT_MAT4: Self = Self(0x8B5C);
+    pub const FLOAT_MAT2X3: Self = Self(0x8B65);
+    pub const FLOAT_MAT2X4: Self = Self(0x8B66);
+    pub const FLOAT_MAT3X2: Self = Self(0x8B67);
+    pub const FLOAT_MAT3X4: Self = Self(0x8B68);
+    pub const FLOAT_MAT4X2: Self = Self(0x8B69);
+    pub const FLOAT_MAT4X3: Self = Self(0x8B6A);
+    pub const SAMPLER_1D: Self = Self(0x8B5D);
+    pub const SAMPLER_2D: Self = Self(0x8B5E);
+    pub const SAMPLER_3D: Self = Self(0x8B5F);
+    pub const SAMPLER_CUBE: Self = Self(0x8B60);
+    pub const SAMPLER_1D_SHADOW: Self = Self(0x8B61);
+    pub const SAMPLER_2D_SHADOW: Self = Self(0x8B62);
+    pub const SAMPLER_1D_ARRAY: Self = Self(0x8DC0);
+    pub const SAMPLER_2D_ARRAY: Self = Self(0x8DC1);
+    pub const SAMPLER_2D_ARRAY_SHADOW: Self = Self(0x8DC4);
+    pub const SAMPLER_CUBE_SHADOW: Self = Self(0x8DC5);
+    pub const SAMPLER_2D_MULTISAMPLE: Self = Self(0x9108);
+    pub const SAMPLER_2D_RECT: Self = Self(0x8B63);
+    pub const SAMPLER_BUFFER: Self = Self(0x8DC2);
+    pub const INT_SAMPLER_2D: Self = Self(0x8DCA);
+    pub const INT_SAMPLER_3D: Self = Self(0x8DCB);
+    pub const INT_SAMPLER_CUBE: Self = Self(0x8DCC);
+    pub const INT_SAMPLER_2D_ARRAY: Self = Self(0x8DCF);
+    pub const UNSIGNED_INT_SAMPLER_2D: Self = Self(0x8DD2);
+    pub const UNSIGNED_INT_SAMPLER_3D: Self = Self(0x8DD3);
+    pub const UNSIGNED_INT_SAMPLER_CUBE: Self = Self(0x8DD4);
+    pub const UNSIGNED_INT_SAMPLER_2D_ARRAY: Self = Self(0x8DD7);
+
+    //since 4.0
+    pub const DOUBLE_VEC2: Self = Self(0x8FFC);
+    pub const DOUBLE_VEC3: Self = Self(0x8FFD);
+    pub const DOUBLE_VEC4: Self = Self(0x8FFE);
+    pub const SAMPLER_CUBE_MAP_ARRAY: Self = Self(0x900C);
+
+    //since 4.2
+    pub const IMAGE_2D: Self = Self(0x904D);
+    pub const IMAGE_3D: Self = Self(0x904E);
+    pub const IMAGE_2D_ARRAY: Self = Self(0x9053);
+    pub const IMAGE_CUBE: Self = Self(0x9050);
+    pub const UNSIGNED_INT_ATOMIC_COUNTER: Self = Self(0x92DB);
+}
+
+impl std::fmt::Debug for UniformKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if let Some(name) = self.name() {
+            return write!(f, "UniformKind::{name}");
+        }
+        write!(f, "UniformKind({:#06X})", self.0)
+    }
+}
+
+impl UniformKind {
+    /// The name of the constant, `None` for unknown values.
+    const fn name(self) -> Option<&'static str> {
+        Some(match self {
+            Self::FLOAT => "FLOAT",
+            Self::FLOAT_VEC2 => "FLOAT_VEC2",
+            Self::FLOAT_VEC3 => "FLOAT_VEC3",
+            Self::FLOAT_VEC4 => "FLOAT_VEC4",
+            Self::DOUBLE => "DOUBLE",
+            Self::INT => "INT",
+            Self::INT_VEC2 => "INT_VEC2",
+            Self::INT_VEC3 => "INT_VEC3",
+            Self::INT_VEC4 => "INT_VEC4",
+            Self::UNSIGNED_INT => "UNSIGNED_INT",
+            Self::UNSIGNED_INT_VEC2 => "UNSIGNED_INT_VEC2",
+            Self::UNSIGNED_INT_VEC3 => "UNSIGNED_INT_VEC3",
+            Self::UNSIGNED_INT_VEC4 => "UNSIGNED_INT_VEC4",
+            Self::BOOL => "BOOL",
+            Self::BOOL_VEC2 => "BOOL_VEC2",
+            Self::BOOL_VEC3 => "BOOL_VEC3",
+            Self::BOOL_VEC4 => "BOOL_VEC4",
+            Self::FLOAT_MAT2 => "FLOAT_MAT2",
+            Self::FLOAT_MAT3 => "FLOAT_MAT3",
+            Self::FLOAT_MAT4 => "FLOAT_MAT4",
+            Self::FLOAT_MAT2X3 => "FLOAT_MAT2X3",
+            Self::FLOAT_MAT2X4 => "FLOAT_MAT2X4",
+            Self::FLOAT_MAT3X2 => "FLOAT_MAT3X2",
+            Self::FLOAT_MAT3X4 => "FLOAT_MAT3X4",
+            Self::FLOAT_MAT4X2 => "FLOAT_MAT4X2",
+            Self::FLOAT_MAT4X3 => "FLOAT_MAT4X3",
+            Self::SAMPLER_1D => "SAMPLER_1D",
+            Self::SAMPLER_2D => "SAMPLER_2D",
+            Self::SAMPLER_3D => "SAMPLER_3D",
+            Self::SAMPLER_CUBE => "SAMPLER_CUBE",
+            Self::SAMPLER_1D_SHADOW => "SAMPLER_1D_SHADOW",
+            Self::SAMPLER_2D_SHADOW => "SAMPLER_2D_SHADOW",
+            Self::SAMPLER_1D_ARRAY => "SAMPLER_1D_ARRAY",
+            Self::SAMPLER_2D_ARRAY => "SAMPLER_2D_ARRAY",
+            Self::SAMPLER_2D_ARRAY_SHADOW => "SAMPLER_2D_ARRAY_SHADOW",
+            Self::SAMPLER_CUBE_SHADOW => "SAMPLER_CUBE_SHADOW",
+            Self::SAMPLER_2D_MULTISAMPLE => "SAMPLER_2D_MULTISAMPLE",
+            Self::SAMPLER_2D_RECT => "SAMPLER_2D_RECT",
+            Self::SAMPLER_BUFFER => "SAMPLER_BUFFER",
+            Self::INT_SAMPLER_2D => "INT_SAMPLER_2D",
+            Self::INT_SAMPLER_3D => "INT_SAMPLER_3D",
+            Self::INT_SAMPLER_CUBE => "INT_SAMPLER_CUBE",
+            Self::INT_SAMPLER_2D_ARRAY => "INT_SAMPLER_2D_ARRAY",
+            Self::UNSIGNED_INT_SAMPLER_2D => "UNSIGNED_INT_SAMPLER_2D",
+            Self::UNSIGNED_INT_SAMPLER_3D => "UNSIGNED_INT_SAMPLER_3D",
+            Self::UNSIGNED_INT_SAMPLER_CUBE => "UNSIGNED_INT_SAMPLER_CUBE",
+            Self::UNSIGNED_INT_SAMPLER_2D_ARRAY => "UNSIGNED_INT_SAMPLER_2D_ARRAY",
+            Self::DOUBLE_VEC2 => "DOUBLE_VEC2",
+            Self::DOUBLE_VEC3 => "DOUBLE_VEC3",
+            Self::DOUBLE_VEC4 => "DOUBLE_VEC4",
+            Self::SAMPLER_CUBE_MAP_ARRAY => "SAMPLER_CUBE_MAP_ARRAY",
+            Self::IMAGE_2D => "IMAGE_2D",
+            Self::IMAGE_3D => "IMAGE_3D",
+            Self::IMAGE_2D_ARRAY => "IMAGE_2D_ARRAY",
+            Self::IMAGE_CUBE => "IMAGE_CUBE",
+            Self::UNSIGNED_INT_ATOMIC_COUNTER => "UNSIGNED_INT_ATOMIC_COUNTER",
+            _ => return None,
+        })
+    }
+}
+
+#[repr(transparent)]
+#[derive(Copy, Clone, Eq, PartialEq)]
+pub struct ProgramInterface(GLenum);
+impl ProgramInterface {
+    //GL 4.2
+    pub const ATOMIC_COUNTER_BUFFER: Self = Self(0x92C0);
+
+    //since 4.3
+    pub const UNIFORM: Self = Self(0x92E1);
+    pub const UNIFORM_BLOCK: Self = Self(0x92E2);
+    pub const PROGRAM_INPUT: Self = Self(0x92E3);
+    pub const PROGRAM_OUTPUT: Self = Self(0x92E4);
+    pub const BUFFER_VARIABLE: Self = Self(0x92E5);
+    pub const SHADER_STORAGE_BLOCK: Self = Self(0x92E6);
+    pub const TRANSFORM_FEEDBACK_VARYING: Self = Self(0x92F4);
+}
+
+impl std::fmt::Debug for ProgramInterface {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if let Some(name) = self.name() {
+            return write!(f, "ProgramInterface::{name}");
+        }
+        write!(f, "ProgramInterface({:#06X})", self.0)
+    }
+}
+
+impl ProgramInterface {
+    /// The name of the constant, `None` for unknown values.
+    const fn name(self) -> Option<&'static str> {
+        Some(match self {
+            Self::ATOMIC_COUNTER_BUFFER => "ATOMIC_COUNTER_BUFFER",
+            Self::UNIFORM => "UNIFORM",
+            Self::UNIFORM_BLOCK => "UNIFORM_BLOCK",
+            Self::PROGRAM_INPUT => "PROGRAM_INPUT",
+            Self::PROGRAM_OUTPUT => "PROGRAM_OUTPUT",
+            Self::BUFFER_VARIABLE => "BUFFER_VARIABLE",
+            Self::SHADER_STORAGE_BLOCK => "SHADER_STORAGE_BLOCK",
+            Self::TRANSFORM_FEEDBACK_VARYING => "TRANSFORM_FEEDBACK_VARYING",
+            _ => return None,
+        })
+    }
+}
+
+#[repr(transparent)]
+#[derive(Copy, Clone, Eq, PartialEq)]
+pub struct ProgramInterfaceParameter(GLenum);
+impl ProgramInterfaceParameter {
+    pub const ACTIVE_RESOURCES: Self = Self(0x92F5);
+    pub const MAX_NAME_LENGTH: Self = Self(0x92F6);
+    pub const MAX_NUM_ACTIVE_VARIABLES: Self = Self(0x92F7);
+    pub const MAX_NUM_COMPATIBLE_SUBROUTINES: Self = Self(0x92F8);
+}
+
+impl std::fmt::Debug for ProgramInterfaceParameter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if let Some(name) = self.name() {
+            return write!(f, "ProgramInterfaceParameter::{name}");
+        }
+        write!(f, "ProgramInterfaceParameter({:#06X})", self.0)
+    }
+}
+
+impl ProgramInterfaceParameter {
+    /// The name of the constant, `None` for unknown values.
+    const fn name(self) -> Option<&'static str> {
+        Some(match self {
+            Self::ACTIVE_RESOURCES => "ACTIVE_RESOURCES",
+            Self::MAX_NAME_LENGTH => "MAX_NAME_LENGTH",
+            Self::MAX_NUM_ACTIVE_VARIABLES => "MAX_NUM_ACTIVE_VARIABLES",
+            Self::MAX_NUM_COMPATIBLE_SUBROUTINES => "MAX_NUM_COMPATIBLE_SUBROUTINES",
+            _ => return None,
+        })
+    }
+}
+
+#[repr(transparent)]
+#[derive(Copy, Clone, Eq, PartialEq)]
+pub struct DebugSource(GLenum);
+impl DebugSource {
+    //GL 3.3
+    pub const DONT_CARE: Self = Self(0x1100);
+
+    //since 4.3
+    pub const API: Self = Self(0x8246);
+    pub const WINDOW_SYSTEM: Self = Self(0x8247);
+    pub const SHADER_COMPILER: Self = Self(0x8248);
+    pub const THIRD_PARTY: Self = Self(0x8249);
+    pub const APPLICATION: Self = Self(0x824A);
+    pub const OTHER: Self = Self(0x824B);
+}
+
+impl std::fmt::Debug for DebugSource {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if let Some(name) = self.name() {
+            return write!(f, "DebugSource::{name}");
+        }
+        write!(f, "DebugSource({:#06X})", self.0)
+    }
+}
+
+impl DebugSource {
+    /// The name of the constant, `None` for unknown values.
+    const fn name(self) -> Option<&'static str> {
+        Some(match self {
+            Self::DONT_CARE => "DONT_CARE",
+            Self::API => "API",
+            Self::WINDOW_SYSTEM => "WINDOW_SYSTEM",
+            Self::SHADER_COMPILER => "SHADER_COMPILER",
+            Self::THIRD_PARTY => "THIRD_PARTY",
+            Self::APPLICATION => "APPLICATION",
+            Self::OTHER => "OTHER",
+            _ => return None,
+        })
+    }
+}
+
+#[repr(transparent)]
+#[derive(Copy, Clone, Eq, PartialEq)]
+pub struct DebugType(GLenum);
+impl DebugType {
+    //GL 3.3
+    pub const DONT_CARE: Self = Self(0x1100);
+
+    //since 4.3
+    pub const ERROR: Self = Self(0x824C);
+    pub const DEPRECATED_BEHAVIOUR: Self = Self(0x824D);
+    pub const UNDEFINED_BEHAVIOUR: Self = Self(0x824E);
+    pub const PORTABILITY: Self = Self(0x824F);
+    pub const PERFORMANCE: Self = Self(0x8250);
+    pub const OTHER: Self = Self(0x8251);
+    pub const MARKER: Self = Self(0x8268);
+    pub const PUSH_GROUP: Self = Self(0x8269);
+    pub const POP_GROUP: Self = Self(0x826A);
+}
+
+impl std::fmt::Debug for DebugType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if let Some(name) = self.name() {
+            return write!(f, "DebugType::{name}");
+        }
+        write!(f, "DebugType({:#06X})", self.0)
+    }
+}
+
+impl DebugType {
+    /// The name of the constant, `None` for unknown values.
+    const fn name(self) -> Option<&'static str> {
+        Some(match self {
+            Self::DONT_CARE => "DONT_CARE",
+            Self::ERROR => "ERROR",
+            Self::DEPRECATED_BEHAVIOUR => "DEPRECATED_BEHAVIOUR",
+            Self::UNDEFINED_BEHAVIOUR => "UNDEFINED_BEHAVIOUR",
+            Self::PORTABILITY => "PORTABILITY",
+            Self::PERFORMANCE => "PERFORMANCE",
+            Self::OTHER => "OTHER",
+            Self::MARKER => "MARKER",
+            Self::PUSH_GROUP => "PUSH_GROUP",
+            Self::POP_GROUP => "POP_GROUP",
+            _ => return None,
+        })
+    }
+}
+
+#[repr(transparent)]
+#[derive(Copy, Clone, Eq, PartialEq)]
+pub struct DebugSeverity(GLenum);
+impl DebugSeverity {
+    //GL 3.3
+    pub const DONT_CARE: Self = Self(0x1100);
+
+    //since 4.3
+    pub const HIGH: Self = Self(0x9146);
+    pub const MEDIUM: Self = Self(0x9147);
+    pub const LOW: Self = Self(0x9148);
+    pub const NOTIFICATION: Self = Self(0x826B);
+}
+
+impl std::fmt::Debug for DebugSeverity {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if let Some(name) = self.name() {
+            return write!(f, "DebugSeverity::{name}");
+        }
+        write!(f, "DebugSeverity({:#06X})", self.0)
+    }
+}
+
+impl DebugSeverity {
+    /// The name of the constant, `None` for unknown values.
+    const fn name(self) -> Option<&'static str> {
+        Some(match self {
+            Self::DONT_CARE => "DONT_CARE",
+            Self::HIGH => "HIGH",
+            Self::MEDIUM => "MEDIUM",
+            Self::LOW => "LOW",
+            Self::NOTIFICATION => "NOTIFICATION",
+            _ => return None,
+        })
+    }
+}
+
+#[repr(transparent)]
+#[derive(Copy, Clone, Eq, PartialEq)]
+pub struct ObjectIdentifier(GLenum);
+impl ObjectIdentifier {
+    //GL 3.3
+    pub const TEXTURE: Self = Self(0x1702);
+    pub const RENDERBUFFER: Self = Self(0x8D41);
+    pub const FRAMEBUFFER: Self = Self(0x8D40);
+
+    //since 4.0
+    pub const TRANSFORM_FEEDBACK: Self = Self(0x8E22);
+
+    //since 4.3
+    pub const BUFFER: Self = Self(0x82E0);
+    pub const SHADER: Self = Self(0x82E1);
+    pub const PROGRAM: Self = Self(0x82E2);
+    pub const VERTEX_ARRAY: Self = Self(0x8074);
+    pub const QUERY: Self = Self(0x82E3);
+    pub const PROGRAM_PIPELINE: Self = Self(0x82E4);
+    pub const SAMPLER: Self = Self(0x82E6);
+}
+
+impl std::fmt::Debug for ObjectIdentifier {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if let Some(name) = self.name() {
+            return write!(f, "ObjectIdentifier::{name}");
+        }
+        write!(f, "ObjectIdentifier({:#06X})", self.0)
+    }
+}
+
+impl ObjectIdentifier {
+    /// The name of the constant, `None` for unknown values.
+    const fn name(self) -> Option<&'static str> {
+        Some(match self {
+            Self::TEXTURE => "TEXTURE",
+            Self::RENDERBUFFER => "RENDERBUFFER",
+            Self::FRAMEBUFFER => "FRAMEBUFFER",
+            Self::TRANSFORM_FEEDBACK => "TRANSFORM_FEEDBACK",
+            Self::BUFFER => "BUFFER",
+            Self::SHADER => "SHADER",
+            Self::PROGRAM => "PROGRAM",
+            Self::VERTEX_ARRAY => "VERTEX_ARRAY",
+            Self::QUERY => "QUERY",
+            Self::PROGRAM_PIPELINE => "PROGRAM_PIPELINE",
+            Self::SAMPLER => "SAMPLER",
+            _ => return None,
+        })
+    }
+}
+
+#[repr(transparent)]
+#[derive(Copy, Clone, Eq, PartialEq)]
+pub struct ErrorCode(GLenum);
+impl ErrorCode {
+    //GL 3.3
+    pub const NO_ERROR: Self = Self(0);
+    pub const INVALID_ENUM: Self = Self(0x0500);
+    pub const INVALID_VALUE: Self = Self(0x0501);
+    pub const INVALID_OPERATION: Self = Self(0x0502);
+    pub const OUT_OF_MEMORY: Self = Self(0x0505);
+    pub const INVALID_FRAMEBUFFER_OPERATION: Self = Self(0x0506);
+
+    //since 4.3
+    pub const STACK_OVERFLOW: Self = Self(0x0503);
+    pub const STACK_UNDERFLOW: Self = Self(0x0504);
+
+    //since 4.5
+    pub const CONTEXT_LOST: Self = Self(0x0507);
+}
+
+impl std::fmt::Debug for ErrorCode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if let Some(name) = self.name() {
+            return write!(f, "ErrorCode::{name}");
+        }
+        write!(f, "ErrorCode({:#06X})", self.0)
+    }
+}
+
+impl ErrorCode {
+    /// The name of the constant, `None` for unknown values.
+    const fn name(self) -> Option<&'static str> {
+        Some(match self {
+            Self::NO_ERROR => "NO_ERROR",
+            Self::INVALID_ENUM => "INVALID_ENUM",
+            Self::INVALID_VALUE => "INVALID_VALUE",
+            Self::INVALID_OPERATION => "INVALID_OPERATION",
+            Self::OUT_OF_MEMORY => "OUT_OF_MEMORY",
+            Self::INVALID_FRAMEBUFFER_OPERATION => "INVALID_FRAMEBUFFER_OPERATION",
+            Self::STACK_OVERFLOW => "STACK_OVERFLOW",
+            Self::STACK_UNDERFLOW => "STACK_UNDERFLOW",
+            Self::CONTEXT_LOST => "CONTEXT_LOST",
+            _ => return None,
+        })
+    }
+}
@@ -0,0 +1,17 @@
+pub use super::types::{
+    GLbitField, GLboolean, GLchar, GLdouble, GLenum, GLfloat, GLint, GLintptr, GLsizei, GLsizeiptr,
+    GLuint,
+};
+
+// handles and enums shared by all desktop versions, see `cac_gl_generator`
+include!("generated.rs");
+include!("../common.rs");
+
+// OpenGL ES only has user clip planes through an extension
+impl Capability {
+    /// The user clip plane `CLIP_DISTANCE0 + index`, written through `gl_ClipDistance[index]`.
+    #[must_use]
+    pub const fn clip_distance(index: GLuint) -> Self {
+        Self(Self::CLIP_DISTANCE0.0 + index)
+    }
+}
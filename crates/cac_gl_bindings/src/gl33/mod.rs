@@ -0,0 +1,6 @@
+pub use crate::gl::*;
+use crate::{not_loaded, Error, Loader};
+
+// `Api` and its wrappers for everything new in OpenGL 3.3, see `cac_gl_generator`
+include!("generated.rs");
+include!("../checks.rs");
@@ -0,0 +1,1050 @@
+// This file is generated by `cac_gl_generator` from the Khronos registry, do not edit it by hand.
+// Change `crates/cac_gl_generator/allowlist.toml` and run `cargo run -p cac_gl_generator` instead.
+
+/// Bindings to a curated subset of OpenGL 4.3
+///
+/// Everything up to OpenGL 3.3 is available through `Deref` to `gl33::Api`.
+#[allow(clippy::struct_field_names)]
+pub struct Api {
+    base: super::gl33::Api,
+
+    debug_handler: DebugRegistration,
+
+    //debug
+    debug_message_callback_ptr: Option<
+        unsafe extern "system" fn(
+            callback: Option<DebugMessageCallback>,
+            user_param: *const std::ffi::c_void,
+        ),
+    >,
+    debug_message_control_ptr: Option<
+        unsafe extern "system" fn(
+            source: DebugSource,
+            kind: DebugType,
+            severity: DebugSeverity,
+            count: GLsizei,
+            ids: *const GLuint,
+            enabled: GLboolean,
+        ),
+    >,
+    debug_message_insert_ptr: Option<
+        unsafe extern "system" fn(
+            source: DebugSource,
+            kind: DebugType,
+            id: GLuint,
+            severity: DebugSeverity,
+            length: GLsizei,
+            buf: *const GLchar,
+        ),
+    >,
+    get_debug_message_log_ptr: Option<
+        unsafe extern "system" fn(
+            count: GLuint,
+            buf_size: GLsizei,
+            sources: *mut DebugSource,
+            types: *mut DebugType,
+            ids: *mut GLuint,
+            severities: *mut DebugSeverity,
+            lengths: *mut GLsizei,
+            message_log: *mut GLchar,
+        ) -> GLuint,
+    >,
+    push_debug_group_ptr: Option<
+        unsafe extern "system" fn(
+            source: DebugSource,
+            id: GLuint,
+            length: GLsizei,
+            message: *const GLchar,
+        ),
+    >,
+    pop_debug_group_ptr: Option<unsafe extern "system" fn()>,
+    object_label_ptr: Option<
+        unsafe extern "system" fn(
+            identifier: ObjectIdentifier,
+            name: GLuint,
+            length: GLsizei,
+            label: *const GLchar,
+        ),
+    >,
+    object_ptr_label_ptr: Option<
+        unsafe extern "system" fn(
+            ptr: *const std::ffi::c_void,
+            length: GLsizei,
+            label: *const GLchar,
+        ),
+    >,
+
+    //state
+    depth_rangef_ptr: Option<unsafe extern "system" fn(n: GLfloat, f: GLfloat)>,
+
+    //draw
+    draw_arrays_instanced_base_instance_ptr: Option<
+        unsafe extern "system" fn(
+            mode: Primitive,
+            first: GLint,
+            count: GLsizei,
+            instancecount: GLsizei,
+            baseinstance: GLuint,
+        ),
+    >,
+    draw_elements_instanced_base_instance_ptr: Option<
+        unsafe extern "system" fn(
+            mode: Primitive,
+            count: GLsizei,
+            kind: IndexKind,
+            indices: *const std::ffi::c_void,
+            instancecount: GLsizei,
+            baseinstance: GLuint,
+        ),
+    >,
+    draw_elements_instanced_base_vertex_base_instance_ptr: Option<
+        unsafe extern "system" fn(
+            mode: Primitive,
+            count: GLsizei,
+            kind: IndexKind,
+            indices: *const std::ffi::c_void,
+            instancecount: GLsizei,
+            basevertex: GLint,
+            baseinstance: GLuint,
+        ),
+    >,
+    draw_arrays_indirect_ptr:
+        Option<unsafe extern "system" fn(mode: Primitive, indirect: *const std::ffi::c_void)>,
+    draw_elements_indirect_ptr: Option<
+        unsafe extern "system" fn(
+            mode: Primitive,
+            kind: IndexKind,
+            indirect: *const std::ffi::c_void,
+        ),
+    >,
+    multi_draw_arrays_indirect_ptr: Option<
+        unsafe extern "system" fn(
+            mode: Primitive,
+            indirect: *const std::ffi::c_void,
+            drawcount: GLsizei,
+            stride: GLsizei,
+        ),
+    >,
+    multi_draw_elements_indirect_ptr: Option<
+        unsafe extern "system" fn(
+            mode: Primitive,
+            kind: IndexKind,
+            indirect: *const std::ffi::c_void,
+            drawcount: GLsizei,
+            stride: GLsizei,
+        ),
+    >,
+
+    //vertex arrays
+    bind_vertex_buffer_ptr: Option<
+        unsafe extern "system" fn(
+            bindingindex: GLuint,
+            buffer: Buffer,
+            offset: GLintptr,
+            stride: GLsizei,
+        ),
+    >,
+    vertex_attrib_format_ptr: Option<
+        unsafe extern "system" fn(
+            attribindex: GLuint,
+            size: GLint,
+            kind: VertexAttributeKind,
+            normalized: GLboolean,
+            relativeoffset: GLuint,
+        ),
+    >,
+    vertex_attrib_binding_ptr:
+        Option<unsafe extern "system" fn(attribindex: GLuint, bindingindex: GLuint)>,
+
+    //textures
+    tex_storage_2d_ptr: Option<
+        unsafe extern "system" fn(
+            target: TextureTarget,
+            levels: GLsizei,
+            internalformat: InternalFormat,
+            width: GLsizei,
+            height: GLsizei,
+        ),
+    >,
+    tex_storage_3d_ptr: Option<
+        unsafe extern "system" fn(
+            target: TextureTarget,
+            levels: GLsizei,
+            internalformat: InternalFormat,
+            width: GLsizei,
+            height: GLsizei,
+            depth: GLsizei,
+        ),
+    >,
+
+    //uniforms
+    get_program_interfaceiv_ptr: Option<
+        unsafe extern "system" fn(
+            program: Program,
+            program_interface: ProgramInterface,
+            pname: ProgramInterfaceParameter,
+            params: *mut GLint,
+        ),
+    >,
+
+    //compute
+    dispatch_compute_ptr: Option<
+        unsafe extern "system" fn(num_groups_x: GLuint, num_groups_y: GLuint, num_groups_z: GLuint),
+    >,
+    dispatch_compute_indirect_ptr: Option<unsafe extern "system" fn(indirect: GLintptr)>,
+    memory_barrier_ptr: Option<unsafe extern "system" fn(barriers: BarrierBits)>,
+}
+
+impl std::ops::Deref for Api {
+    type Target = super::gl33::Api;
+
+    fn deref(&self) -> &super::gl33::Api {
+        &self.base
+    }
+}
+
+#[allow(
+    clippy::let_and_return,
+    clippy::semicolon_if_nothing_returned,
+    clippy::similar_names,
+    clippy::too_many_arguments
+)]
+impl Api {
+    /// Loads all function pointers using a context function loader,
+    /// also called `ProcAddress`. Works with GLFW, SDL2 any pretty much any OpenGL context.
+    ///
+    /// # Errors
+    /// This function will return an error if any function pointer returns a null pointer.
+    ///
+    /// # Safety
+    /// Unfortunately, some drivers return wrong addresses that are indistinguishable from correct
+    /// ones, instead of being null pointers.
+    ///
+    #[allow(clippy::too_many_lines)]
+    pub unsafe fn with_loader(
+        loader: &impl Fn(&str) -> *const std::ffi::c_void,
+    ) -> Result<Self, Error> {
+        Ok(Self {
+            base: unsafe { super::gl33::Api::with_loader(loader)? },
+
+            debug_handler: DebugRegistration::default(),
+
+            //debug
+            debug_message_callback_ptr: Some(loader.load("glDebugMessageCallback")?),
+            debug_message_control_ptr: Some(loader.load("glDebugMessageControl")?),
+            debug_message_insert_ptr: Some(loader.load("glDebugMessageInsert")?),
+            get_debug_message_log_ptr: Some(loader.load("glGetDebugMessageLog")?),
+            push_debug_group_ptr: Some(loader.load("glPushDebugGroup")?),
+            pop_debug_group_ptr: Some(loader.load("glPopDebugGroup")?),
+            object_label_ptr: Some(loader.load("glObjectLabel")?),
+            object_ptr_label_ptr: Some(loader.load("glObjectPtrLabel")?),
+
+            //state
+            depth_rangef_ptr: Some(loader.load("glDepthRangef")?),
+
+            //draw
+            draw_arrays_instanced_base_instance_ptr: Some(
+                loader.load("glDrawArraysInstancedBaseInstance")?,
+            ),
+            draw_elements_instanced_base_instance_ptr: Some(
+                loader.load("glDrawElementsInstancedBaseInstance")?,
+            ),
+            draw_elements_instanced_base_vertex_base_instance_ptr: Some(
+                loader.load("glDrawElementsInstancedBaseVertexBaseInstance")?,
+            ),
+            draw_arrays_indirect_ptr: Some(loader.load("glDrawArraysIndirect")?),
+            draw_elements_indirect_ptr: Some(loader.load("glDrawElementsIndirect")?),
+            multi_draw_arrays_indirect_ptr: Some(loader.load("glMultiDrawArraysIndirect")?),
+            multi_draw_elements_indirect_ptr: Some(loader.load("glMultiDrawElementsIndirect")?),
+
+            //vertex arrays
+            bind_vertex_buffer_ptr: Some(loader.load("glBindVertexBuffer")?),
+            vertex_attrib_format_ptr: Some(loader.load("glVertexAttribFormat")?),
+            vertex_attrib_binding_ptr: Some(loader.load("glVertexAttribBinding")?),
+
+            //textures
+            tex_storage_2d_ptr: Some(loader.load("glTexStorage2D")?),
+            tex_storage_3d_ptr: Some(loader.load("glTexStorage3D")?),
+
+            //uniforms
+            get_program_interfaceiv_ptr: Some(loader.load("glGetProgramInterfaceiv")?),
+
+            //compute
+            dispatch_compute_ptr: Some(loader.load("glDispatchCompute")?),
+            dispatch_compute_indirect_ptr: Some(loader.load("glDispatchComputeIndirect")?),
+            memory_barrier_ptr: Some(loader.load("glMemoryBarrier")?),
+        })
+    }
+
+    /// Like `with_loader`, but keeps going when a function pointer is null, e.g. for entry points
+    /// a driver doesn't expose. Use `is_loaded` or `missing_functions` to find out what is
+    /// available, calling a wrapper whose function is missing panics.
+    ///
+    /// # Safety
+    /// See the safety note in `with_loader`
+    #[must_use]
+    #[allow(clippy::too_many_lines)]
+    pub unsafe fn with_loader_partial(loader: &impl Fn(&str) -> *const std::ffi::c_void) -> Self {
+        Self {
+            base: unsafe { super::gl33::Api::with_loader_partial(loader) },
+
+            debug_handler: DebugRegistration::default(),
+
+            //debug
+            debug_message_callback_ptr: loader.load("glDebugMessageCallback").ok(),
+            debug_message_control_ptr: loader.load("glDebugMessageControl").ok(),
+            debug_message_insert_ptr: loader.load("glDebugMessageInsert").ok(),
+            get_debug_message_log_ptr: loader.load("glGetDebugMessageLog").ok(),
+            push_debug_group_ptr: loader.load("glPushDebugGroup").ok(),
+            pop_debug_group_ptr: loader.load("glPopDebugGroup").ok(),
+            object_label_ptr: loader.load("glObjectLabel").ok(),
+            object_ptr_label_ptr: loader.load("glObjectPtrLabel").ok(),
+
+            //state
+            depth_rangef_ptr: loader.load("glDepthRangef").ok(),
+
+            //draw
+            draw_arrays_instanced_base_instance_ptr: loader
+                .load("glDrawArraysInstancedBaseInstance")
+                .ok(),
+            draw_elements_instanced_base_instance_ptr: loader
+                .load("glDrawElementsInstancedBaseInstance")
+                .ok(),
+            draw_elements_instanced_base_vertex_base_instance_ptr: loader
+                .load("glDrawElementsInstancedBaseVertexBaseInstance")
+                .ok(),
+            draw_arrays_indirect_ptr: loader.load("glDrawArraysIndirect").ok(),
+            draw_elements_indirect_ptr: loader.load("glDrawElementsIndirect").ok(),
+            multi_draw_arrays_indirect_ptr: loader.load("glMultiDrawArraysIndirect").ok(),
+            multi_draw_elements_indirect_ptr: loader.load("glMultiDrawElementsIndirect").ok(),
+
+            //vertex arrays
+            bind_vertex_buffer_ptr: loader.load("glBindVertexBuffer").ok(),
+            vertex_attrib_format_ptr: loader.load("glVertexAttribFormat").ok(),
+            vertex_attrib_binding_ptr: loader.load("glVertexAttribBinding").ok(),
+
+            //textures
+            tex_storage_2d_ptr: loader.load("glTexStorage2D").ok(),
+            tex_storage_3d_ptr: loader.load("glTexStorage3D").ok(),
+
+            //uniforms
+            get_program_interfaceiv_ptr: loader.load("glGetProgramInterfaceiv").ok(),
+
+            //compute
+            dispatch_compute_ptr: loader.load("glDispatchCompute").ok(),
+            dispatch_compute_indirect_ptr: loader.load("glDispatchComputeIndirect").ok(),
+            memory_barrier_ptr: loader.load("glMemoryBarrier").ok(),
+        }
+    }
+
+    /// Returns true if `symbol`, e.g. `"glDebugMessageCallback"`, is part of this `Api` and was
+    /// found by the loader.
+    #[must_use]
+    pub fn is_loaded(&self, symbol: &str) -> bool {
+        self.symbols().any(|(s, loaded)| s == symbol && loaded) || self.base.is_loaded(symbol)
+    }
+
+    /// Symbols the loader returned a null pointer for, always empty after `with_loader`.
+    pub fn missing_functions(&self) -> impl Iterator<Item = &'static str> {
+        self.base.missing_functions().chain(
+            self.symbols()
+                .filter_map(|(symbol, loaded)| (!loaded).then_some(symbol)),
+        )
+    }
+
+    #[allow(clippy::too_many_lines)]
+    fn symbols(&self) -> impl Iterator<Item = (&'static str, bool)> {
+        [
+            (
+                "glDebugMessageCallback",
+                self.debug_message_callback_ptr.is_some(),
+            ),
+            (
+                "glDebugMessageControl",
+                self.debug_message_control_ptr.is_some(),
+            ),
+            (
+                "glDebugMessageInsert",
+                self.debug_message_insert_ptr.is_some(),
+            ),
+            (
+                "glGetDebugMessageLog",
+                self.get_debug_message_log_ptr.is_some(),
+            ),
+            ("glPushDebugGroup", self.push_debug_group_ptr.is_some()),
+            ("glPopDebugGroup", self.pop_debug_group_ptr.is_some()),
+            ("glObjectLabel", self.object_label_ptr.is_some()),
+            ("glObjectPtrLabel", self.object_ptr_label_ptr.is_some()),
+            ("glDepthRangef", self.depth_rangef_ptr.is_some()),
+            (
+                "glDrawArraysInstancedBaseInstance",
+                self.draw_arrays_instanced_base_instance_ptr.is_some(),
+            ),
+            (
+                "glDrawElementsInstancedBaseInstance",
+                self.draw_elements_instanced_base_instance_ptr.is_some(),
+            ),
+            (
+                "glDrawElementsInstancedBaseVertexBaseInstance",
+                self.draw_elements_instanced_base_vertex_base_instance_ptr
+                    .is_some(),
+            ),
+            (
+                "glDrawArraysIndirect",
+                self.draw_arrays_indirect_ptr.is_some(),
+            ),
+            (
+                "glDrawElementsIndirect",
+                self.draw_elements_indirect_ptr.is_some(),
+            ),
+            (
+                "glMultiDrawArraysIndirect",
+                self.multi_draw_arrays_indirect_ptr.is_some(),
+            ),
+            (
+                "glMultiDrawElementsIndirect",
+                self.multi_draw_elements_indirect_ptr.is_some(),
+            ),
+            ("glBindVertexBuffer", self.bind_vertex_buffer_ptr.is_some()),
+            (
+                "glVertexAttribFormat",
+                self.vertex_attrib_format_ptr.is_some(),
+            ),
+            (
+                "glVertexAttribBinding",
+                self.vertex_attrib_binding_ptr.is_some(),
+            ),
+            ("glTexStorage2D", self.tex_storage_2d_ptr.is_some()),
+            ("glTexStorage3D", self.tex_storage_3d_ptr.is_some()),
+            (
+                "glGetProgramInterfaceiv",
+                self.get_program_interfaceiv_ptr.is_some(),
+            ),
+            ("glDispatchCompute", self.dispatch_compute_ptr.is_some()),
+            (
+                "glDispatchComputeIndirect",
+                self.dispatch_compute_indirect_ptr.is_some(),
+            ),
+            ("glMemoryBarrier", self.memory_barrier_ptr.is_some()),
+        ]
+        .into_iter()
+    }
+
+    // DEBUG
+
+    /// Set the debug message callback.
+    /// Make sure to `enable` `Capability::DEBUG_OUTPUT` and to use a debug context.
+    /// `set_debug_handler` takes a closure instead.
+    ///
+    /// # Safety
+    /// See the safety note in `with_loader`
+    #[inline]
+    #[cfg_attr(feature = "trace", track_caller)]
+    pub unsafe fn debug_message_callback(
+        &self,
+        callback: Option<DebugMessageCallback>,
+        user_param: *const std::ffi::c_void,
+    ) {
+        let function = self
+            .debug_message_callback_ptr
+            .unwrap_or_else(|| not_loaded("glDebugMessageCallback"));
+        #[cfg(feature = "trace")]
+        log::trace!("glDebugMessageCallback(callback: {callback:?}, user_param: {user_param:?})");
+        unsafe { function(callback, user_param) };
+        #[cfg(feature = "trace")]
+        crate::trace::check("glDebugMessageCallback", || {
+            unsafe { self.get_error() }.error()
+        });
+    }
+
+    /// Enables or disables the messages matching `source`, `kind` and `severity`, `DONT_CARE` matches any.
+    /// With `count` ids, only those messages are affected, which requires `severity` to be `DONT_CARE`.
+    ///
+    /// # Safety
+    /// See the safety note in `with_loader`
+    #[inline]
+    #[cfg_attr(feature = "trace", track_caller)]
+    pub unsafe fn debug_message_control(
+        &self,
+        source: DebugSource,
+        kind: DebugType,
+        severity: DebugSeverity,
+        count: GLsizei,
+        ids: *const GLuint,
+        enabled: GLboolean,
+    ) {
+        let function = self
+            .debug_message_control_ptr
+            .unwrap_or_else(|| not_loaded("glDebugMessageControl"));
+        #[cfg(feature = "trace")]
+        log::trace!("glDebugMessageControl(source: {source:?}, kind: {kind:?}, severity: {severity:?}, count: {count:?}, ids: {ids:?}, enabled: {enabled:?})");
+        unsafe { function(source, kind, severity, count, ids, enabled) };
+        #[cfg(feature = "trace")]
+        crate::trace::check("glDebugMessageControl", || {
+            unsafe { self.get_error() }.error()
+        });
+    }
+
+    /// Sends a message of `source` `APPLICATION` or `THIRD_PARTY` through the debug output.
+    ///
+    /// # Safety
+    /// See the safety note in `with_loader`
+    #[inline]
+    #[cfg_attr(feature = "trace", track_caller)]
+    pub unsafe fn debug_message_insert(
+        &self,
+        source: DebugSource,
+        kind: DebugType,
+        id: GLuint,
+        severity: DebugSeverity,
+        length: GLsizei,
+        buf: *const GLchar,
+    ) {
+        let function = self
+            .debug_message_insert_ptr
+            .unwrap_or_else(|| not_loaded("glDebugMessageInsert"));
+        #[cfg(feature = "trace")]
+        log::trace!("glDebugMessageInsert(source: {source:?}, kind: {kind:?}, id: {id:?}, severity: {severity:?}, length: {length:?}, buf: {buf:?})");
+        unsafe { function(source, kind, id, severity, length, buf) };
+        #[cfg(feature = "trace")]
+        crate::trace::check("glDebugMessageInsert", || {
+            unsafe { self.get_error() }.error()
+        });
+    }
+
+    /// Moves up to `count` messages out of the log, returning how many were written.
+    /// Messages are only logged while no callback is set, `debug_message_log` drains them one by one.
+    ///
+    /// # Safety
+    /// See the safety note in `with_loader`
+    #[inline]
+    #[cfg_attr(feature = "trace", track_caller)]
+    #[must_use]
+    pub unsafe fn get_debug_message_log(
+        &self,
+        count: GLuint,
+        buf_size: GLsizei,
+        sources: *mut DebugSource,
+        types: *mut DebugType,
+        ids: *mut GLuint,
+        severities: *mut DebugSeverity,
+        lengths: *mut GLsizei,
+        message_log: *mut GLchar,
+    ) -> GLuint {
+        let function = self
+            .get_debug_message_log_ptr
+            .unwrap_or_else(|| not_loaded("glGetDebugMessageLog"));
+        #[cfg(feature = "trace")]
+        log::trace!("glGetDebugMessageLog(count: {count:?}, buf_size: {buf_size:?}, sources: {sources:?}, types: {types:?}, ids: {ids:?}, severities: {severities:?}, lengths: {lengths:?}, message_log: {message_log:?})");
+        let result = unsafe {
+            function(
+                count,
+                buf_size,
+                sources,
+                types,
+                ids,
+                severities,
+                lengths,
+                message_log,
+            )
+        };
+        #[cfg(feature = "trace")]
+        crate::trace::check("glGetDebugMessageLog", || {
+            unsafe { self.get_error() }.error()
+        });
+        result
+    }
+
+    /// Starts a named group of commands, shown by graphics debuggers.
+    /// Also sends a `PUSH_GROUP` message through the debug output.
+    ///
+    /// # Safety
+    /// See the safety note in `with_loader`
+    #[inline]
+    #[cfg_attr(feature = "trace", track_caller)]
+    pub unsafe fn push_debug_group(
+        &self,
+        source: DebugSource,
+        id: GLuint,
+        length: GLsizei,
+        message: *const GLchar,
+    ) {
+        let function = self
+            .push_debug_group_ptr
+            .unwrap_or_else(|| not_loaded("glPushDebugGroup"));
+        #[cfg(feature = "trace")]
+        log::trace!("glPushDebugGroup(source: {source:?}, id: {id:?}, length: {length:?}, message: {message:?})");
+        unsafe { function(source, id, length, message) };
+        #[cfg(feature = "trace")]
+        crate::trace::check("glPushDebugGroup", || unsafe { self.get_error() }.error());
+    }
+
+    /// Ends the group of the last `push_debug_group`.
+    ///
+    /// # Safety
+    /// See the safety note in `with_loader`
+    #[inline]
+    #[cfg_attr(feature = "trace", track_caller)]
+    pub unsafe fn pop_debug_group(&self) {
+        let function = self
+            .pop_debug_group_ptr
+            .unwrap_or_else(|| not_loaded("glPopDebugGroup"));
+        #[cfg(feature = "trace")]
+        log::trace!("glPopDebugGroup()");
+        unsafe { function() };
+        #[cfg(feature = "trace")]
+        crate::trace::check("glPopDebugGroup", || unsafe { self.get_error() }.error());
+    }
+
+    /// Names object `name` of kind `identifier` in debug messages and tools.
+    /// `label_object` does the same for typed handles.
+    ///
+    /// # Safety
+    /// See the safety note in `with_loader`
+    #[inline]
+    #[cfg_attr(feature = "trace", track_caller)]
+    pub unsafe fn object_label(
+        &self,
+        identifier: ObjectIdentifier,
+        name: GLuint,
+        length: GLsizei,
+        label: *const GLchar,
+    ) {
+        let function = self
+            .object_label_ptr
+            .unwrap_or_else(|| not_loaded("glObjectLabel"));
+        #[cfg(feature = "trace")]
+        log::trace!("glObjectLabel(identifier: {identifier:?}, name: {name:?}, length: {length:?}, label: {label:?})");
+        unsafe { function(identifier, name, length, label) };
+        #[cfg(feature = "trace")]
+        crate::trace::check("glObjectLabel", || unsafe { self.get_error() }.error());
+    }
+
+    /// Names a sync object in debug messages and tools.
+    ///
+    /// # Safety
+    /// See the safety note in `with_loader`
+    #[inline]
+    #[cfg_attr(feature = "trace", track_caller)]
+    pub unsafe fn object_ptr_label(
+        &self,
+        ptr: *const std::ffi::c_void,
+        length: GLsizei,
+        label: *const GLchar,
+    ) {
+        let function = self
+            .object_ptr_label_ptr
+            .unwrap_or_else(|| not_loaded("glObjectPtrLabel"));
+        #[cfg(feature = "trace")]
+        log::trace!("glObjectPtrLabel(ptr: {ptr:?}, length: {length:?}, label: {label:?})");
+        unsafe { function(ptr, length, label) };
+        #[cfg(feature = "trace")]
+        crate::trace::check("glObjectPtrLabel", || unsafe { self.get_error() }.error());
+    }
+
+    // STATE
+
+    /// # Safety
+    /// See the safety note in `with_loader`
+    #[inline]
+    #[cfg_attr(feature = "trace", track_caller)]
+    pub unsafe fn depth_rangef(&self, n: GLfloat, f: GLfloat) {
+        let function = self
+            .depth_rangef_ptr
+            .unwrap_or_else(|| not_loaded("glDepthRangef"));
+        #[cfg(feature = "trace")]
+        log::trace!("glDepthRangef(n: {n:?}, f: {f:?})");
+        unsafe { function(n, f) };
+        #[cfg(feature = "trace")]
+        crate::trace::check("glDepthRangef", || unsafe { self.get_error() }.error());
+    }
+
+    // DRAW
+
+    /// Like `draw_arrays_instanced`, but instanced attributes start at instance `baseinstance`.
+    ///
+    /// # Safety
+    /// See the safety note in `with_loader`
+    #[inline]
+    #[cfg_attr(feature = "trace", track_caller)]
+    pub unsafe fn draw_arrays_instanced_base_instance(
+        &self,
+        mode: Primitive,
+        first: GLint,
+        count: GLsizei,
+        instancecount: GLsizei,
+        baseinstance: GLuint,
+    ) {
+        let function = self
+            .draw_arrays_instanced_base_instance_ptr
+            .unwrap_or_else(|| not_loaded("glDrawArraysInstancedBaseInstance"));
+        #[cfg(feature = "trace")]
+        log::trace!("glDrawArraysInstancedBaseInstance(mode: {mode:?}, first: {first:?}, count: {count:?}, instancecount: {instancecount:?}, baseinstance: {baseinstance:?})");
+        unsafe { function(mode, first, count, instancecount, baseinstance) };
+        #[cfg(feature = "trace")]
+        crate::trace::check("glDrawArraysInstancedBaseInstance", || {
+            unsafe { self.get_error() }.error()
+        });
+    }
+
+    /// Like `draw_elements_instanced`, but instanced attributes start at instance `baseinstance`.
+    ///
+    /// # Safety
+    /// See the safety note in `with_loader`
+    #[inline]
+    #[cfg_attr(feature = "trace", track_caller)]
+    pub unsafe fn draw_elements_instanced_base_instance(
+        &self,
+        mode: Primitive,
+        count: GLsizei,
+        kind: IndexKind,
+        indices: *const std::ffi::c_void,
+        instancecount: GLsizei,
+        baseinstance: GLuint,
+    ) {
+        let function = self
+            .draw_elements_instanced_base_instance_ptr
+            .unwrap_or_else(|| not_loaded("glDrawElementsInstancedBaseInstance"));
+        #[cfg(feature = "trace")]
+        log::trace!("glDrawElementsInstancedBaseInstance(mode: {mode:?}, count: {count:?}, kind: {kind:?}, indices: {indices:?}, instancecount: {instancecount:?}, baseinstance: {baseinstance:?})");
+        unsafe { function(mode, count, kind, indices, instancecount, baseinstance) };
+        #[cfg(feature = "trace")]
+        crate::trace::check("glDrawElementsInstancedBaseInstance", || {
+            unsafe { self.get_error() }.error()
+        });
+    }
+
+    /// # Safety
+    /// See the safety note in `with_loader`
+    #[inline]
+    #[cfg_attr(feature = "trace", track_caller)]
+    pub unsafe fn draw_elements_instanced_base_vertex_base_instance(
+        &self,
+        mode: Primitive,
+        count: GLsizei,
+        kind: IndexKind,
+        indices: *const std::ffi::c_void,
+        instancecount: GLsizei,
+        basevertex: GLint,
+        baseinstance: GLuint,
+    ) {
+        let function = self
+            .draw_elements_instanced_base_vertex_base_instance_ptr
+            .unwrap_or_else(|| not_loaded("glDrawElementsInstancedBaseVertexBaseInstance"));
+        #[cfg(feature = "trace")]
+        log::trace!("glDrawElementsInstancedBaseVertexBaseInstance(mode: {mode:?}, count: {count:?}, kind: {kind:?}, indices: {indices:?}, instancecount: {instancecount:?}, basevertex: {basevertex:?}, baseinstance: {baseinstance:?})");
+        unsafe {
+            function(
+                mode,
+                count,
+                kind,
+                indices,
+                instancecount,
+                basevertex,
+                baseinstance,
+            )
+        };
+        #[cfg(feature = "trace")]
+        crate::trace::check("glDrawElementsInstancedBaseVertexBaseInstance", || {
+            unsafe { self.get_error() }.error()
+        });
+    }
+
+    /// `indirect` is a byte offset to a `DrawArraysIndirectCommand` in the bound `DRAW_INDIRECT_BUFFER`.
+    ///
+    /// # Safety
+    /// See the safety note in `with_loader`
+    #[inline]
+    #[cfg_attr(feature = "trace", track_caller)]
+    pub unsafe fn draw_arrays_indirect(&self, mode: Primitive, indirect: *const std::ffi::c_void) {
+        let function = self
+            .draw_arrays_indirect_ptr
+            .unwrap_or_else(|| not_loaded("glDrawArraysIndirect"));
+        #[cfg(feature = "trace")]
+        log::trace!("glDrawArraysIndirect(mode: {mode:?}, indirect: {indirect:?})");
+        unsafe { function(mode, indirect) };
+        #[cfg(feature = "trace")]
+        crate::trace::check("glDrawArraysIndirect", || {
+            unsafe { self.get_error() }.error()
+        });
+    }
+
+    /// `indirect` is a byte offset to a `DrawElementsIndirectCommand` in the bound `DRAW_INDIRECT_BUFFER`.
+    ///
+    /// # Safety
+    /// See the safety note in `with_loader`
+    #[inline]
+    #[cfg_attr(feature = "trace", track_caller)]
+    pub unsafe fn draw_elements_indirect(
+        &self,
+        mode: Primitive,
+        kind: IndexKind,
+        indirect: *const std::ffi::c_void,
+    ) {
+        let function = self
+            .draw_elements_indirect_ptr
+            .unwrap_or_else(|| not_loaded("glDrawElementsIndirect"));
+        #[cfg(feature = "trace")]
+        log::trace!(
+            "glDrawElementsIndirect(mode: {mode:?}, kind: {kind:?}, indirect: {indirect:?})"
+        );
+        unsafe { function(mode, kind, indirect) };
+        #[cfg(feature = "trace")]
+        crate::trace::check("glDrawElementsIndirect", || {
+            unsafe { self.get_error() }.error()
+        });
+    }
+
+    /// Draws `drawcount` `DrawArraysIndirectCommand`s `stride` bytes apart, `0` means tightly packed.
+    ///
+    /// # Safety
+    /// See the safety note in `with_loader`
+    #[inline]
+    #[cfg_attr(feature = "trace", track_caller)]
+    pub unsafe fn multi_draw_arrays_indirect(
+        &self,
+        mode: Primitive,
+        indirect: *const std::ffi::c_void,
+        drawcount: GLsizei,
+        stride: GLsizei,
+    ) {
+        let function = self
+            .multi_draw_arrays_indirect_ptr
+            .unwrap_or_else(|| not_loaded("glMultiDrawArraysIndirect"));
+        #[cfg(feature = "trace")]
+        log::trace!("glMultiDrawArraysIndirect(mode: {mode:?}, indirect: {indirect:?}, drawcount: {drawcount:?}, stride: {stride:?})");
+        unsafe { function(mode, indirect, drawcount, stride) };
+        #[cfg(feature = "trace")]
+        crate::trace::check("glMultiDrawArraysIndirect", || {
+            unsafe { self.get_error() }.error()
+        });
+    }
+
+    /// Draws `drawcount` `DrawElementsIndirectCommand`s `stride` bytes apart, `0` means tightly packed.
+    ///
+    /// # Safety
+    /// See the safety note in `with_loader`
+    #[inline]
+    #[cfg_attr(feature = "trace", track_caller)]
+    pub unsafe fn multi_draw_elements_indirect(
+        &self,
+        mode: Primitive,
+        kind: IndexKind,
+        indirect: *const std::ffi::c_void,
+        drawcount: GLsizei,
+        stride: GLsizei,
+    ) {
+        let function = self
+            .multi_draw_elements_indirect_ptr
+            .unwrap_or_else(|| not_loaded("glMultiDrawElementsIndirect"));
+        #[cfg(feature = "trace")]
+        log::trace!("glMultiDrawElementsIndirect(mode: {mode:?}, kind: {kind:?}, indirect: {indirect:?}, drawcount: {drawcount:?}, stride: {stride:?})");
+        unsafe { function(mode, kind, indirect, drawcount, stride) };
+        #[cfg(feature = "trace")]
+        crate::trace::check("glMultiDrawElementsIndirect", || {
+            unsafe { self.get_error() }.error()
+        });
+    }
+
+    // VERTEX ARRAYS
+
+    /// # Safety
+    /// See the safety note in `with_loader`
+    #[inline]
+    #[cfg_attr(feature = "trace", track_caller)]
+    pub unsafe fn bind_vertex_buffer(
+        &self,
+        bindingindex: GLuint,
+        buffer: Buffer,
+        offset: GLintptr,
+        stride: GLsizei,
+    ) {
+        let function = self
+            .bind_vertex_buffer_ptr
+            .unwrap_or_else(|| not_loaded("glBindVertexBuffer"));
+        #[cfg(feature = "trace")]
+        log::trace!("glBindVertexBuffer(bindingindex: {bindingindex:?}, buffer: {buffer:?}, offset: {offset:?}, stride: {stride:?})");
+        unsafe { function(bindingindex, buffer, offset, stride) };
+        #[cfg(feature = "trace")]
+        crate::trace::check("glBindVertexBuffer", || unsafe { self.get_error() }.error());
+    }
+
+    /// # Safety
+    /// See the safety note in `with_loader`
+    #[inline]
+    #[cfg_attr(feature = "trace", track_caller)]
+    pub unsafe fn vertex_attrib_format(
+        &self,
+        attribindex: GLuint,
+        size: GLint,
+        kind: VertexAttributeKind,
+        normalized: GLboolean,
+        relativeoffset: GLuint,
+    ) {
+        let function = self
+            .vertex_attrib_format_ptr
+            .unwrap_or_else(|| not_loaded("glVertexAttribFormat"));
+        #[cfg(feature = "trace")]
+        log::trace!("glVertexAttribFormat(attribindex: {attribindex:?}, size: {size:?}, kind: {kind:?}, normalized: {normalized:?}, relativeoffset: {relativeoffset:?})");
+        unsafe { function(attribindex, size, kind, normalized, relativeoffset) };
+        #[cfg(feature = "trace")]
+        crate::trace::check("glVertexAttribFormat", || {
+            unsafe { self.get_error() }.error()
+        });
+    }
+
+    /// # Safety
+    /// See the safety note in `with_loader`
+    #[inline]
+    #[cfg_attr(feature = "trace", track_caller)]
+    pub unsafe fn vertex_attrib_binding(&self, attribindex: GLuint, bindingindex: GLuint) {
+        let function = self
+            .vertex_attrib_binding_ptr
+            .unwrap_or_else(|| not_loaded("glVertexAttribBinding"));
+        #[cfg(feature = "trace")]
+        log::trace!(
+            "glVertexAttribBinding(attribindex: {attribindex:?}, bindingindex: {bindingindex:?})"
+        );
+        unsafe { function(attribindex, bindingindex) };
+        #[cfg(feature = "trace")]
+        crate::trace::check("glVertexAttribBinding", || {
+            unsafe { self.get_error() }.error()
+        });
+    }
+
+    // TEXTURES
+
+    /// Allocates immutable storage for all `levels`, only sized `InternalFormat`s are allowed.
+    ///
+    /// # Safety
+    /// See the safety note in `with_loader`
+    #[inline]
+    #[cfg_attr(feature = "trace", track_caller)]
+    pub unsafe fn tex_storage_2d(
+        &self,
+        target: TextureTarget,
+        levels: GLsizei,
+        internalformat: InternalFormat,
+        width: GLsizei,
+        height: GLsizei,
+    ) {
+        let function = self
+            .tex_storage_2d_ptr
+            .unwrap_or_else(|| not_loaded("glTexStorage2D"));
+        #[cfg(feature = "trace")]
+        log::trace!("glTexStorage2D(target: {target:?}, levels: {levels:?}, internalformat: {internalformat:?}, width: {width:?}, height: {height:?})");
+        unsafe { function(target, levels, internalformat, width, height) };
+        #[cfg(feature = "trace")]
+        crate::trace::check("glTexStorage2D", || unsafe { self.get_error() }.error());
+    }
+
+    /// Allocates immutable storage for all `levels`, only sized `InternalFormat`s are allowed.
+    ///
+    /// # Safety
+    /// See the safety note in `with_loader`
+    #[inline]
+    #[cfg_attr(feature = "trace", track_caller)]
+    pub unsafe fn tex_storage_3d(
+        &self,
+        target: TextureTarget,
+        levels: GLsizei,
+        internalformat: InternalFormat,
+        width: GLsizei,
+        height: GLsizei,
+        depth: GLsizei,
+    ) {
+        let function = self
+            .tex_storage_3d_ptr
+            .unwrap_or_else(|| not_loaded("glTexStorage3D"));
+        #[cfg(feature = "trace")]
+        log::trace!("glTexStorage3D(target: {target:?}, levels: {levels:?}, internalformat: {internalformat:?}, width: {width:?}, height: {height:?}, depth: {depth:?})");
+        unsafe { function(target, levels, internalformat, width, height, depth) };
+        #[cfg(feature = "trace")]
+        crate::trace::check("glTexStorage3D", || unsafe { self.get_error() }.error());
+    }
+
+    // UNIFORMS
+
+    /// # Safety
+    /// See the safety note in `with_loader`
+    #[inline]
+    #[cfg_attr(feature = "trace", track_caller)]
+    pub unsafe fn get_program_interfaceiv(
+        &self,
+        program: Program,
+        program_interface: ProgramInterface,
+        pname: ProgramInterfaceParameter,
+        params: *mut GLint,
+    ) {
+        let function = self
+            .get_program_interfaceiv_ptr
+            .unwrap_or_else(|| not_loaded("glGetProgramInterfaceiv"));
+        #[cfg(feature = "trace")]
+        log::trace!("glGetProgramInterfaceiv(program: {program:?}, program_interface: {program_interface:?}, pname: {pname:?}, params: {params:?})");
+        unsafe { function(program, program_interface, pname, params) };
+        #[cfg(feature = "trace")]
+        crate::trace::check("glGetProgramInterfaceiv", || {
+            unsafe { self.get_error() }.error()
+        });
+    }
+
+    // COMPUTE
+
+    /// Runs the compute shader of the current program in `x * y * z` work groups.
+    ///
+    /// # Safety
+    /// See the safety note in `with_loader`
+    #[inline]
+    #[cfg_attr(feature = "trace", track_caller)]
+    pub unsafe fn dispatch_compute(
+        &self,
+        num_groups_x: GLuint,
+        num_groups_y: GLuint,
+        num_groups_z: GLuint,
+    ) {
+        let function = self
+            .dispatch_compute_ptr
+            .unwrap_or_else(|| not_loaded("glDispatchCompute"));
+        #[cfg(feature = "trace")]
+        log::trace!("glDispatchCompute(num_groups_x: {num_groups_x:?}, num_groups_y: {num_groups_y:?}, num_groups_z: {num_groups_z:?})");
+        unsafe { function(num_groups_x, num_groups_y, num_groups_z) };
+        #[cfg(feature = "trace")]
+        crate::trace::check("glDispatchCompute", || unsafe { self.get_error() }.error());
+    }
+
+    /// `indirect` is a byte offset to a `DispatchIndirectCommand` in the bound `DISPATCH_INDIRECT_BUFFER`.
+    ///
+    /// # Safety
+    /// See the safety note in `with_loader`
+    #[inline]
+    #[cfg_attr(feature = "trace", track_caller)]
+    pub unsafe fn dispatch_compute_indirect(&self, indirect: GLintptr) {
+        let function = self
+            .dispatch_compute_indirect_ptr
+            .unwrap_or_else(|| not_loaded("glDispatchComputeIndirect"));
+        #[cfg(feature = "trace")]
+        log::trace!("glDispatchComputeIndirect(indirect: {indirect:?})");
+        unsafe { function(indirect) };
+        #[cfg(feature = "trace")]
+        crate::trace::check("glDispatchComputeIndirect", || {
+            unsafe { self.get_error() }.error()
+        });
+    }
+
+    /// Makes the writes of earlier shaders visible to the kinds of access in `barriers`.
+    ///
+    /// # Safety
+    /// See the safety note in `with_loader`
+    #[inline]
+    #[cfg_attr(feature = "trace", track_caller)]
+    pub unsafe fn memory_barrier(&self, barriers: BarrierBits) {
+        let function = self
+            .memory_barrier_ptr
+            .unwrap_or_else(|| not_loaded("glMemoryBarrier"));
+        #[cfg(feature = "trace")]
+        log::trace!("glMemoryBarrier(barriers: {barriers:?})");
+        unsafe { function(barriers) };
+        #[cfg(feature = "trace")]
+        crate::trace::check("glMemoryBarrier", || unsafe { self.get_error() }.error());
+    }
+}
@@ -0,0 +1,7 @@
+pub use crate::gl::*;
+use crate::{not_loaded, Error, Loader};
+
+// `Api` and its wrappers for everything new in OpenGL 4.3, see `cac_gl_generator`
+include!("generated.rs");
+include!("../compute.rs");
+include!("../debug.rs");
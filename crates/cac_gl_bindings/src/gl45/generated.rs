@@ -0,0 +1,173 @@
+// This file is generated by `cac_gl_generator` from the Khronos registry, do not edit it by hand.
+// Change `crates/cac_gl_generator/allowlist.toml` and run `cargo run -p cac_gl_generator` instead.
+
+/// Bindings to a curated subset of OpenGL 4.5
+///
+/// Everything up to OpenGL 4.3 is available through `Deref` to `gl43::Api`.
+#[allow(clippy::struct_field_names)]
+pub struct Api {
+    base: super::gl43::Api,
+
+    //vertex arrays
+    create_vertex_arrays_ptr:
+        Option<unsafe extern "system" fn(n: GLsizei, arrays: *mut VertexArray)>,
+
+    //buffers
+    create_buffers_ptr: Option<unsafe extern "system" fn(n: GLsizei, buffers: *mut Buffer)>,
+    named_buffer_data_ptr: Option<
+        unsafe extern "system" fn(
+            buffer: Buffer,
+            size: GLsizeiptr,
+            data: *const std::ffi::c_void,
+            usage: BufferUsage,
+        ),
+    >,
+}
+
+impl std::ops::Deref for Api {
+    type Target = super::gl43::Api;
+
+    fn deref(&self) -> &super::gl43::Api {
+        &self.base
+    }
+}
+
+#[allow(
+    clippy::let_and_return,
+    clippy::semicolon_if_nothing_returned,
+    clippy::similar_names,
+    clippy::too_many_arguments
+)]
+impl Api {
+    /// Loads all function pointers using a context function loader,
+    /// also called `ProcAddress`. Works with GLFW, SDL2 any pretty much any OpenGL context.
+    ///
+    /// # Errors
+    /// This function will return an error if any function pointer returns a null pointer.
+    ///
+    /// # Safety
+    /// Unfortunately, some drivers return wrong addresses that are indistinguishable from correct
+    /// ones, instead of being null pointers.
+    ///
+    #[allow(clippy::too_many_lines)]
+    pub unsafe fn with_loader(
+        loader: &impl Fn(&str) -> *const std::ffi::c_void,
+    ) -> Result<Self, Error> {
+        Ok(Self {
+            base: unsafe { super::gl43::Api::with_loader(loader)? },
+
+            //vertex arrays
+            create_vertex_arrays_ptr: Some(loader.load("glCreateVertexArrays")?),
+
+            //buffers
+            create_buffers_ptr: Some(loader.load("glCreateBuffers")?),
+            named_buffer_data_ptr: Some(loader.load("glNamedBufferData")?),
+        })
+    }
+
+    /// Like `with_loader`, but keeps going when a function pointer is null, e.g. for entry points
+    /// a driver doesn't expose. Use `is_loaded` or `missing_functions` to find out what is
+    /// available, calling a wrapper whose function is missing panics.
+    ///
+    /// # Safety
+    /// See the safety note in `with_loader`
+    #[must_use]
+    #[allow(clippy::too_many_lines)]
+    pub unsafe fn with_loader_partial(loader: &impl Fn(&str) -> *const std::ffi::c_void) -> Self {
+        Self {
+            base: unsafe { super::gl43::Api::with_loader_partial(loader) },
+
+            //vertex arrays
+            create_vertex_arrays_ptr: loader.load("glCreateVertexArrays").ok(),
+
+            //buffers
+            create_buffers_ptr: loader.load("glCreateBuffers").ok(),
+            named_buffer_data_ptr: loader.load("glNamedBufferData").ok(),
+        }
+    }
+
+    /// Returns true if `symbol`, e.g. `"glDebugMessageCallback"`, is part of this `Api` and was
+    /// found by the loader.
+    #[must_use]
+    pub fn is_loaded(&self, symbol: &str) -> bool {
+        self.symbols().any(|(s, loaded)| s == symbol && loaded) || self.base.is_loaded(symbol)
+    }
+
+    /// Symbols the loader returned a null pointer for, always empty after `with_loader`.
+    pub fn missing_functions(&self) -> impl Iterator<Item = &'static str> {
+        self.base.missing_functions().chain(
+            self.symbols()
+                .filter_map(|(symbol, loaded)| (!loaded).then_some(symbol)),
+        )
+    }
+
+    #[allow(clippy::too_many_lines)]
+    fn symbols(&self) -> impl Iterator<Item = (&'static str, bool)> {
+        [
+            (
+                "glCreateVertexArrays",
+                self.create_vertex_arrays_ptr.is_some(),
+            ),
+            ("glCreateBuffers", self.create_buffers_ptr.is_some()),
+            ("glNamedBufferData", self.named_buffer_data_ptr.is_some()),
+        ]
+        .into_iter()
+    }
+
+    // VERTEX ARRAYS
+
+    /// # Safety
+    /// See the safety note in `with_loader`
+    #[inline]
+    #[cfg_attr(feature = "trace", track_caller)]
+    pub unsafe fn create_vertex_arrays(&self, n: GLsizei, arrays: *mut VertexArray) {
+        let function = self
+            .create_vertex_arrays_ptr
+            .unwrap_or_else(|| not_loaded("glCreateVertexArrays"));
+        #[cfg(feature = "trace")]
+        log::trace!("glCreateVertexArrays(n: {n:?}, arrays: {arrays:?})");
+        unsafe { function(n, arrays) };
+        #[cfg(feature = "trace")]
+        crate::trace::check("glCreateVertexArrays", || {
+            unsafe { self.get_error() }.error()
+        });
+    }
+
+    // BUFFERS
+
+    /// # Safety
+    /// See the safety note in `with_loader`
+    #[inline]
+    #[cfg_attr(feature = "trace", track_caller)]
+    pub unsafe fn create_buffers(&self, n: GLsizei, buffers: *mut Buffer) {
+        let function = self
+            .create_buffers_ptr
+            .unwrap_or_else(|| not_loaded("glCreateBuffers"));
+        #[cfg(feature = "trace")]
+        log::trace!("glCreateBuffers(n: {n:?}, buffers: {buffers:?})");
+        unsafe { function(n, buffers) };
+        #[cfg(feature = "trace")]
+        crate::trace::check("glCreateBuffers", || unsafe { self.get_error() }.error());
+    }
+
+    /// # Safety
+    /// See the safety note in `with_loader`
+    #[inline]
+    #[cfg_attr(feature = "trace", track_caller)]
+    pub unsafe fn named_buffer_data(
+        &self,
+        buffer: Buffer,
+        size: GLsizeiptr,
+        data: *const std::ffi::c_void,
+        usage: BufferUsage,
+    ) {
+        let function = self
+            .named_buffer_data_ptr
+            .unwrap_or_else(|| not_loaded("glNamedBufferData"));
+        #[cfg(feature = "trace")]
+        log::trace!("glNamedBufferData(buffer: {buffer:?}, size: {size:?}, data: {data:?}, usage: {usage:?})");
+        unsafe { function(buffer, size, data, usage) };
+        #[cfg(feature = "trace")]
+        crate::trace::check("glNamedBufferData", || unsafe { self.get_error() }.error());
+    }
+}
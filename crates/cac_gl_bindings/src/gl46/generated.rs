@@ -0,0 +1,130 @@
+// This file is generated by `cac_gl_generator` from the Khronos registry, do not edit it by hand.
+// Change `crates/cac_gl_generator/allowlist.toml` and run `cargo run -p cac_gl_generator` instead.
+
+/// Bindings to a curated subset of OpenGL 4.6
+///
+/// Everything up to OpenGL 4.5 is available through `Deref` to `gl45::Api`.
+#[allow(clippy::struct_field_names)]
+pub struct Api {
+    base: super::gl45::Api,
+
+    //shaders
+    specialize_shader_ptr: Option<
+        unsafe extern "system" fn(
+            shader: Shader,
+            p_entry_point: *const GLchar,
+            num_specialization_constants: GLuint,
+            p_constant_index: *const GLuint,
+            p_constant_value: *const GLuint,
+        ),
+    >,
+}
+
+impl std::ops::Deref for Api {
+    type Target = super::gl45::Api;
+
+    fn deref(&self) -> &super::gl45::Api {
+        &self.base
+    }
+}
+
+#[allow(
+    clippy::let_and_return,
+    clippy::semicolon_if_nothing_returned,
+    clippy::similar_names,
+    clippy::too_many_arguments
+)]
+impl Api {
+    /// Loads all function pointers using a context function loader,
+    /// also called `ProcAddress`. Works with GLFW, SDL2 any pretty much any OpenGL context.
+    ///
+    /// # Errors
+    /// This function will return an error if any function pointer returns a null pointer.
+    ///
+    /// # Safety
+    /// Unfortunately, some drivers return wrong addresses that are indistinguishable from correct
+    /// ones, instead of being null pointers.
+    ///
+    #[allow(clippy::too_many_lines)]
+    pub unsafe fn with_loader(
+        loader: &impl Fn(&str) -> *const std::ffi::c_void,
+    ) -> Result<Self, Error> {
+        Ok(Self {
+            base: unsafe { super::gl45::Api::with_loader(loader)? },
+
+            //shaders
+            specialize_shader_ptr: Some(loader.load("glSpecializeShader")?),
+        })
+    }
+
+    /// Like `with_loader`, but keeps going when a function pointer is null, e.g. for entry points
+    /// a driver doesn't expose. Use `is_loaded` or `missing_functions` to find out what is
+    /// available, calling a wrapper whose function is missing panics.
+    ///
+    /// # Safety
+    /// See the safety note in `with_loader`
+    #[must_use]
+    #[allow(clippy::too_many_lines)]
+    pub unsafe fn with_loader_partial(loader: &impl Fn(&str) -> *const std::ffi::c_void) -> Self {
+        Self {
+            base: unsafe { super::gl45::Api::with_loader_partial(loader) },
+
+            //shaders
+            specialize_shader_ptr: loader.load("glSpecializeShader").ok(),
+        }
+    }
+
+    /// Returns true if `symbol`, e.g. `"glDebugMessageCallback"`, is part of this `Api` and was
+    /// found by the loader.
+    #[must_use]
+    pub fn is_loaded(&self, symbol: &str) -> bool {
+        self.symbols().any(|(s, loaded)| s == symbol && loaded) || self.base.is_loaded(symbol)
+    }
+
+    /// Symbols the loader returned a null pointer for, always empty after `with_loader`.
+    pub fn missing_functions(&self) -> impl Iterator<Item = &'static str> {
+        self.base.missing_functions().chain(
+            self.symbols()
+                .filter_map(|(symbol, loaded)| (!loaded).then_some(symbol)),
+        )
+    }
+
+    #[allow(clippy::too_many_lines)]
+    fn symbols(&self) -> impl Iterator<Item = (&'static str, bool)> {
+        [("glSpecializeShader", self.specialize_shader_ptr.is_some())].into_iter()
+    }
+
+    // SHADERS
+
+    /// Specializes a SPIR-V shader previously uploaded with `glShaderBinary`.
+    ///
+    /// # Safety
+    /// See the safety note in `with_loader`
+    #[inline]
+    #[cfg_attr(feature = "trace", track_caller)]
+    pub unsafe fn specialize_shader(
+        &self,
+        shader: Shader,
+        p_entry_point: *const GLchar,
+        num_specialization_constants: GLuint,
+        p_constant_index: *const GLuint,
+        p_constant_value: *const GLuint,
+    ) {
+        let function = self
+            .specialize_shader_ptr
+            .unwrap_or_else(|| not_loaded("glSpecializeShader"));
+        #[cfg(feature = "trace")]
+        log::trace!("glSpecializeShader(shader: {shader:?}, p_entry_point: {p_entry_point:?}, num_specialization_constants: {num_specialization_constants:?}, p_constant_index: {p_constant_index:?}, p_constant_value: {p_constant_value:?})");
+        unsafe {
+            function(
+                shader,
+                p_entry_point,
+                num_specialization_constants,
+                p_constant_index,
+                p_constant_value,
+            )
+        };
+        #[cfg(feature = "trace")]
+        crate::trace::check("glSpecializeShader", || unsafe { self.get_error() }.error());
+    }
+}
@@ -0,0 +1,5 @@
+pub use crate::gl::*;
+use crate::{not_loaded, Error, Loader};
+
+// `Api` and its wrappers for everything new in OpenGL 4.6, see `cac_gl_generator`
+include!("generated.rs");
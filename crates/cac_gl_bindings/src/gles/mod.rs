@@ -0,0 +1,8 @@
+pub use super::types::{
+    GLbitField, GLboolean, GLchar, GLdouble, GLenum, GLfloat, GLint, GLintptr, GLsizei, GLsizeiptr,
+    GLuint,
+};
+
+// handles and enums shared by all OpenGL ES versions, see `cac_gl_generator`
+include!("generated.rs");
+include!("../common.rs");
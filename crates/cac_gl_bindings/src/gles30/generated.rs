@@ -0,0 +1,3663 @@
+// This file is generated by `cac_gl_generator` from the Khronos registry, do not edit it by hand.
+// Change `crates/cac_gl_generator/allowlist.toml` and run `cargo run -p cac_gl_generator` instead.
+
+/// Bindings to a curated subset of OpenGL ES 3.0
+#[allow(clippy::struct_field_names)]
+pub struct Api {
+    //debug
+    get_error_ptr: Option<unsafe extern "system" fn() -> ErrorCode>,
+
+    //state
+    enable_ptr: Option<unsafe extern "system" fn(cap: Capability)>,
+    disable_ptr: Option<unsafe extern "system" fn(cap: Capability)>,
+    is_enabled_ptr: Option<unsafe extern "system" fn(cap: Capability) -> GLboolean>,
+    viewport_ptr:
+        Option<unsafe extern "system" fn(x: GLint, y: GLint, width: GLsizei, height: GLsizei)>,
+    scissor_ptr:
+        Option<unsafe extern "system" fn(x: GLint, y: GLint, width: GLsizei, height: GLsizei)>,
+    blend_func_ptr: Option<unsafe extern "system" fn(sfactor: BlendFactor, dfactor: BlendFactor)>,
+    blend_func_separate_ptr: Option<
+        unsafe extern "system" fn(
+            sfactor_rgb: BlendFactor,
+            dfactor_rgb: BlendFactor,
+            sfactor_alpha: BlendFactor,
+            dfactor_alpha: BlendFactor,
+        ),
+    >,
+    blend_equation_ptr: Option<unsafe extern "system" fn(mode: BlendEquation)>,
+    blend_equation_separate_ptr:
+        Option<unsafe extern "system" fn(mode_rgb: BlendEquation, mode_alpha: BlendEquation)>,
+    blend_color_ptr: Option<
+        unsafe extern "system" fn(red: GLfloat, green: GLfloat, blue: GLfloat, alpha: GLfloat),
+    >,
+    depth_func_ptr: Option<unsafe extern "system" fn(func: CompareFunc)>,
+    depth_mask_ptr: Option<unsafe extern "system" fn(flag: GLboolean)>,
+    depth_rangef_ptr: Option<unsafe extern "system" fn(n: GLfloat, f: GLfloat)>,
+    stencil_func_ptr:
+        Option<unsafe extern "system" fn(func: CompareFunc, reference: GLint, mask: GLuint)>,
+    stencil_func_separate_ptr: Option<
+        unsafe extern "system" fn(face: Face, func: CompareFunc, reference: GLint, mask: GLuint),
+    >,
+    stencil_op_ptr:
+        Option<unsafe extern "system" fn(fail: StencilOp, zfail: StencilOp, zpass: StencilOp)>,
+    stencil_op_separate_ptr: Option<
+        unsafe extern "system" fn(
+            face: Face,
+            sfail: StencilOp,
+            dpfail: StencilOp,
+            dppass: StencilOp,
+        ),
+    >,
+    stencil_mask_ptr: Option<unsafe extern "system" fn(mask: GLuint)>,
+    stencil_mask_separate_ptr: Option<unsafe extern "system" fn(face: Face, mask: GLuint)>,
+    cull_face_ptr: Option<unsafe extern "system" fn(mode: Face)>,
+    front_face_ptr: Option<unsafe extern "system" fn(mode: FrontFace)>,
+    polygon_offset_ptr: Option<unsafe extern "system" fn(factor: GLfloat, units: GLfloat)>,
+    color_mask_ptr: Option<
+        unsafe extern "system" fn(
+            red: GLboolean,
+            green: GLboolean,
+            blue: GLboolean,
+            alpha: GLboolean,
+        ),
+    >,
+    line_width_ptr: Option<unsafe extern "system" fn(width: GLfloat)>,
+    clear_ptr: Option<unsafe extern "system" fn(mask: ClearMask)>,
+    clear_color_ptr: Option<
+        unsafe extern "system" fn(red: GLfloat, green: GLfloat, blue: GLfloat, alpha: GLfloat),
+    >,
+
+    //draw
+    draw_arrays_ptr:
+        Option<unsafe extern "system" fn(mode: Primitive, first: GLint, count: GLsizei)>,
+    draw_elements_ptr: Option<
+        unsafe extern "system" fn(
+            mode: Primitive,
+            count: GLsizei,
+            kind: IndexKind,
+            indices: *const std::ffi::c_void,
+        ),
+    >,
+    draw_range_elements_ptr: Option<
+        unsafe extern "system" fn(
+            mode: Primitive,
+            start: GLuint,
+            end: GLuint,
+            count: GLsizei,
+            kind: IndexKind,
+            indices: *const std::ffi::c_void,
+        ),
+    >,
+    draw_arrays_instanced_ptr: Option<
+        unsafe extern "system" fn(
+            mode: Primitive,
+            first: GLint,
+            count: GLsizei,
+            instancecount: GLsizei,
+        ),
+    >,
+    draw_elements_instanced_ptr: Option<
+        unsafe extern "system" fn(
+            mode: Primitive,
+            count: GLsizei,
+            kind: IndexKind,
+            indices: *const std::ffi::c_void,
+            instancecount: GLsizei,
+        ),
+    >,
+
+    //vertex arrays
+    gen_vertex_arrays_ptr: Option<unsafe extern "system" fn(n: GLsizei, arrays: *mut VertexArray)>,
+    bind_vertex_array_ptr: Option<unsafe extern "system" fn(array: VertexArray)>,
+    enable_vertex_attrib_array_ptr: Option<unsafe extern "system" fn(index: GLuint)>,
+    vertex_attrib_pointer_ptr: Option<
+        unsafe extern "system" fn(
+            index: GLuint,
+            size: GLint,
+            kind: VertexAttributeKind,
+            normalized: GLboolean,
+            stride: GLsizei,
+            pointer: *const std::ffi::c_void,
+        ),
+    >,
+    vertex_attrib_i_pointer_ptr: Option<
+        unsafe extern "system" fn(
+            index: GLuint,
+            size: GLint,
+            kind: VertexAttributeKind,
+            stride: GLsizei,
+            pointer: *const std::ffi::c_void,
+        ),
+    >,
+    vertex_attrib_divisor_ptr: Option<unsafe extern "system" fn(index: GLuint, divisor: GLuint)>,
+    delete_vertex_arrays_ptr:
+        Option<unsafe extern "system" fn(n: GLsizei, arrays: *const VertexArray)>,
+
+    //buffers
+    gen_buffers_ptr: Option<unsafe extern "system" fn(n: GLsizei, buffers: *mut Buffer)>,
+    bind_buffer_ptr: Option<unsafe extern "system" fn(target: BufferTarget, buffer: Buffer)>,
+    bind_buffer_base_ptr:
+        Option<unsafe extern "system" fn(target: BufferTarget, index: GLuint, buffer: Buffer)>,
+    bind_buffer_range_ptr: Option<
+        unsafe extern "system" fn(
+            target: BufferTarget,
+            index: GLuint,
+            buffer: Buffer,
+            offset: GLintptr,
+            size: GLsizeiptr,
+        ),
+    >,
+    buffer_data_ptr: Option<
+        unsafe extern "system" fn(
+            target: BufferTarget,
+            size: GLsizeiptr,
+            data: *const std::ffi::c_void,
+            usage: BufferUsage,
+        ),
+    >,
+    buffer_sub_data_ptr: Option<
+        unsafe extern "system" fn(
+            target: BufferTarget,
+            offset: GLintptr,
+            size: GLsizeiptr,
+            data: *const std::ffi::c_void,
+        ),
+    >,
+    copy_buffer_sub_data_ptr: Option<
+        unsafe extern "system" fn(
+            read_target: BufferTarget,
+            write_target: BufferTarget,
+            read_offset: GLintptr,
+            write_offset: GLintptr,
+            size: GLsizeiptr,
+        ),
+    >,
+    map_buffer_range_ptr: Option<
+        unsafe extern "system" fn(
+            target: BufferTarget,
+            offset: GLintptr,
+            length: GLsizeiptr,
+            access: MapAccess,
+        ) -> *mut std::ffi::c_void,
+    >,
+    flush_mapped_buffer_range_ptr: Option<
+        unsafe extern "system" fn(target: BufferTarget, offset: GLintptr, length: GLsizeiptr),
+    >,
+    unmap_buffer_ptr: Option<unsafe extern "system" fn(target: BufferTarget) -> GLboolean>,
+    delete_buffers_ptr: Option<unsafe extern "system" fn(n: GLsizei, buffers: *const Buffer)>,
+
+    //shaders
+    create_shader_ptr: Option<unsafe extern "system" fn(kind: ShaderKind) -> Shader>,
+    shader_source_ptr: Option<
+        unsafe extern "system" fn(
+            shader: Shader,
+            count: GLsizei,
+            string: *const *const GLchar,
+            length: *const GLint,
+        ),
+    >,
+    compile_shader_ptr: Option<unsafe extern "system" fn(shader: Shader)>,
+    delete_shader_ptr: Option<unsafe extern "system" fn(shader: Shader)>,
+    get_shaderiv_ptr: Option<
+        unsafe extern "system" fn(shader: Shader, pname: ShaderParameter, params: *mut GLint),
+    >,
+    get_shader_info_log_ptr: Option<
+        unsafe extern "system" fn(
+            shader: Shader,
+            buf_size: GLsizei,
+            length: *mut GLsizei,
+            info_log: *mut GLchar,
+        ),
+    >,
+
+    //program
+    create_program_ptr: Option<unsafe extern "system" fn() -> Program>,
+    attach_shader_ptr: Option<unsafe extern "system" fn(program: Program, shader: Shader)>,
+    link_program_ptr: Option<unsafe extern "system" fn(program: Program)>,
+    detach_shader_ptr: Option<unsafe extern "system" fn(program: Program, shader: Shader)>,
+    get_programiv_ptr: Option<
+        unsafe extern "system" fn(program: Program, pname: ProgramParameter, params: *mut GLint),
+    >,
+    get_program_info_log_ptr: Option<
+        unsafe extern "system" fn(
+            program: Program,
+            buf_size: GLsizei,
+            length: *mut GLsizei,
+            info_log: *mut GLchar,
+        ),
+    >,
+    use_program_ptr: Option<unsafe extern "system" fn(program: Program)>,
+    delete_program_ptr: Option<unsafe extern "system" fn(program: Program)>,
+
+    //textures
+    gen_textures_ptr: Option<unsafe extern "system" fn(n: GLsizei, textures: *mut Texture)>,
+    bind_texture_ptr: Option<unsafe extern "system" fn(target: TextureTarget, texture: Texture)>,
+    active_texture_ptr: Option<unsafe extern "system" fn(texture: TextureUnit)>,
+    tex_image_2d_ptr: Option<
+        unsafe extern "system" fn(
+            target: TextureTarget,
+            level: GLint,
+            internalformat: InternalFormat,
+            width: GLsizei,
+            height: GLsizei,
+            border: GLint,
+            format: PixelFormat,
+            kind: PixelType,
+            pixels: *const std::ffi::c_void,
+        ),
+    >,
+    tex_image_3d_ptr: Option<
+        unsafe extern "system" fn(
+            target: TextureTarget,
+            level: GLint,
+            internalformat: InternalFormat,
+            width: GLsizei,
+            height: GLsizei,
+            depth: GLsizei,
+            border: GLint,
+            format: PixelFormat,
+            kind: PixelType,
+            pixels: *const std::ffi::c_void,
+        ),
+    >,
+    tex_storage_2d_ptr: Option<
+        unsafe extern "system" fn(
+            target: TextureTarget,
+            levels: GLsizei,
+            internalformat: InternalFormat,
+            width: GLsizei,
+            height: GLsizei,
+        ),
+    >,
+    tex_storage_3d_ptr: Option<
+        unsafe extern "system" fn(
+            target: TextureTarget,
+            levels: GLsizei,
+            internalformat: InternalFormat,
+            width: GLsizei,
+            height: GLsizei,
+            depth: GLsizei,
+        ),
+    >,
+    tex_sub_image_2d_ptr: Option<
+        unsafe extern "system" fn(
+            target: TextureTarget,
+            level: GLint,
+            xoffset: GLint,
+            yoffset: GLint,
+            width: GLsizei,
+            height: GLsizei,
+            format: PixelFormat,
+            kind: PixelType,
+            pixels: *const std::ffi::c_void,
+        ),
+    >,
+    tex_sub_image_3d_ptr: Option<
+        unsafe extern "system" fn(
+            target: TextureTarget,
+            level: GLint,
+            xoffset: GLint,
+            yoffset: GLint,
+            zoffset: GLint,
+            width: GLsizei,
+            height: GLsizei,
+            depth: GLsizei,
+            format: PixelFormat,
+            kind: PixelType,
+            pixels: *const std::ffi::c_void,
+        ),
+    >,
+    tex_parameteri_ptr: Option<
+        unsafe extern "system" fn(target: TextureTarget, pname: TextureParameter, param: GLint),
+    >,
+    tex_parameterf_ptr: Option<
+        unsafe extern "system" fn(target: TextureTarget, pname: TextureParameter, param: GLfloat),
+    >,
+    generate_mipmap_ptr: Option<unsafe extern "system" fn(target: TextureTarget)>,
+    delete_textures_ptr: Option<unsafe extern "system" fn(n: GLsizei, textures: *const Texture)>,
+
+    //framebuffers
+    gen_framebuffers_ptr:
+        Option<unsafe extern "system" fn(n: GLsizei, framebuffers: *mut Framebuffer)>,
+    bind_framebuffer_ptr:
+        Option<unsafe extern "system" fn(target: FramebufferTarget, framebuffer: Framebuffer)>,
+    framebuffer_texture_2d_ptr: Option<
+        unsafe extern "system" fn(
+            target: FramebufferTarget,
+            attachment: Attachment,
+            textarget: TextureTarget,
+            texture: Texture,
+            level: GLint,
+        ),
+    >,
+    framebuffer_renderbuffer_ptr: Option<
+        unsafe extern "system" fn(
+            target: FramebufferTarget,
+            attachment: Attachment,
+            renderbuffertarget: RenderbufferTarget,
+            renderbuffer: Renderbuffer,
+        ),
+    >,
+    check_framebuffer_status_ptr:
+        Option<unsafe extern "system" fn(target: FramebufferTarget) -> FramebufferStatus>,
+    draw_buffers_ptr: Option<unsafe extern "system" fn(n: GLsizei, bufs: *const ColorBuffer)>,
+    read_buffer_ptr: Option<unsafe extern "system" fn(src: ColorBuffer)>,
+    blit_framebuffer_ptr: Option<
+        unsafe extern "system" fn(
+            src_x_0: GLint,
+            src_y_0: GLint,
+            src_x_1: GLint,
+            src_y_1: GLint,
+            dst_x_0: GLint,
+            dst_y_0: GLint,
+            dst_x_1: GLint,
+            dst_y_1: GLint,
+            mask: ClearMask,
+            filter: TextureFilter,
+        ),
+    >,
+    delete_framebuffers_ptr:
+        Option<unsafe extern "system" fn(n: GLsizei, framebuffers: *const Framebuffer)>,
+
+    //renderbuffers
+    gen_renderbuffers_ptr:
+        Option<unsafe extern "system" fn(n: GLsizei, renderbuffers: *mut Renderbuffer)>,
+    bind_renderbuffer_ptr:
+        Option<unsafe extern "system" fn(target: RenderbufferTarget, renderbuffer: Renderbuffer)>,
+    renderbuffer_storage_ptr: Option<
+        unsafe extern "system" fn(
+            target: RenderbufferTarget,
+            internalformat: InternalFormat,
+            width: GLsizei,
+            height: GLsizei,
+        ),
+    >,
+    renderbuffer_storage_multisample_ptr: Option<
+        unsafe extern "system" fn(
+            target: RenderbufferTarget,
+            samples: GLsizei,
+            internalformat: InternalFormat,
+            width: GLsizei,
+            height: GLsizei,
+        ),
+    >,
+    delete_renderbuffers_ptr:
+        Option<unsafe extern "system" fn(n: GLsizei, renderbuffers: *const Renderbuffer)>,
+
+    //uniforms
+    get_uniform_location_ptr:
+        Option<unsafe extern "system" fn(program: Program, name: *const GLchar) -> UniformLocation>,
+    uniform_1f_ptr: Option<unsafe extern "system" fn(location: UniformLocation, v0: GLfloat)>,
+    uniform_1fv_ptr: Option<
+        unsafe extern "system" fn(location: UniformLocation, count: GLsizei, value: *const GLfloat),
+    >,
+    uniform_1i_ptr: Option<unsafe extern "system" fn(location: UniformLocation, v0: GLint)>,
+    uniform_1iv_ptr: Option<
+        unsafe extern "system" fn(location: UniformLocation, count: GLsizei, value: *const GLint),
+    >,
+    uniform_1ui_ptr: Option<unsafe extern "system" fn(location: UniformLocation, v0: GLuint)>,
+    uniform_1uiv_ptr: Option<
+        unsafe extern "system" fn(location: UniformLocation, count: GLsizei, value: *const GLuint),
+    >,
+    uniform_2f_ptr:
+        Option<unsafe extern "system" fn(location: UniformLocation, v0: GLfloat, v1: GLfloat)>,
+    uniform_2fv_ptr: Option<
+        unsafe extern "system" fn(location: UniformLocation, count: GLsizei, value: *const GLfloat),
+    >,
+    uniform_2i_ptr:
+        Option<unsafe extern "system" fn(location: UniformLocation, v0: GLint, v1: GLint)>,
+    uniform_2iv_ptr: Option<
+        unsafe extern "system" fn(location: UniformLocation, count: GLsizei, value: *const GLint),
+    >,
+    uniform_2ui_ptr:
+        Option<unsafe extern "system" fn(location: UniformLocation, v0: GLuint, v1: GLuint)>,
+    uniform_2uiv_ptr: Option<
+        unsafe extern "system" fn(location: UniformLocation, count: GLsizei, value: *const GLuint),
+    >,
+    uniform_3f_ptr: Option<
+        unsafe extern "system" fn(location: UniformLocation, v0: GLfloat, v1: GLfloat, v2: GLfloat),
+    >,
+    uniform_3fv_ptr: Option<
+        unsafe extern "system" fn(location: UniformLocation, count: GLsizei, value: *const GLfloat),
+    >,
+    uniform_3i_ptr: Option<
+        unsafe extern "system" fn(location: UniformLocation, v0: GLint, v1: GLint, v2: GLint),
+    >,
+    uniform_3iv_ptr: Option<
+        unsafe extern "system" fn(location: UniformLocation, count: GLsizei, value: *const GLint),
+    >,
+    uniform_3ui_ptr: Option<
+        unsafe extern "system" fn(location: UniformLocation, v0: GLuint, v1: GLuint, v2: GLuint),
+    >,
+    uniform_3uiv_ptr: Option<
+        unsafe extern "system" fn(location: UniformLocation, count: GLsizei, value: *const GLuint),
+    >,
+    uniform_4f_ptr: Option<
+        unsafe extern "system" fn(
+            location: UniformLocation,
+            v0: GLfloat,
+            v1: GLfloat,
+            v2: GLfloat,
+            v3: GLfloat,
+        ),
+    >,
+    uniform_4fv_ptr: Option<
+        unsafe extern "system" fn(location: UniformLocation, count: GLsizei, value: *const GLfloat),
+    >,
+    uniform_4i_ptr: Option<
+        unsafe extern "system" fn(
+            location: UniformLocation,
+            v0: GLint,
+            v1: GLint,
+            v2: GLint,
+            v3: GLint,
+        ),
+    >,
+    uniform_4iv_ptr: Option<
+        unsafe extern "system" fn(location: UniformLocation, count: GLsizei, value: *const GLint),
+    >,
+    uniform_4ui_ptr: Option<
+        unsafe extern "system" fn(
+            location: UniformLocation,
+            v0: GLuint,
+            v1: GLuint,
+            v2: GLuint,
+            v3: GLuint,
+        ),
+    >,
+    uniform_4uiv_ptr: Option<
+        unsafe extern "system" fn(location: UniformLocation, count: GLsizei, value: *const GLuint),
+    >,
+    uniform_matrix_2fv_ptr: Option<
+        unsafe extern "system" fn(
+            location: UniformLocation,
+            count: GLsizei,
+            transpose: GLboolean,
+            value: *const GLfloat,
+        ),
+    >,
+    uniform_matrix_3fv_ptr: Option<
+        unsafe extern "system" fn(
+            location: UniformLocation,
+            count: GLsizei,
+            transpose: GLboolean,
+            value: *const GLfloat,
+        ),
+    >,
+    uniform_matrix_4fv_ptr: Option<
+        unsafe extern "system" fn(
+            location: UniformLocation,
+            count: GLsizei,
+            transpose: GLboolean,
+            value: *const GLfloat,
+        ),
+    >,
+    uniform_matrix_2x3fv_ptr: Option<
+        unsafe extern "system" fn(
+            location: UniformLocation,
+            count: GLsizei,
+            transpose: GLboolean,
+            value: *const GLfloat,
+        ),
+    >,
+    uniform_matrix_3x2fv_ptr: Option<
+        unsafe extern "system" fn(
+            location: UniformLocation,
+            count: GLsizei,
+            transpose: GLboolean,
+            value: *const GLfloat,
+        ),
+    >,
+    uniform_matrix_2x4fv_ptr: Option<
+        unsafe extern "system" fn(
+            location: UniformLocation,
+            count: GLsizei,
+            transpose: GLboolean,
+            value: *const GLfloat,
+        ),
+    >,
+    uniform_matrix_4x2fv_ptr: Option<
+        unsafe extern "system" fn(
+            location: UniformLocation,
+            count: GLsizei,
+            transpose: GLboolean,
+            value: *const GLfloat,
+        ),
+    >,
+    uniform_matrix_3x4fv_ptr: Option<
+        unsafe extern "system" fn(
+            location: UniformLocation,
+            count: GLsizei,
+            transpose: GLboolean,
+            value: *const GLfloat,
+        ),
+    >,
+    uniform_matrix_4x3fv_ptr: Option<
+        unsafe extern "system" fn(
+            location: UniformLocation,
+            count: GLsizei,
+            transpose: GLboolean,
+            value: *const GLfloat,
+        ),
+    >,
+    get_active_uniform_ptr: Option<
+        unsafe extern "system" fn(
+            program: Program,
+            index: GLuint,
+            buf_size: GLsizei,
+            length: *mut GLsizei,
+            size: *mut GLint,
+            kind: *mut UniformKind,
+            name: *mut GLchar,
+        ),
+    >,
+
+    //queries
+    get_integerv_ptr: Option<unsafe extern "system" fn(pname: IntegerParameter, data: *mut GLint)>,
+    get_integeri_v_ptr: Option<
+        unsafe extern "system" fn(target: IndexedParameter, index: GLuint, data: *mut GLint),
+    >,
+}
+
+#[allow(
+    clippy::let_and_return,
+    clippy::semicolon_if_nothing_returned,
+    clippy::similar_names,
+    clippy::too_many_arguments
+)]
+impl Api {
+    /// Loads all function pointers using a context function loader,
+    /// also called `ProcAddress`. Works with GLFW, SDL2 any pretty much any OpenGL ES context.
+    ///
+    /// # Errors
+    /// This function will return an error if any function pointer returns a null pointer.
+    ///
+    /// # Safety
+    /// Unfortunately, some drivers return wrong addresses that are indistinguishable from correct
+    /// ones, instead of being null pointers.
+    ///
+    #[allow(clippy::too_many_lines)]
+    pub unsafe fn with_loader(
+        loader: &impl Fn(&str) -> *const std::ffi::c_void,
+    ) -> Result<Self, Error> {
+        Ok(Self {
+            //debug
+            get_error_ptr: Some(loader.load("glGetError")?),
+
+            //state
+            enable_ptr: Some(loader.load("glEnable")?),
+            disable_ptr: Some(loader.load("glDisable")?),
+            is_enabled_ptr: Some(loader.load("glIsEnabled")?),
+            viewport_ptr: Some(loader.load("glViewport")?),
+            scissor_ptr: Some(loader.load("glScissor")?),
+            blend_func_ptr: Some(loader.load("glBlendFunc")?),
+            blend_func_separate_ptr: Some(loader.load("glBlendFuncSeparate")?),
+            blend_equation_ptr: Some(loader.load("glBlendEquation")?),
+            blend_equation_separate_ptr: Some(loader.load("glBlendEquationSeparate")?),
+            blend_color_ptr: Some(loader.load("glBlendColor")?),
+            depth_func_ptr: Some(loader.load("glDepthFunc")?),
+            depth_mask_ptr: Some(loader.load("glDepthMask")?),
+            depth_rangef_ptr: Some(loader.load("glDepthRangef")?),
+            stencil_func_ptr: Some(loader.load("glStencilFunc")?),
+            stencil_func_separate_ptr: Some(loader.load("glStencilFuncSeparate")?),
+            stencil_op_ptr: Some(loader.load("glStencilOp")?),
+            stencil_op_separate_ptr: Some(loader.load("glStencilOpSeparate")?),
+            stencil_mask_ptr: Some(loader.load("glStencilMask")?),
+            stencil_mask_separate_ptr: Some(loader.load("glStencilMaskSeparate")?),
+            cull_face_ptr: Some(loader.load("glCullFace")?),
+            front_face_ptr: Some(loader.load("glFrontFace")?),
+            polygon_offset_ptr: Some(loader.load("glPolygonOffset")?),
+            color_mask_ptr: Some(loader.load("glColorMask")?),
+            line_width_ptr: Some(loader.load("glLineWidth")?),
+            clear_ptr: Some(loader.load("glClear")?),
+            clear_color_ptr: Some(loader.load("glClearColor")?),
+
+            //draw
+            draw_arrays_ptr: Some(loader.load("glDrawArrays")?),
+            draw_elements_ptr: Some(loader.load("glDrawElements")?),
+            draw_range_elements_ptr: Some(loader.load("glDrawRangeElements")?),
+            draw_arrays_instanced_ptr: Some(loader.load("glDrawArraysInstanced")?),
+            draw_elements_instanced_ptr: Some(loader.load("glDrawElementsInstanced")?),
+
+            //vertex arrays
+            gen_vertex_arrays_ptr: Some(loader.load("glGenVertexArrays")?),
+            bind_vertex_array_ptr: Some(loader.load("glBindVertexArray")?),
+            enable_vertex_attrib_array_ptr: Some(loader.load("glEnableVertexAttribArray")?),
+            vertex_attrib_pointer_ptr: Some(loader.load("glVertexAttribPointer")?),
+            vertex_attrib_i_pointer_ptr: Some(loader.load("glVertexAttribIPointer")?),
+            vertex_attrib_divisor_ptr: Some(loader.load("glVertexAttribDivisor")?),
+            delete_vertex_arrays_ptr: Some(loader.load("glDeleteVertexArrays")?),
+
+            //buffers
+            gen_buffers_ptr: Some(loader.load("glGenBuffers")?),
+            bind_buffer_ptr: Some(loader.load("glBindBuffer")?),
+            bind_buffer_base_ptr: Some(loader.load("glBindBufferBase")?),
+            bind_buffer_range_ptr: Some(loader.load("glBindBufferRange")?),
+            buffer_data_ptr: Some(loader.load("glBufferData")?),
+            buffer_sub_data_ptr: Some(loader.load("glBufferSubData")?),
+            copy_buffer_sub_data_ptr: Some(loader.load("glCopyBufferSubData")?),
+            map_buffer_range_ptr: Some(loader.load("glMapBufferRange")?),
+            flush_mapped_buffer_range_ptr: Some(loader.load("glFlushMappedBufferRange")?),
+            unmap_buffer_ptr: Some(loader.load("glUnmapBuffer")?),
+            delete_buffers_ptr: Some(loader.load("glDeleteBuffers")?),
+
+            //shaders
+            create_shader_ptr: Some(loader.load("glCreateShader")?),
+            shader_source_ptr: Some(loader.load("glShaderSource")?),
+            compile_shader_ptr: Some(loader.load("glCompileShader")?),
+            delete_shader_ptr: Some(loader.load("glDeleteShader")?),
+            get_shaderiv_ptr: Some(loader.load("glGetShaderiv")?),
+            get_shader_info_log_ptr: Some(loader.load("glGetShaderInfoLog")?),
+
+            //program
+            create_program_ptr: Some(loader.load("glCreateProgram")?),
+            attach_shader_ptr: Some(loader.load("glAttachShader")?),
+            link_program_ptr: Some(loader.load("glLinkProgram")?),
+            detach_shader_ptr: Some(loader.load("glDetachShader")?),
+            get_programiv_ptr: Some(loader.load("glGetProgramiv")?),
+            get_program_info_log_ptr: Some(loader.load("glGetProgramInfoLog")?),
+            use_program_ptr: Some(loader.load("glUseProgram")?),
+            delete_program_ptr: Some(loader.load("glDeleteProgram")?),
+
+            //textures
+            gen_textures_ptr: Some(loader.load("glGenTextures")?),
+            bind_texture_ptr: Some(loader.load("glBindTexture")?),
+            active_texture_ptr: Some(loader.load("glActiveTexture")?),
+            tex_image_2d_ptr: Some(loader.load("glTexImage2D")?),
+            tex_image_3d_ptr: Some(loader.load("glTexImage3D")?),
+            tex_storage_2d_ptr: Some(loader.load("glTexStorage2D")?),
+            tex_storage_3d_ptr: Some(loader.load("glTexStorage3D")?),
+            tex_sub_image_2d_ptr: Some(loader.load("glTexSubImage2D")?),
+            tex_sub_image_3d_ptr: Some(loader.load("glTexSubImage3D")?),
+            tex_parameteri_ptr: Some(loader.load("glTexParameteri")?),
+            tex_parameterf_ptr: Some(loader.load("glTexParameterf")?),
+            generate_mipmap_ptr: Some(loader.load("glGenerateMipmap")?),
+            delete_textures_ptr: Some(loader.load("glDeleteTextures")?),
+
+            //framebuffers
+            gen_framebuffers_ptr: Some(loader.load("glGenFramebuffers")?),
+            bind_framebuffer_ptr: Some(loader.load("glBindFramebuffer")?),
+            framebuffer_texture_2d_ptr: Some(loader.load("glFramebufferTexture2D")?),
+            framebuffer_renderbuffer_ptr: Some(loader.load("glFramebufferRenderbuffer")?),
+            check_framebuffer_status_ptr: Some(loader.load("glCheckFramebufferStatus")?),
+            draw_buffers_ptr: Some(loader.load("glDrawBuffers")?),
+            read_buffer_ptr: Some(loader.load("glReadBuffer")?),
+            blit_framebuffer_ptr: Some(loader.load("glBlitFramebuffer")?),
+            delete_framebuffers_ptr: Some(loader.load("glDeleteFramebuffers")?),
+
+            //renderbuffers
+            gen_renderbuffers_ptr: Some(loader.load("glGenRenderbuffers")?),
+            bind_renderbuffer_ptr: Some(loader.load("glBindRenderbuffer")?),
+            renderbuffer_storage_ptr: Some(loader.load("glRenderbufferStorage")?),
+            renderbuffer_storage_multisample_ptr: Some(
+                loader.load("glRenderbufferStorageMultisample")?,
+            ),
+            delete_renderbuffers_ptr: Some(loader.load("glDeleteRenderbuffers")?),
+
+            //uniforms
+            get_uniform_location_ptr: Some(loader.load("glGetUniformLocation")?),
+            uniform_1f_ptr: Some(loader.load("glUniform1f")?),
+            uniform_1fv_ptr: Some(loader.load("glUniform1fv")?),
+            uniform_1i_ptr: Some(loader.load("glUniform1i")?),
+            uniform_1iv_ptr: Some(loader.load("glUniform1iv")?),
+            uniform_1ui_ptr: Some(loader.load("glUniform1ui")?),
+            uniform_1uiv_ptr: Some(loader.load("glUniform1uiv")?),
+            uniform_2f_ptr: Some(loader.load("glUniform2f")?),
+            uniform_2fv_ptr: Some(loader.load("glUniform2fv")?),
+            uniform_2i_ptr: Some(loader.load("glUniform2i")?),
+            uniform_2iv_ptr: Some(loader.load("glUniform2iv")?),
+            uniform_2ui_ptr: Some(loader.load("glUniform2ui")?),
+            uniform_2uiv_ptr: Some(loader.load("glUniform2uiv")?),
+            uniform_3f_ptr: Some(loader.load("glUniform3f")?),
+            uniform_3fv_ptr: Some(loader.load("glUniform3fv")?),
+            uniform_3i_ptr: Some(loader.load("glUniform3i")?),
+            uniform_3iv_ptr: Some(loader.load("glUniform3iv")?),
+            uniform_3ui_ptr: Some(loader.load("glUniform3ui")?),
+            uniform_3uiv_ptr: Some(loader.load("glUniform3uiv")?),
+            uniform_4f_ptr: Some(loader.load("glUniform4f")?),
+            uniform_4fv_ptr: Some(loader.load("glUniform4fv")?),
+            uniform_4i_ptr: Some(loader.load("glUniform4i")?),
+            uniform_4iv_ptr: Some(loader.load("glUniform4iv")?),
+            uniform_4ui_ptr: Some(loader.load("glUniform4ui")?),
+            uniform_4uiv_ptr: Some(loader.load("glUniform4uiv")?),
+            uniform_matrix_2fv_ptr: Some(loader.load("glUniformMatrix2fv")?),
+            uniform_matrix_3fv_ptr: Some(loader.load("glUniformMatrix3fv")?),
+            uniform_matrix_4fv_ptr: Some(loader.load("glUniformMatrix4fv")?),
+            uniform_matrix_2x3fv_ptr: Some(loader.load("glUniformMatrix2x3fv")?),
+            uniform_matrix_3x2fv_ptr: Some(loader.load("glUniformMatrix3x2fv")?),
+            uniform_matrix_2x4fv_ptr: Some(loader.load("glUniformMatrix2x4fv")?),
+            uniform_matrix_4x2fv_ptr: Some(loader.load("glUniformMatrix4x2fv")?),
+            uniform_matrix_3x4fv_ptr: Some(loader.load("glUniformMatrix3x4fv")?),
+            uniform_matrix_4x3fv_ptr: Some(loader.load("glUniformMatrix4x3fv")?),
+            get_active_uniform_ptr: Some(loader.load("glGetActiveUniform")?),
+
+            //queries
+            get_integerv_ptr: Some(loader.load("glGetIntegerv")?),
+            get_integeri_v_ptr: Some(loader.load("glGetIntegeri_v")?),
+        })
+    }
+
+    /// Like `with_loader`, but keeps going when a function pointer is null, e.g. for entry points
+    /// a driver doesn't expose. Use `is_loaded` or `missing_functions` to find out what is
+    /// available, calling a wrapper whose function is missing panics.
+    ///
+    /// # Safety
+    /// See the safety note in `with_loader`
+    #[must_use]
+    #[allow(clippy::too_many_lines)]
+    pub unsafe fn with_loader_partial(loader: &impl Fn(&str) -> *const std::ffi::c_void) -> Self {
+        Self {
+            //debug
+            get_error_ptr: loader.load("glGetError").ok(),
+
+            //state
+            enable_ptr: loader.load("glEnable").ok(),
+            disable_ptr: loader.load("glDisable").ok(),
+            is_enabled_ptr: loader.load("glIsEnabled").ok(),
+            viewport_ptr: loader.load("glViewport").ok(),
+            scissor_ptr: loader.load("glScissor").ok(),
+            blend_func_ptr: loader.load("glBlendFunc").ok(),
+            blend_func_separate_ptr: loader.load("glBlendFuncSeparate").ok(),
+            blend_equation_ptr: loader.load("glBlendEquation").ok(),
+            blend_equation_separate_ptr: loader.load("glBlendEquationSeparate").ok(),
+            blend_color_ptr: loader.load("glBlendColor").ok(),
+            depth_func_ptr: loader.load("glDepthFunc").ok(),
+            depth_mask_ptr: loader.load("glDepthMask").ok(),
+            depth_rangef_ptr: loader.load("glDepthRangef").ok(),
+            stencil_func_ptr: loader.load("glStencilFunc").ok(),
+            stencil_func_separate_ptr: loader.load("glStencilFuncSeparate").ok(),
+            stencil_op_ptr: loader.load("glStencilOp").ok(),
+            stencil_op_separate_ptr: loader.load("glStencilOpSeparate").ok(),
+            stencil_mask_ptr: loader.load("glStencilMask").ok(),
+            stencil_mask_separate_ptr: loader.load("glStencilMaskSeparate").ok(),
+            cull_face_ptr: loader.load("glCullFace").ok(),
+            front_face_ptr: loader.load("glFrontFace").ok(),
+            polygon_offset_ptr: loader.load("glPolygonOffset").ok(),
+            color_mask_ptr: loader.load("glColorMask").ok(),
+            line_width_ptr: loader.load("glLineWidth").ok(),
+            clear_ptr: loader.load("glClear").ok(),
+            clear_color_ptr: loader.load("glClearColor").ok(),
+
+            //draw
+            draw_arrays_ptr: loader.load("glDrawArrays").ok(),
+            draw_elements_ptr: loader.load("glDrawElements").ok(),
+            draw_range_elements_ptr: loader.load("glDrawRangeElements").ok(),
+            draw_arrays_instanced_ptr: loader.load("glDrawArraysInstanced").ok(),
+            draw_elements_instanced_ptr: loader.load("glDrawElementsInstanced").ok(),
+
+            //vertex arrays
+            gen_vertex_arrays_ptr: loader.load("glGenVertexArrays").ok(),
+            bind_vertex_array_ptr: loader.load("glBindVertexArray").ok(),
+            enable_vertex_attrib_array_ptr: loader.load("glEnableVertexAttribArray").ok(),
+            vertex_attrib_pointer_ptr: loader.load("glVertexAttribPointer").ok(),
+            vertex_attrib_i_pointer_ptr: loader.load("glVertexAttribIPointer").ok(),
+            vertex_attrib_divisor_ptr: loader.load("glVertexAttribDivisor").ok(),
+            delete_vertex_arrays_ptr: loader.load("glDeleteVertexArrays").ok(),
+
+            //buffers
+            gen_buffers_ptr: loader.load("glGenBuffers").ok(),
+            bind_buffer_ptr: loader.load("glBindBuffer").ok(),
+            bind_buffer_base_ptr: loader.load("glBindBufferBase").ok(),
+            bind_buffer_range_ptr: loader.load("glBindBufferRange").ok(),
+            buffer_data_ptr: loader.load("glBufferData").ok(),
+            buffer_sub_data_ptr: loader.load("glBufferSubData").ok(),
+            copy_buffer_sub_data_ptr: loader.load("glCopyBufferSubData").ok(),
+            map_buffer_range_ptr: loader.load("glMapBufferRange").ok(),
+            flush_mapped_buffer_range_ptr: loader.load("glFlushMappedBufferRange").ok(),
+            unmap_buffer_ptr: loader.load("glUnmapBuffer").ok(),
+            delete_buffers_ptr: loader.load("glDeleteBuffers").ok(),
+
+            //shaders
+            create_shader_ptr: loader.load("glCreateShader").ok(),
+            shader_source_ptr: loader.load("glShaderSource").ok(),
+            compile_shader_ptr: loader.load("glCompileShader").ok(),
+            delete_shader_ptr: loader.load("glDeleteShader").ok(),
+            get_shaderiv_ptr: loader.load("glGetShaderiv").ok(),
+            get_shader_info_log_ptr: loader.load("glGetShaderInfoLog").ok(),
+
+            //program
+            create_program_ptr: loader.load("glCreateProgram").ok(),
+            attach_shader_ptr: loader.load("glAttachShader").ok(),
+            link_program_ptr: loader.load("glLinkProgram").ok(),
+            detach_shader_ptr: loader.load("glDetachShader").ok(),
+            get_programiv_ptr: loader.load("glGetProgramiv").ok(),
+            get_program_info_log_ptr: loader.load("glGetProgramInfoLog").ok(),
+            use_program_ptr: loader.load("glUseProgram").ok(),
+            delete_program_ptr: loader.load("glDeleteProgram").ok(),
+
+            //textures
+            gen_textures_ptr: loader.load("glGenTextures").ok(),
+            bind_texture_ptr: loader.load("glBindTexture").ok(),
+            active_texture_ptr: loader.load("glActiveTexture").ok(),
+            tex_image_2d_ptr: loader.load("glTexImage2D").ok(),
+            tex_image_3d_ptr: loader.load("glTexImage3D").ok(),
+            tex_storage_2d_ptr: loader.load("glTexStorage2D").ok(),
+            tex_storage_3d_ptr: loader.load("glTexStorage3D").ok(),
+            tex_sub_image_2d_ptr: loader.load("glTexSubImage2D").ok(),
+            tex_sub_image_3d_ptr: loader.load("glTexSubImage3D").ok(),
+            tex_parameteri_ptr: loader.load("glTexParameteri").ok(),
+            tex_parameterf_ptr: loader.load("glTexParameterf").ok(),
+            generate_mipmap_ptr: loader.load("glGenerateMipmap").ok(),
+            delete_textures_ptr: loader.load("glDeleteTextures").ok(),
+
+            //framebuffers
+            gen_framebuffers_ptr: loader.load("glGenFramebuffers").ok(),
+            bind_framebuffer_ptr: loader.load("glBindFramebuffer").ok(),
+            framebuffer_texture_2d_ptr: loader.load("glFramebufferTexture2D").ok(),
+            framebuffer_renderbuffer_ptr: loader.load("glFramebufferRenderbuffer").ok(),
+            check_framebuffer_status_ptr: loader.load("glCheckFramebufferStatus").ok(),
+            draw_buffers_ptr: loader.load("glDrawBuffers").ok(),
+            read_buffer_ptr: loader.load("glReadBuffer").ok(),
+            blit_framebuffer_ptr: loader.load("glBlitFramebuffer").ok(),
+            delete_framebuffers_ptr: loader.load("glDeleteFramebuffers").ok(),
+
+            //renderbuffers
+            gen_renderbuffers_ptr: loader.load("glGenRenderbuffers").ok(),
+            bind_renderbuffer_ptr: loader.load("glBindRenderbuffer").ok(),
+            renderbuffer_storage_ptr: loader.load("glRenderbufferStorage").ok(),
+            renderbuffer_storage_multisample_ptr: loader
+                .load("glRenderbufferStorageMultisample")
+                .ok(),
+            delete_renderbuffers_ptr: loader.load("glDeleteRenderbuffers").ok(),
+
+            //uniforms
+            get_uniform_location_ptr: loader.load("glGetUniformLocation").ok(),
+            uniform_1f_ptr: loader.load("glUniform1f").ok(),
+            uniform_1fv_ptr: loader.load("glUniform1fv").ok(),
+            uniform_1i_ptr: loader.load("glUniform1i").ok(),
+            uniform_1iv_ptr: loader.load("glUniform1iv").ok(),
+            uniform_1ui_ptr: loader.load("glUniform1ui").ok(),
+            uniform_1uiv_ptr: loader.load("glUniform1uiv").ok(),
+            uniform_2f_ptr: loader.load("glUniform2f").ok(),
+            uniform_2fv_ptr: loader.load("glUniform2fv").ok(),
+            uniform_2i_ptr: loader.load("glUniform2i").ok(),
+            uniform_2iv_ptr: loader.load("glUniform2iv").ok(),
+            uniform_2ui_ptr: loader.load("glUniform2ui").ok(),
+            uniform_2uiv_ptr: loader.load("glUniform2uiv").ok(),
+            uniform_3f_ptr: loader.load("glUniform3f").ok(),
+            uniform_3fv_ptr: loader.load("glUniform3fv").ok(),
+            uniform_3i_ptr: loader.load("glUniform3i").ok(),
+            uniform_3iv_ptr: loader.load("glUniform3iv").ok(),
+            uniform_3ui_ptr: loader.load("glUniform3ui").ok(),
+            uniform_3uiv_ptr: loader.load("glUniform3uiv").ok(),
+            uniform_4f_ptr: loader.load("glUniform4f").ok(),
+            uniform_4fv_ptr: loader.load("glUniform4fv").ok(),
+            uniform_4i_ptr: loader.load("glUniform4i").ok(),
+            uniform_4iv_ptr: loader.load("glUniform4iv").ok(),
+            uniform_4ui_ptr: loader.load("glUniform4ui").ok(),
+            uniform_4uiv_ptr: loader.load("glUniform4uiv").ok(),
+            uniform_matrix_2fv_ptr: loader.load("glUniformMatrix2fv").ok(),
+            uniform_matrix_3fv_ptr: loader.load("glUniformMatrix3fv").ok(),
+            uniform_matrix_4fv_ptr: loader.load("glUniformMatrix4fv").ok(),
+            uniform_matrix_2x3fv_ptr: loader.load("glUniformMatrix2x3fv").ok(),
+            uniform_matrix_3x2fv_ptr: loader.load("glUniformMatrix3x2fv").ok(),
+            uniform_matrix_2x4fv_ptr: loader.load("glUniformMatrix2x4fv").ok(),
+            uniform_matrix_4x2fv_ptr: loader.load("glUniformMatrix4x2fv").ok(),
+            uniform_matrix_3x4fv_ptr: loader.load("glUniformMatrix3x4fv").ok(),
+            uniform_matrix_4x3fv_ptr: loader.load("glUniformMatrix4x3fv").ok(),
+            get_active_uniform_ptr: loader.load("glGetActiveUniform").ok(),
+
+            //queries
+            get_integerv_ptr: loader.load("glGetIntegerv").ok(),
+            get_integeri_v_ptr: loader.load("glGetIntegeri_v").ok(),
+        }
+    }
+
+    /// Returns true if `symbol`, e.g. `"glDebugMessageCallback"`, is part of this `Api` and was
+    /// found by the loader.
+    #[must_use]
+    pub fn is_loaded(&self, symbol: &str) -> bool {
+        self.symbols().any(|(s, loaded)| s == symbol && loaded)
+    }
+
+    /// Symbols the loader returned a null pointer for, always empty after `with_loader`.
+    pub fn missing_functions(&self) -> impl Iterator<Item = &'static str> {
+        self.symbols()
+            .filter_map(|(symbol, loaded)| (!loaded).then_some(symbol))
+    }
+
+    #[allow(clippy::too_many_lines)]
+    fn symbols(&self) -> impl Iterator<Item = (&'static str, bool)> {
+        [
+            ("glGetError", self.get_error_ptr.is_some()),
+            ("glEnable", self.enable_ptr.is_some()),
+            ("glDisable", self.disable_ptr.is_some()),
+            ("glIsEnabled", self.is_enabled_ptr.is_some()),
+            ("glViewport", self.viewport_ptr.is_some()),
+            ("glScissor", self.scissor_ptr.is_some()),
+            ("glBlendFunc", self.blend_func_ptr.is_some()),
+            (
+                "glBlendFuncSeparate",
+                self.blend_func_separate_ptr.is_some(),
+            ),
+            ("glBlendEquation", self.blend_equation_ptr.is_some()),
+            (
+                "glBlendEquationSeparate",
+                self.blend_equation_separate_ptr.is_some(),
+            ),
+            ("glBlendColor", self.blend_color_ptr.is_some()),
+            ("glDepthFunc", self.depth_func_ptr.is_some()),
+            ("glDepthMask", self.depth_mask_ptr.is_some()),
+            ("glDepthRangef", self.depth_rangef_ptr.is_some()),
+            ("glStencilFunc", self.stencil_func_ptr.is_some()),
+            (
+                "glStencilFuncSeparate",
+                self.stencil_func_separate_ptr.is_some(),
+            ),
+            ("glStencilOp", self.stencil_op_ptr.is_some()),
+            (
+                "glStencilOpSeparate",
+                self.stencil_op_separate_ptr.is_some(),
+            ),
+            ("glStencilMask", self.stencil_mask_ptr.is_some()),
+            (
+                "glStencilMaskSeparate",
+                self.stencil_mask_separate_ptr.is_some(),
+            ),
+            ("glCullFace", self.cull_face_ptr.is_some()),
+            ("glFrontFace", self.front_face_ptr.is_some()),
+            ("glPolygonOffset", self.polygon_offset_ptr.is_some()),
+            ("glColorMask", self.color_mask_ptr.is_some()),
+            ("glLineWidth", self.line_width_ptr.is_some()),
+            ("glClear", self.clear_ptr.is_some()),
+            ("glClearColor", self.clear_color_ptr.is_some()),
+            ("glDrawArrays", self.draw_arrays_ptr.is_some()),
+            ("glDrawElements", self.draw_elements_ptr.is_some()),
+            (
+                "glDrawRangeElements",
+                self.draw_range_elements_ptr.is_some(),
+            ),
+            (
+                "glDrawArraysInstanced",
+                self.draw_arrays_instanced_ptr.is_some(),
+            ),
+            (
+                "glDrawElementsInstanced",
+                self.draw_elements_instanced_ptr.is_some(),
+            ),
+            ("glGenVertexArrays", self.gen_vertex_arrays_ptr.is_some()),
+            ("glBindVertexArray", self.bind_vertex_array_ptr.is_some()),
+            (
+                "glEnableVertexAttribArray",
+                self.enable_vertex_attrib_array_ptr.is_some(),
+            ),
+            (
+                "glVertexAttribPointer",
+                self.vertex_attrib_pointer_ptr.is_some(),
+            ),
+            (
+                "glVertexAttribIPointer",
+                self.vertex_attrib_i_pointer_ptr.is_some(),
+            ),
+            (
+                "glVertexAttribDivisor",
+                self.vertex_attrib_divisor_ptr.is_some(),
+            ),
+            (
+                "glDeleteVertexArrays",
+                self.delete_vertex_arrays_ptr.is_some(),
+            ),
+            ("glGenBuffers", self.gen_buffers_ptr.is_some()),
+            ("glBindBuffer", self.bind_buffer_ptr.is_some()),
+            ("glBindBufferBase", self.bind_buffer_base_ptr.is_some()),
+            ("glBindBufferRange", self.bind_buffer_range_ptr.is_some()),
+            ("glBufferData", self.buffer_data_ptr.is_some()),
+            ("glBufferSubData", self.buffer_sub_data_ptr.is_some()),
+            (
+                "glCopyBufferSubData",
+                self.copy_buffer_sub_data_ptr.is_some(),
+            ),
+            ("glMapBufferRange", self.map_buffer_range_ptr.is_some()),
+            (
+                "glFlushMappedBufferRange",
+                self.flush_mapped_buffer_range_ptr.is_some(),
+            ),
+            ("glUnmapBuffer", self.unmap_buffer_ptr.is_some()),
+            ("glDeleteBuffers", self.delete_buffers_ptr.is_some()),
+            ("glCreateShader", self.create_shader_ptr.is_some()),
+            ("glShaderSource", self.shader_source_ptr.is_some()),
+            ("glCompileShader", self.compile_shader_ptr.is_some()),
+            ("glDeleteShader", self.delete_shader_ptr.is_some()),
+            ("glGetShaderiv", self.get_shaderiv_ptr.is_some()),
+            ("glGetShaderInfoLog", self.get_shader_info_log_ptr.is_some()),
+            ("glCreateProgram", self.create_program_ptr.is_some()),
+            ("glAttachShader", self.attach_shader_ptr.is_some()),
+            ("glLinkProgram", self.link_program_ptr.is_some()),
+            ("glDetachShader", self.detach_shader_ptr.is_some()),
+            ("glGetProgramiv", self.get_programiv_ptr.is_some()),
+            (
+                "glGetProgramInfoLog",
+                self.get_program_info_log_ptr.is_some(),
+            ),
+            ("glUseProgram", self.use_program_ptr.is_some()),
+            ("glDeleteProgram", self.delete_program_ptr.is_some()),
+            ("glGenTextures", self.gen_textures_ptr.is_some()),
+            ("glBindTexture", self.bind_texture_ptr.is_some()),
+            ("glActiveTexture", self.active_texture_ptr.is_some()),
+            ("glTexImage2D", self.tex_image_2d_ptr.is_some()),
+            ("glTexImage3D", self.tex_image_3d_ptr.is_some()),
+            ("glTexStorage2D", self.tex_storage_2d_ptr.is_some()),
+            ("glTexStorage3D", self.tex_storage_3d_ptr.is_some()),
+            ("glTexSubImage2D", self.tex_sub_image_2d_ptr.is_some()),
+            ("glTexSubImage3D", self.tex_sub_image_3d_ptr.is_some()),
+            ("glTexParameteri", self.tex_parameteri_ptr.is_some()),
+            ("glTexParameterf", self.tex_parameterf_ptr.is_some()),
+            ("glGenerateMipmap", self.generate_mipmap_ptr.is_some()),
+            ("glDeleteTextures", self.delete_textures_ptr.is_some()),
+            ("glGenFramebuffers", self.gen_framebuffers_ptr.is_some()),
+            ("glBindFramebuffer", self.bind_framebuffer_ptr.is_some()),
+            (
+                "glFramebufferTexture2D",
+                self.framebuffer_texture_2d_ptr.is_some(),
+            ),
+            (
+                "glFramebufferRenderbuffer",
+                self.framebuffer_renderbuffer_ptr.is_some(),
+            ),
+            (
+                "glCheckFramebufferStatus",
+                self.check_framebuffer_status_ptr.is_some(),
+            ),
+            ("glDrawBuffers", self.draw_buffers_ptr.is_some()),
+            ("glReadBuffer", self.read_buffer_ptr.is_some()),
+            ("glBlitFramebuffer", self.blit_framebuffer_ptr.is_some()),
+            (
+                "glDeleteFramebuffers",
+                self.delete_framebuffers_ptr.is_some(),
+            ),
+            ("glGenRenderbuffers", self.gen_renderbuffers_ptr.is_some()),
+            ("glBindRenderbuffer", self.bind_renderbuffer_ptr.is_some()),
+            (
+                "glRenderbufferStorage",
+                self.renderbuffer_storage_ptr.is_some(),
+            ),
+            (
+                "glRenderbufferStorageMultisample",
+                self.renderbuffer_storage_multisample_ptr.is_some(),
+            ),
+            (
+                "glDeleteRenderbuffers",
+                self.delete_renderbuffers_ptr.is_some(),
+            ),
+            (
+                "glGetUniformLocation",
+                self.get_uniform_location_ptr.is_some(),
+            ),
+            ("glUniform1f", self.uniform_1f_ptr.is_some()),
+            ("glUniform1fv", self.uniform_1fv_ptr.is_some()),
+            ("glUniform1i", self.uniform_1i_ptr.is_some()),
+            ("glUniform1iv", self.uniform_1iv_ptr.is_some()),
+            ("glUniform1ui", self.uniform_1ui_ptr.is_some()),
+            ("glUniform1uiv", self.uniform_1uiv_ptr.is_some()),
+            ("glUniform2f", self.uniform_2f_ptr.is_some()),
+            ("glUniform2fv", self.uniform_2fv_ptr.is_some()),
+            ("glUniform2i", self.uniform_2i_ptr.is_some()),
+            ("glUniform2iv", self.uniform_2iv_ptr.is_some()),
+            ("glUniform2ui", self.uniform_2ui_ptr.is_some()),
+            ("glUniform2uiv", self.uniform_2uiv_ptr.is_some()),
+            ("glUniform3f", self.uniform_3f_ptr.is_some()),
+            ("glUniform3fv", self.uniform_3fv_ptr.is_some()),
+            ("glUniform3i", self.uniform_3i_ptr.is_some()),
+            ("glUniform3iv", self.uniform_3iv_ptr.is_some()),
+            ("glUniform3ui", self.uniform_3ui_ptr.is_some()),
+            ("glUniform3uiv", self.uniform_3uiv_ptr.is_some()),
+            ("glUniform4f", self.uniform_4f_ptr.is_some()),
+            ("glUniform4fv", self.uniform_4fv_ptr.is_some()),
+            ("glUniform4i", self.uniform_4i_ptr.is_some()),
+            ("glUniform4iv", self.uniform_4iv_ptr.is_some()),
+            ("glUniform4ui", self.uniform_4ui_ptr.is_some()),
+            ("glUniform4uiv", self.uniform_4uiv_ptr.is_some()),
+            ("glUniformMatrix2fv", self.uniform_matrix_2fv_ptr.is_some()),
+            ("glUniformMatrix3fv", self.uniform_matrix_3fv_ptr.is_some()),
+            ("glUniformMatrix4fv", self.uniform_matrix_4fv_ptr.is_some()),
+            (
+                "glUniformMatrix2x3fv",
+                self.uniform_matrix_2x3fv_ptr.is_some(),
+            ),
+            (
+                "glUniformMatrix3x2fv",
+                self.uniform_matrix_3x2fv_ptr.is_some(),
+            ),
+            (
+                "glUniformMatrix2x4fv",
+                self.uniform_matrix_2x4fv_ptr.is_some(),
+            ),
+            (
+                "glUniformMatrix4x2fv",
+                self.uniform_matrix_4x2fv_ptr.is_some(),
+            ),
+            (
+                "glUniformMatrix3x4fv",
+                self.uniform_matrix_3x4fv_ptr.is_some(),
+            ),
+            (
+                "glUniformMatrix4x3fv",
+                self.uniform_matrix_4x3fv_ptr.is_some(),
+            ),
+            ("glGetActiveUniform", self.get_active_uniform_ptr.is_some()),
+            ("glGetIntegerv", self.get_integerv_ptr.is_some()),
+            ("glGetIntegeri_v", self.get_integeri_v_ptr.is_some()),
+        ]
+        .into_iter()
+    }
+
+    // DEBUG
+
+    /// Returns and clears one of the recorded error flags, `NO_ERROR` if there are none.
+    /// `check_errors` clears all of them.
+    ///
+    /// # Safety
+    /// See the safety note in `with_loader`
+    #[inline]
+    #[must_use]
+    pub unsafe fn get_error(&self) -> ErrorCode {
+        let function = self
+            .get_error_ptr
+            .unwrap_or_else(|| not_loaded("glGetError"));
+        unsafe { function() }
+    }
+
+    // STATE
+
+    /// Enables certain state or context capabilities.
+    ///
+    /// # Safety
+    /// See the safety note in `with_loader`
+    #[inline]
+    #[cfg_attr(feature = "trace", track_caller)]
+    pub unsafe fn enable(&self, cap: Capability) {
+        let function = self.enable_ptr.unwrap_or_else(|| not_loaded("glEnable"));
+        #[cfg(feature = "trace")]
+        log::trace!("glEnable(cap: {cap:?})");
+        unsafe { function(cap) };
+        #[cfg(feature = "trace")]
+        crate::trace::check("glEnable", || unsafe { self.get_error() }.error());
+    }
+
+    /// # Safety
+    /// See the safety note in `with_loader`
+    #[inline]
+    #[cfg_attr(feature = "trace", track_caller)]
+    pub unsafe fn disable(&self, cap: Capability) {
+        let function = self.disable_ptr.unwrap_or_else(|| not_loaded("glDisable"));
+        #[cfg(feature = "trace")]
+        log::trace!("glDisable(cap: {cap:?})");
+        unsafe { function(cap) };
+        #[cfg(feature = "trace")]
+        crate::trace::check("glDisable", || unsafe { self.get_error() }.error());
+    }
+
+    /// # Safety
+    /// See the safety note in `with_loader`
+    #[inline]
+    #[cfg_attr(feature = "trace", track_caller)]
+    #[must_use]
+    pub unsafe fn is_enabled(&self, cap: Capability) -> GLboolean {
+        let function = self
+            .is_enabled_ptr
+            .unwrap_or_else(|| not_loaded("glIsEnabled"));
+        #[cfg(feature = "trace")]
+        log::trace!("glIsEnabled(cap: {cap:?})");
+        let result = unsafe { function(cap) };
+        #[cfg(feature = "trace")]
+        crate::trace::check("glIsEnabled", || unsafe { self.get_error() }.error());
+        result
+    }
+
+    /// # Safety
+    /// See the safety note in `with_loader`
+    #[inline]
+    #[cfg_attr(feature = "trace", track_caller)]
+    pub unsafe fn viewport(&self, x: GLint, y: GLint, width: GLsizei, height: GLsizei) {
+        let function = self
+            .viewport_ptr
+            .unwrap_or_else(|| not_loaded("glViewport"));
+        #[cfg(feature = "trace")]
+        log::trace!("glViewport(x: {x:?}, y: {y:?}, width: {width:?}, height: {height:?})");
+        unsafe { function(x, y, width, height) };
+        #[cfg(feature = "trace")]
+        crate::trace::check("glViewport", || unsafe { self.get_error() }.error());
+    }
+
+    /// Sets the rectangle `Capability::SCISSOR_TEST` discards fragments outside of.
+    ///
+    /// # Safety
+    /// See the safety note in `with_loader`
+    #[inline]
+    #[cfg_attr(feature = "trace", track_caller)]
+    pub unsafe fn scissor(&self, x: GLint, y: GLint, width: GLsizei, height: GLsizei) {
+        let function = self.scissor_ptr.unwrap_or_else(|| not_loaded("glScissor"));
+        #[cfg(feature = "trace")]
+        log::trace!("glScissor(x: {x:?}, y: {y:?}, width: {width:?}, height: {height:?})");
+        unsafe { function(x, y, width, height) };
+        #[cfg(feature = "trace")]
+        crate::trace::check("glScissor", || unsafe { self.get_error() }.error());
+    }
+
+    /// # Safety
+    /// See the safety note in `with_loader`
+    #[inline]
+    #[cfg_attr(feature = "trace", track_caller)]
+    pub unsafe fn blend_func(&self, sfactor: BlendFactor, dfactor: BlendFactor) {
+        let function = self
+            .blend_func_ptr
+            .unwrap_or_else(|| not_loaded("glBlendFunc"));
+        #[cfg(feature = "trace")]
+        log::trace!("glBlendFunc(sfactor: {sfactor:?}, dfactor: {dfactor:?})");
+        unsafe { function(sfactor, dfactor) };
+        #[cfg(feature = "trace")]
+        crate::trace::check("glBlendFunc", || unsafe { self.get_error() }.error());
+    }
+
+    /// # Safety
+    /// See the safety note in `with_loader`
+    #[inline]
+    #[cfg_attr(feature = "trace", track_caller)]
+    pub unsafe fn blend_func_separate(
+        &self,
+        sfactor_rgb: BlendFactor,
+        dfactor_rgb: BlendFactor,
+        sfactor_alpha: BlendFactor,
+        dfactor_alpha: BlendFactor,
+    ) {
+        let function = self
+            .blend_func_separate_ptr
+            .unwrap_or_else(|| not_loaded("glBlendFuncSeparate"));
+        #[cfg(feature = "trace")]
+        log::trace!("glBlendFuncSeparate(sfactor_rgb: {sfactor_rgb:?}, dfactor_rgb: {dfactor_rgb:?}, sfactor_alpha: {sfactor_alpha:?}, dfactor_alpha: {dfactor_alpha:?})");
+        unsafe { function(sfactor_rgb, dfactor_rgb, sfactor_alpha, dfactor_alpha) };
+        #[cfg(feature = "trace")]
+        crate::trace::check("glBlendFuncSeparate", || {
+            unsafe { self.get_error() }.error()
+        });
+    }
+
+    /// # Safety
+    /// See the safety note in `with_loader`
+    #[inline]
+    #[cfg_attr(feature = "trace", track_caller)]
+    pub unsafe fn blend_equation(&self, mode: BlendEquation) {
+        let function = self
+            .blend_equation_ptr
+            .unwrap_or_else(|| not_loaded("glBlendEquation"));
+        #[cfg(feature = "trace")]
+        log::trace!("glBlendEquation(mode: {mode:?})");
+        unsafe { function(mode) };
+        #[cfg(feature = "trace")]
+        crate::trace::check("glBlendEquation", || unsafe { self.get_error() }.error());
+    }
+
+    /// # Safety
+    /// See the safety note in `with_loader`
+    #[inline]
+    #[cfg_attr(feature = "trace", track_caller)]
+    pub unsafe fn blend_equation_separate(
+        &self,
+        mode_rgb: BlendEquation,
+        mode_alpha: BlendEquation,
+    ) {
+        let function = self
+            .blend_equation_separate_ptr
+            .unwrap_or_else(|| not_loaded("glBlendEquationSeparate"));
+        #[cfg(feature = "trace")]
+        log::trace!("glBlendEquationSeparate(mode_rgb: {mode_rgb:?}, mode_alpha: {mode_alpha:?})");
+        unsafe { function(mode_rgb, mode_alpha) };
+        #[cfg(feature = "trace")]
+        crate::trace::check("glBlendEquationSeparate", || {
+            unsafe { self.get_error() }.error()
+        });
+    }
+
+    /// Sets the color of the `CONSTANT_*` `BlendFactor`s.
+    ///
+    /// # Safety
+    /// See the safety note in `with_loader`
+    #[inline]
+    #[cfg_attr(feature = "trace", track_caller)]
+    pub unsafe fn blend_color(&self, red: GLfloat, green: GLfloat, blue: GLfloat, alpha: GLfloat) {
+        let function = self
+            .blend_color_ptr
+            .unwrap_or_else(|| not_loaded("glBlendColor"));
+        #[cfg(feature = "trace")]
+        log::trace!(
+            "glBlendColor(red: {red:?}, green: {green:?}, blue: {blue:?}, alpha: {alpha:?})"
+        );
+        unsafe { function(red, green, blue, alpha) };
+        #[cfg(feature = "trace")]
+        crate::trace::check("glBlendColor", || unsafe { self.get_error() }.error());
+    }
+
+    /// # Safety
+    /// See the safety note in `with_loader`
+    #[inline]
+    #[cfg_attr(feature = "trace", track_caller)]
+    pub unsafe fn depth_func(&self, func: CompareFunc) {
+        let function = self
+            .depth_func_ptr
+            .unwrap_or_else(|| not_loaded("glDepthFunc"));
+        #[cfg(feature = "trace")]
+        log::trace!("glDepthFunc(func: {func:?})");
+        unsafe { function(func) };
+        #[cfg(feature = "trace")]
+        crate::trace::check("glDepthFunc", || unsafe { self.get_error() }.error());
+    }
+
+    /// # Safety
+    /// See the safety note in `with_loader`
+    #[inline]
+    #[cfg_attr(feature = "trace", track_caller)]
+    pub unsafe fn depth_mask(&self, flag: GLboolean) {
+        let function = self
+            .depth_mask_ptr
+            .unwrap_or_else(|| not_loaded("glDepthMask"));
+        #[cfg(feature = "trace")]
+        log::trace!("glDepthMask(flag: {flag:?})");
+        unsafe { function(flag) };
+        #[cfg(feature = "trace")]
+        crate::trace::check("glDepthMask", || unsafe { self.get_error() }.error());
+    }
+
+    /// # Safety
+    /// See the safety note in `with_loader`
+    #[inline]
+    #[cfg_attr(feature = "trace", track_caller)]
+    pub unsafe fn depth_rangef(&self, n: GLfloat, f: GLfloat) {
+        let function = self
+            .depth_rangef_ptr
+            .unwrap_or_else(|| not_loaded("glDepthRangef"));
+        #[cfg(feature = "trace")]
+        log::trace!("glDepthRangef(n: {n:?}, f: {f:?})");
+        unsafe { function(n, f) };
+        #[cfg(feature = "trace")]
+        crate::trace::check("glDepthRangef", || unsafe { self.get_error() }.error());
+    }
+
+    /// # Safety
+    /// See the safety note in `with_loader`
+    #[inline]
+    #[cfg_attr(feature = "trace", track_caller)]
+    pub unsafe fn stencil_func(&self, func: CompareFunc, reference: GLint, mask: GLuint) {
+        let function = self
+            .stencil_func_ptr
+            .unwrap_or_else(|| not_loaded("glStencilFunc"));
+        #[cfg(feature = "trace")]
+        log::trace!("glStencilFunc(func: {func:?}, reference: {reference:?}, mask: {mask:?})");
+        unsafe { function(func, reference, mask) };
+        #[cfg(feature = "trace")]
+        crate::trace::check("glStencilFunc", || unsafe { self.get_error() }.error());
+    }
+
+    /// # Safety
+    /// See the safety note in `with_loader`
+    #[inline]
+    #[cfg_attr(feature = "trace", track_caller)]
+    pub unsafe fn stencil_func_separate(
+        &self,
+        face: Face,
+        func: CompareFunc,
+        reference: GLint,
+        mask: GLuint,
+    ) {
+        let function = self
+            .stencil_func_separate_ptr
+            .unwrap_or_else(|| not_loaded("glStencilFuncSeparate"));
+        #[cfg(feature = "trace")]
+        log::trace!("glStencilFuncSeparate(face: {face:?}, func: {func:?}, reference: {reference:?}, mask: {mask:?})");
+        unsafe { function(face, func, reference, mask) };
+        #[cfg(feature = "trace")]
+        crate::trace::check("glStencilFuncSeparate", || {
+            unsafe { self.get_error() }.error()
+        });
+    }
+
+    /// # Safety
+    /// See the safety note in `with_loader`
+    #[inline]
+    #[cfg_attr(feature = "trace", track_caller)]
+    pub unsafe fn stencil_op(&self, fail: StencilOp, zfail: StencilOp, zpass: StencilOp) {
+        let function = self
+            .stencil_op_ptr
+            .unwrap_or_else(|| not_loaded("glStencilOp"));
+        #[cfg(feature = "trace")]
+        log::trace!("glStencilOp(fail: {fail:?}, zfail: {zfail:?}, zpass: {zpass:?})");
+        unsafe { function(fail, zfail, zpass) };
+        #[cfg(feature = "trace")]
+        crate::trace::check("glStencilOp", || unsafe { self.get_error() }.error());
+    }
+
+    /// # Safety
+    /// See the safety note in `with_loader`
+    #[inline]
+    #[cfg_attr(feature = "trace", track_caller)]
+    pub unsafe fn stencil_op_separate(
+        &self,
+        face: Face,
+        sfail: StencilOp,
+        dpfail: StencilOp,
+        dppass: StencilOp,
+    ) {
+        let function = self
+            .stencil_op_separate_ptr
+            .unwrap_or_else(|| not_loaded("glStencilOpSeparate"));
+        #[cfg(feature = "trace")]
+        log::trace!("glStencilOpSeparate(face: {face:?}, sfail: {sfail:?}, dpfail: {dpfail:?}, dppass: {dppass:?})");
+        unsafe { function(face, sfail, dpfail, dppass) };
+        #[cfg(feature = "trace")]
+        crate::trace::check("glStencilOpSeparate", || {
+            unsafe { self.get_error() }.error()
+        });
+    }
+
+    /// # Safety
+    /// See the safety note in `with_loader`
+    #[inline]
+    #[cfg_attr(feature = "trace", track_caller)]
+    pub unsafe fn stencil_mask(&self, mask: GLuint) {
+        let function = self
+            .stencil_mask_ptr
+            .unwrap_or_else(|| not_loaded("glStencilMask"));
+        #[cfg(feature = "trace")]
+        log::trace!("glStencilMask(mask: {mask:?})");
+        unsafe { function(mask) };
+        #[cfg(feature = "trace")]
+        crate::trace::check("glStencilMask", || unsafe { self.get_error() }.error());
+    }
+
+    /// # Safety
+    /// See the safety note in `with_loader`
+    #[inline]
+    #[cfg_attr(feature = "trace", track_caller)]
+    pub unsafe fn stencil_mask_separate(&self, face: Face, mask: GLuint) {
+        let function = self
+            .stencil_mask_separate_ptr
+            .unwrap_or_else(|| not_loaded("glStencilMaskSeparate"));
+        #[cfg(feature = "trace")]
+        log::trace!("glStencilMaskSeparate(face: {face:?}, mask: {mask:?})");
+        unsafe { function(face, mask) };
+        #[cfg(feature = "trace")]
+        crate::trace::check("glStencilMaskSeparate", || {
+            unsafe { self.get_error() }.error()
+        });
+    }
+
+    /// # Safety
+    /// See the safety note in `with_loader`
+    #[inline]
+    #[cfg_attr(feature = "trace", track_caller)]
+    pub unsafe fn cull_face(&self, mode: Face) {
+        let function = self
+            .cull_face_ptr
+            .unwrap_or_else(|| not_loaded("glCullFace"));
+        #[cfg(feature = "trace")]
+        log::trace!("glCullFace(mode: {mode:?})");
+        unsafe { function(mode) };
+        #[cfg(feature = "trace")]
+        crate::trace::check("glCullFace", || unsafe { self.get_error() }.error());
+    }
+
+    /// # Safety
+    /// See the safety note in `with_loader`
+    #[inline]
+    #[cfg_attr(feature = "trace", track_caller)]
+    pub unsafe fn front_face(&self, mode: FrontFace) {
+        let function = self
+            .front_face_ptr
+            .unwrap_or_else(|| not_loaded("glFrontFace"));
+        #[cfg(feature = "trace")]
+        log::trace!("glFrontFace(mode: {mode:?})");
+        unsafe { function(mode) };
+        #[cfg(feature = "trace")]
+        crate::trace::check("glFrontFace", || unsafe { self.get_error() }.error());
+    }
+
+    /// # Safety
+    /// See the safety note in `with_loader`
+    #[inline]
+    #[cfg_attr(feature = "trace", track_caller)]
+    pub unsafe fn polygon_offset(&self, factor: GLfloat, units: GLfloat) {
+        let function = self
+            .polygon_offset_ptr
+            .unwrap_or_else(|| not_loaded("glPolygonOffset"));
+        #[cfg(feature = "trace")]
+        log::trace!("glPolygonOffset(factor: {factor:?}, units: {units:?})");
+        unsafe { function(factor, units) };
+        #[cfg(feature = "trace")]
+        crate::trace::check("glPolygonOffset", || unsafe { self.get_error() }.error());
+    }
+
+    /// # Safety
+    /// See the safety note in `with_loader`
+    #[inline]
+    #[cfg_attr(feature = "trace", track_caller)]
+    pub unsafe fn color_mask(
+        &self,
+        red: GLboolean,
+        green: GLboolean,
+        blue: GLboolean,
+        alpha: GLboolean,
+    ) {
+        let function = self
+            .color_mask_ptr
+            .unwrap_or_else(|| not_loaded("glColorMask"));
+        #[cfg(feature = "trace")]
+        log::trace!(
+            "glColorMask(red: {red:?}, green: {green:?}, blue: {blue:?}, alpha: {alpha:?})"
+        );
+        unsafe { function(red, green, blue, alpha) };
+        #[cfg(feature = "trace")]
+        crate::trace::check("glColorMask", || unsafe { self.get_error() }.error());
+    }
+
+    /// The core profile only guarantees a width of `1.0`.
+    ///
+    /// # Safety
+    /// See the safety note in `with_loader`
+    #[inline]
+    #[cfg_attr(feature = "trace", track_caller)]
+    pub unsafe fn line_width(&self, width: GLfloat) {
+        let function = self
+            .line_width_ptr
+            .unwrap_or_else(|| not_loaded("glLineWidth"));
+        #[cfg(feature = "trace")]
+        log::trace!("glLineWidth(width: {width:?})");
+        unsafe { function(width) };
+        #[cfg(feature = "trace")]
+        crate::trace::check("glLineWidth", || unsafe { self.get_error() }.error());
+    }
+
+    /// # Safety
+    /// See the safety note in `with_loader`
+    #[inline]
+    #[cfg_attr(feature = "trace", track_caller)]
+    pub unsafe fn clear(&self, mask: ClearMask) {
+        let function = self.clear_ptr.unwrap_or_else(|| not_loaded("glClear"));
+        #[cfg(feature = "trace")]
+        log::trace!("glClear(mask: {mask:?})");
+        unsafe { function(mask) };
+        #[cfg(feature = "trace")]
+        crate::trace::check("glClear", || unsafe { self.get_error() }.error());
+    }
+
+    /// Sets the clear color
+    ///
+    /// # Safety
+    /// See the safety note in `with_loader`
+    #[inline]
+    #[cfg_attr(feature = "trace", track_caller)]
+    pub unsafe fn clear_color(&self, red: GLfloat, green: GLfloat, blue: GLfloat, alpha: GLfloat) {
+        let function = self
+            .clear_color_ptr
+            .unwrap_or_else(|| not_loaded("glClearColor"));
+        #[cfg(feature = "trace")]
+        log::trace!(
+            "glClearColor(red: {red:?}, green: {green:?}, blue: {blue:?}, alpha: {alpha:?})"
+        );
+        unsafe { function(red, green, blue, alpha) };
+        #[cfg(feature = "trace")]
+        crate::trace::check("glClearColor", || unsafe { self.get_error() }.error());
+    }
+
+    // DRAW
+
+    /// # Safety
+    /// See the safety note in `with_loader`
+    #[inline]
+    #[cfg_attr(feature = "trace", track_caller)]
+    pub unsafe fn draw_arrays(&self, mode: Primitive, first: GLint, count: GLsizei) {
+        let function = self
+            .draw_arrays_ptr
+            .unwrap_or_else(|| not_loaded("glDrawArrays"));
+        #[cfg(feature = "trace")]
+        log::trace!("glDrawArrays(mode: {mode:?}, first: {first:?}, count: {count:?})");
+        unsafe { function(mode, first, count) };
+        #[cfg(feature = "trace")]
+        crate::trace::check("glDrawArrays", || unsafe { self.get_error() }.error());
+    }
+
+    /// `indices` is a byte offset into the bound `ELEMENT_ARRAY_BUFFER`.
+    ///
+    /// # Safety
+    /// See the safety note in `with_loader`
+    #[inline]
+    #[cfg_attr(feature = "trace", track_caller)]
+    pub unsafe fn draw_elements(
+        &self,
+        mode: Primitive,
+        count: GLsizei,
+        kind: IndexKind,
+        indices: *const std::ffi::c_void,
+    ) {
+        let function = self
+            .draw_elements_ptr
+            .unwrap_or_else(|| not_loaded("glDrawElements"));
+        #[cfg(feature = "trace")]
+        log::trace!("glDrawElements(mode: {mode:?}, count: {count:?}, kind: {kind:?}, indices: {indices:?})");
+        unsafe { function(mode, count, kind, indices) };
+        #[cfg(feature = "trace")]
+        crate::trace::check("glDrawElements", || unsafe { self.get_error() }.error());
+    }
+
+    /// Like `draw_elements`, with a hint that all indices lie in `start..=end`.
+    ///
+    /// # Safety
+    /// See the safety note in `with_loader`
+    #[inline]
+    #[cfg_attr(feature = "trace", track_caller)]
+    pub unsafe fn draw_range_elements(
+        &self,
+        mode: Primitive,
+        start: GLuint,
+        end: GLuint,
+        count: GLsizei,
+        kind: IndexKind,
+        indices: *const std::ffi::c_void,
+    ) {
+        let function = self
+            .draw_range_elements_ptr
+            .unwrap_or_else(|| not_loaded("glDrawRangeElements"));
+        #[cfg(feature = "trace")]
+        log::trace!("glDrawRangeElements(mode: {mode:?}, start: {start:?}, end: {end:?}, count: {count:?}, kind: {kind:?}, indices: {indices:?})");
+        unsafe { function(mode, start, end, count, kind, indices) };
+        #[cfg(feature = "trace")]
+        crate::trace::check("glDrawRangeElements", || {
+            unsafe { self.get_error() }.error()
+        });
+    }
+
+    /// # Safety
+    /// See the safety note in `with_loader`
+    #[inline]
+    #[cfg_attr(feature = "trace", track_caller)]
+    pub unsafe fn draw_arrays_instanced(
+        &self,
+        mode: Primitive,
+        first: GLint,
+        count: GLsizei,
+        instancecount: GLsizei,
+    ) {
+        let function = self
+            .draw_arrays_instanced_ptr
+            .unwrap_or_else(|| not_loaded("glDrawArraysInstanced"));
+        #[cfg(feature = "trace")]
+        log::trace!("glDrawArraysInstanced(mode: {mode:?}, first: {first:?}, count: {count:?}, instancecount: {instancecount:?})");
+        unsafe { function(mode, first, count, instancecount) };
+        #[cfg(feature = "trace")]
+        crate::trace::check("glDrawArraysInstanced", || {
+            unsafe { self.get_error() }.error()
+        });
+    }
+
+    /// # Safety
+    /// See the safety note in `with_loader`
+    #[inline]
+    #[cfg_attr(feature = "trace", track_caller)]
+    pub unsafe fn draw_elements_instanced(
+        &self,
+        mode: Primitive,
+        count: GLsizei,
+        kind: IndexKind,
+        indices: *const std::ffi::c_void,
+        instancecount: GLsizei,
+    ) {
+        let function = self
+            .draw_elements_instanced_ptr
+            .unwrap_or_else(|| not_loaded("glDrawElementsInstanced"));
+        #[cfg(feature = "trace")]
+        log::trace!("glDrawElementsInstanced(mode: {mode:?}, count: {count:?}, kind: {kind:?}, indices: {indices:?}, instancecount: {instancecount:?})");
+        unsafe { function(mode, count, kind, indices, instancecount) };
+        #[cfg(feature = "trace")]
+        crate::trace::check("glDrawElementsInstanced", || {
+            unsafe { self.get_error() }.error()
+        });
+    }
+
+    // VERTEX ARRAYS
+
+    /// # Safety
+    /// See the safety note in `with_loader`
+    #[inline]
+    #[cfg_attr(feature = "trace", track_caller)]
+    pub unsafe fn gen_vertex_arrays(&self, n: GLsizei, arrays: *mut VertexArray) {
+        let function = self
+            .gen_vertex_arrays_ptr
+            .unwrap_or_else(|| not_loaded("glGenVertexArrays"));
+        #[cfg(feature = "trace")]
+        log::trace!("glGenVertexArrays(n: {n:?}, arrays: {arrays:?})");
+        unsafe { function(n, arrays) };
+        #[cfg(feature = "trace")]
+        crate::trace::check("glGenVertexArrays", || unsafe { self.get_error() }.error());
+    }
+
+    /// # Safety
+    /// See the safety note in `with_loader`
+    #[inline]
+    #[cfg_attr(feature = "trace", track_caller)]
+    pub unsafe fn bind_vertex_array(&self, array: VertexArray) {
+        let function = self
+            .bind_vertex_array_ptr
+            .unwrap_or_else(|| not_loaded("glBindVertexArray"));
+        #[cfg(feature = "trace")]
+        log::trace!("glBindVertexArray(array: {array:?})");
+        unsafe { function(array) };
+        #[cfg(feature = "trace")]
+        crate::trace::check("glBindVertexArray", || unsafe { self.get_error() }.error());
+    }
+
+    /// # Safety
+    /// See the safety note in `with_loader`
+    #[inline]
+    #[cfg_attr(feature = "trace", track_caller)]
+    pub unsafe fn enable_vertex_attrib_array(&self, index: GLuint) {
+        let function = self
+            .enable_vertex_attrib_array_ptr
+            .unwrap_or_else(|| not_loaded("glEnableVertexAttribArray"));
+        #[cfg(feature = "trace")]
+        log::trace!("glEnableVertexAttribArray(index: {index:?})");
+        unsafe { function(index) };
+        #[cfg(feature = "trace")]
+        crate::trace::check("glEnableVertexAttribArray", || {
+            unsafe { self.get_error() }.error()
+        });
+    }
+
+    /// # Safety
+    /// See the safety note in `with_loader`
+    #[inline]
+    #[cfg_attr(feature = "trace", track_caller)]
+    pub unsafe fn vertex_attrib_pointer(
+        &self,
+        index: GLuint,
+        size: GLint,
+        kind: VertexAttributeKind,
+        normalized: GLboolean,
+        stride: GLsizei,
+        pointer: *const std::ffi::c_void,
+    ) {
+        let function = self
+            .vertex_attrib_pointer_ptr
+            .unwrap_or_else(|| not_loaded("glVertexAttribPointer"));
+        #[cfg(feature = "trace")]
+        log::trace!("glVertexAttribPointer(index: {index:?}, size: {size:?}, kind: {kind:?}, normalized: {normalized:?}, stride: {stride:?}, pointer: {pointer:?})");
+        unsafe { function(index, size, kind, normalized, stride, pointer) };
+        #[cfg(feature = "trace")]
+        crate::trace::check("glVertexAttribPointer", || {
+            unsafe { self.get_error() }.error()
+        });
+    }
+
+    /// Like `vertex_attrib_pointer`, but the shader reads the integers without converting them to floats.
+    ///
+    /// # Safety
+    /// See the safety note in `with_loader`
+    #[inline]
+    #[cfg_attr(feature = "trace", track_caller)]
+    pub unsafe fn vertex_attrib_i_pointer(
+        &self,
+        index: GLuint,
+        size: GLint,
+        kind: VertexAttributeKind,
+        stride: GLsizei,
+        pointer: *const std::ffi::c_void,
+    ) {
+        let function = self
+            .vertex_attrib_i_pointer_ptr
+            .unwrap_or_else(|| not_loaded("glVertexAttribIPointer"));
+        #[cfg(feature = "trace")]
+        log::trace!("glVertexAttribIPointer(index: {index:?}, size: {size:?}, kind: {kind:?}, stride: {stride:?}, pointer: {pointer:?})");
+        unsafe { function(index, size, kind, stride, pointer) };
+        #[cfg(feature = "trace")]
+        crate::trace::check("glVertexAttribIPointer", || {
+            unsafe { self.get_error() }.error()
+        });
+    }
+
+    /// Advances attribute `index` once per `divisor` instances instead of once per vertex, `0` restores the latter.
+    ///
+    /// # Safety
+    /// See the safety note in `with_loader`
+    #[inline]
+    #[cfg_attr(feature = "trace", track_caller)]
+    pub unsafe fn vertex_attrib_divisor(&self, index: GLuint, divisor: GLuint) {
+        let function = self
+            .vertex_attrib_divisor_ptr
+            .unwrap_or_else(|| not_loaded("glVertexAttribDivisor"));
+        #[cfg(feature = "trace")]
+        log::trace!("glVertexAttribDivisor(index: {index:?}, divisor: {divisor:?})");
+        unsafe { function(index, divisor) };
+        #[cfg(feature = "trace")]
+        crate::trace::check("glVertexAttribDivisor", || {
+            unsafe { self.get_error() }.error()
+        });
+    }
+
+    /// # Safety
+    /// See the safety note in `with_loader`
+    #[inline]
+    #[cfg_attr(feature = "trace", track_caller)]
+    pub unsafe fn delete_vertex_arrays(&self, n: GLsizei, arrays: *const VertexArray) {
+        let function = self
+            .delete_vertex_arrays_ptr
+            .unwrap_or_else(|| not_loaded("glDeleteVertexArrays"));
+        #[cfg(feature = "trace")]
+        log::trace!("glDeleteVertexArrays(n: {n:?}, arrays: {arrays:?})");
+        unsafe { function(n, arrays) };
+        #[cfg(feature = "trace")]
+        crate::trace::check("glDeleteVertexArrays", || {
+            unsafe { self.get_error() }.error()
+        });
+    }
+
+    // BUFFERS
+
+    /// # Safety
+    /// See the safety note in `with_loader`
+    #[inline]
+    #[cfg_attr(feature = "trace", track_caller)]
+    pub unsafe fn gen_buffers(&self, n: GLsizei, buffers: *mut Buffer) {
+        let function = self
+            .gen_buffers_ptr
+            .unwrap_or_else(|| not_loaded("glGenBuffers"));
+        #[cfg(feature = "trace")]
+        log::trace!("glGenBuffers(n: {n:?}, buffers: {buffers:?})");
+        unsafe { function(n, buffers) };
+        #[cfg(feature = "trace")]
+        crate::trace::check("glGenBuffers", || unsafe { self.get_error() }.error());
+    }
+
+    /// # Safety
+    /// See the safety note in `with_loader`
+    #[inline]
+    #[cfg_attr(feature = "trace", track_caller)]
+    pub unsafe fn bind_buffer(&self, target: BufferTarget, buffer: Buffer) {
+        let function = self
+            .bind_buffer_ptr
+            .unwrap_or_else(|| not_loaded("glBindBuffer"));
+        #[cfg(feature = "trace")]
+        log::trace!("glBindBuffer(target: {target:?}, buffer: {buffer:?})");
+        unsafe { function(target, buffer) };
+        #[cfg(feature = "trace")]
+        crate::trace::check("glBindBuffer", || unsafe { self.get_error() }.error());
+    }
+
+    /// Binds `buffer` to binding point `index` of an indexed target, e.g. `SHADER_STORAGE_BUFFER`.
+    ///
+    /// # Safety
+    /// See the safety note in `with_loader`
+    #[inline]
+    #[cfg_attr(feature = "trace", track_caller)]
+    pub unsafe fn bind_buffer_base(&self, target: BufferTarget, index: GLuint, buffer: Buffer) {
+        let function = self
+            .bind_buffer_base_ptr
+            .unwrap_or_else(|| not_loaded("glBindBufferBase"));
+        #[cfg(feature = "trace")]
+        log::trace!("glBindBufferBase(target: {target:?}, index: {index:?}, buffer: {buffer:?})");
+        unsafe { function(target, index, buffer) };
+        #[cfg(feature = "trace")]
+        crate::trace::check("glBindBufferBase", || unsafe { self.get_error() }.error());
+    }
+
+    /// Like `bind_buffer_base`, but only binds `size` bytes starting at `offset`.
+    ///
+    /// # Safety
+    /// See the safety note in `with_loader`
+    #[inline]
+    #[cfg_attr(feature = "trace", track_caller)]
+    pub unsafe fn bind_buffer_range(
+        &self,
+        target: BufferTarget,
+        index: GLuint,
+        buffer: Buffer,
+        offset: GLintptr,
+        size: GLsizeiptr,
+    ) {
+        let function = self
+            .bind_buffer_range_ptr
+            .unwrap_or_else(|| not_loaded("glBindBufferRange"));
+        #[cfg(feature = "trace")]
+        log::trace!("glBindBufferRange(target: {target:?}, index: {index:?}, buffer: {buffer:?}, offset: {offset:?}, size: {size:?})");
+        unsafe { function(target, index, buffer, offset, size) };
+        #[cfg(feature = "trace")]
+        crate::trace::check("glBindBufferRange", || unsafe { self.get_error() }.error());
+    }
+
+    /// # Safety
+    /// See the safety note in `with_loader`
+    #[inline]
+    #[cfg_attr(feature = "trace", track_caller)]
+    pub unsafe fn buffer_data(
+        &self,
+        target: BufferTarget,
+        size: GLsizeiptr,
+        data: *const std::ffi::c_void,
+        usage: BufferUsage,
+    ) {
+        let function = self
+            .buffer_data_ptr
+            .unwrap_or_else(|| not_loaded("glBufferData"));
+        #[cfg(feature = "trace")]
+        log::trace!(
+            "glBufferData(target: {target:?}, size: {size:?}, data: {data:?}, usage: {usage:?})"
+        );
+        unsafe { function(target, size, data, usage) };
+        #[cfg(feature = "trace")]
+        crate::trace::check("glBufferData", || unsafe { self.get_error() }.error());
+    }
+
+    /// Overwrites `size` bytes starting at `offset` without reallocating the storage.
+    ///
+    /// # Safety
+    /// See the safety note in `with_loader`
+    #[inline]
+    #[cfg_attr(feature = "trace", track_caller)]
+    pub unsafe fn buffer_sub_data(
+        &self,
+        target: BufferTarget,
+        offset: GLintptr,
+        size: GLsizeiptr,
+        data: *const std::ffi::c_void,
+    ) {
+        let function = self
+            .buffer_sub_data_ptr
+            .unwrap_or_else(|| not_loaded("glBufferSubData"));
+        #[cfg(feature = "trace")]
+        log::trace!("glBufferSubData(target: {target:?}, offset: {offset:?}, size: {size:?}, data: {data:?})");
+        unsafe { function(target, offset, size, data) };
+        #[cfg(feature = "trace")]
+        crate::trace::check("glBufferSubData", || unsafe { self.get_error() }.error());
+    }
+
+    /// Copies `size` bytes between the buffers bound to `read_target` and `write_target`.
+    /// `COPY_READ_BUFFER` and `COPY_WRITE_BUFFER` exist to bind them without disturbing other targets.
+    ///
+    /// # Safety
+    /// See the safety note in `with_loader`
+    #[inline]
+    #[cfg_attr(feature = "trace", track_caller)]
+    pub unsafe fn copy_buffer_sub_data(
+        &self,
+        read_target: BufferTarget,
+        write_target: BufferTarget,
+        read_offset: GLintptr,
+        write_offset: GLintptr,
+        size: GLsizeiptr,
+    ) {
+        let function = self
+            .copy_buffer_sub_data_ptr
+            .unwrap_or_else(|| not_loaded("glCopyBufferSubData"));
+        #[cfg(feature = "trace")]
+        log::trace!("glCopyBufferSubData(read_target: {read_target:?}, write_target: {write_target:?}, read_offset: {read_offset:?}, write_offset: {write_offset:?}, size: {size:?})");
+        unsafe { function(read_target, write_target, read_offset, write_offset, size) };
+        #[cfg(feature = "trace")]
+        crate::trace::check("glCopyBufferSubData", || {
+            unsafe { self.get_error() }.error()
+        });
+    }
+
+    /// Maps `length` bytes starting at `offset` into client memory, null on error.
+    /// The pointer is valid until `unmap_buffer`, a buffer can only be mapped once at a time.
+    ///
+    /// # Safety
+    /// See the safety note in `with_loader`
+    #[inline]
+    #[cfg_attr(feature = "trace", track_caller)]
+    #[must_use]
+    pub unsafe fn map_buffer_range(
+        &self,
+        target: BufferTarget,
+        offset: GLintptr,
+        length: GLsizeiptr,
+        access: MapAccess,
+    ) -> *mut std::ffi::c_void {
+        let function = self
+            .map_buffer_range_ptr
+            .unwrap_or_else(|| not_loaded("glMapBufferRange"));
+        #[cfg(feature = "trace")]
+        log::trace!("glMapBufferRange(target: {target:?}, offset: {offset:?}, length: {length:?}, access: {access:?})");
+        let result = unsafe { function(target, offset, length, access) };
+        #[cfg(feature = "trace")]
+        crate::trace::check("glMapBufferRange", || unsafe { self.get_error() }.error());
+        result
+    }
+
+    /// Makes writes to a range of a mapping with `FLUSH_EXPLICIT` visible to GL.
+    /// `offset` is relative to the start of the mapping.
+    ///
+    /// # Safety
+    /// See the safety note in `with_loader`
+    #[inline]
+    #[cfg_attr(feature = "trace", track_caller)]
+    pub unsafe fn flush_mapped_buffer_range(
+        &self,
+        target: BufferTarget,
+        offset: GLintptr,
+        length: GLsizeiptr,
+    ) {
+        let function = self
+            .flush_mapped_buffer_range_ptr
+            .unwrap_or_else(|| not_loaded("glFlushMappedBufferRange"));
+        #[cfg(feature = "trace")]
+        log::trace!(
+            "glFlushMappedBufferRange(target: {target:?}, offset: {offset:?}, length: {length:?})"
+        );
+        unsafe { function(target, offset, length) };
+        #[cfg(feature = "trace")]
+        crate::trace::check("glFlushMappedBufferRange", || {
+            unsafe { self.get_error() }.error()
+        });
+    }
+
+    /// Ends the mapping of the buffer bound to `target`.
+    /// Returns `FALSE` if the contents got corrupted while mapped and must be uploaded again.
+    ///
+    /// # Safety
+    /// See the safety note in `with_loader`
+    #[inline]
+    #[cfg_attr(feature = "trace", track_caller)]
+    #[must_use]
+    pub unsafe fn unmap_buffer(&self, target: BufferTarget) -> GLboolean {
+        let function = self
+            .unmap_buffer_ptr
+            .unwrap_or_else(|| not_loaded("glUnmapBuffer"));
+        #[cfg(feature = "trace")]
+        log::trace!("glUnmapBuffer(target: {target:?})");
+        let result = unsafe { function(target) };
+        #[cfg(feature = "trace")]
+        crate::trace::check("glUnmapBuffer", || unsafe { self.get_error() }.error());
+        result
+    }
+
+    /// # Safety
+    /// See the safety note in `with_loader`
+    #[inline]
+    #[cfg_attr(feature = "trace", track_caller)]
+    pub unsafe fn delete_buffers(&self, n: GLsizei, buffers: *const Buffer) {
+        let function = self
+            .delete_buffers_ptr
+            .unwrap_or_else(|| not_loaded("glDeleteBuffers"));
+        #[cfg(feature = "trace")]
+        log::trace!("glDeleteBuffers(n: {n:?}, buffers: {buffers:?})");
+        unsafe { function(n, buffers) };
+        #[cfg(feature = "trace")]
+        crate::trace::check("glDeleteBuffers", || unsafe { self.get_error() }.error());
+    }
+
+    // SHADERS
+
+    /// # Safety
+    /// See the safety note in `with_loader`
+    #[inline]
+    #[cfg_attr(feature = "trace", track_caller)]
+    #[must_use]
+    pub unsafe fn create_shader(&self, kind: ShaderKind) -> Shader {
+        let function = self
+            .create_shader_ptr
+            .unwrap_or_else(|| not_loaded("glCreateShader"));
+        #[cfg(feature = "trace")]
+        log::trace!("glCreateShader(kind: {kind:?})");
+        let result = unsafe { function(kind) };
+        #[cfg(feature = "trace")]
+        crate::trace::check("glCreateShader", || unsafe { self.get_error() }.error());
+        result
+    }
+
+    /// # Safety
+    /// See the safety note in `with_loader`
+    #[inline]
+    #[cfg_attr(feature = "trace", track_caller)]
+    pub unsafe fn shader_source(
+        &self,
+        shader: Shader,
+        count: GLsizei,
+        string: *const *const GLchar,
+        length: *const GLint,
+    ) {
+        let function = self
+            .shader_source_ptr
+            .unwrap_or_else(|| not_loaded("glShaderSource"));
+        #[cfg(feature = "trace")]
+        log::trace!("glShaderSource(shader: {shader:?}, count: {count:?}, string: {string:?}, length: {length:?})");
+        unsafe { function(shader, count, string, length) };
+        #[cfg(feature = "trace")]
+        crate::trace::check("glShaderSource", || unsafe { self.get_error() }.error());
+    }
+
+    /// # Safety
+    /// See the safety note in `with_loader`
+    #[inline]
+    #[cfg_attr(feature = "trace", track_caller)]
+    pub unsafe fn compile_shader(&self, shader: Shader) {
+        let function = self
+            .compile_shader_ptr
+            .unwrap_or_else(|| not_loaded("glCompileShader"));
+        #[cfg(feature = "trace")]
+        log::trace!("glCompileShader(shader: {shader:?})");
+        unsafe { function(shader) };
+        #[cfg(feature = "trace")]
+        crate::trace::check("glCompileShader", || unsafe { self.get_error() }.error());
+    }
+
+    /// # Safety
+    /// See the safety note in `with_loader`
+    #[inline]
+    #[cfg_attr(feature = "trace", track_caller)]
+    pub unsafe fn delete_shader(&self, shader: Shader) {
+        let function = self
+            .delete_shader_ptr
+            .unwrap_or_else(|| not_loaded("glDeleteShader"));
+        #[cfg(feature = "trace")]
+        log::trace!("glDeleteShader(shader: {shader:?})");
+        unsafe { function(shader) };
+        #[cfg(feature = "trace")]
+        crate::trace::check("glDeleteShader", || unsafe { self.get_error() }.error());
+    }
+
+    /// # Safety
+    /// See the safety note in `with_loader`
+    #[inline]
+    #[cfg_attr(feature = "trace", track_caller)]
+    pub unsafe fn get_shaderiv(&self, shader: Shader, pname: ShaderParameter, params: *mut GLint) {
+        let function = self
+            .get_shaderiv_ptr
+            .unwrap_or_else(|| not_loaded("glGetShaderiv"));
+        #[cfg(feature = "trace")]
+        log::trace!("glGetShaderiv(shader: {shader:?}, pname: {pname:?}, params: {params:?})");
+        unsafe { function(shader, pname, params) };
+        #[cfg(feature = "trace")]
+        crate::trace::check("glGetShaderiv", || unsafe { self.get_error() }.error());
+    }
+
+    /// Prefer `check_compile_status`, which reads and parses the log.
+    ///
+    /// # Safety
+    /// See the safety note in `with_loader`
+    #[inline]
+    #[cfg_attr(feature = "trace", track_caller)]
+    pub unsafe fn get_shader_info_log(
+        &self,
+        shader: Shader,
+        buf_size: GLsizei,
+        length: *mut GLsizei,
+        info_log: *mut GLchar,
+    ) {
+        let function = self
+            .get_shader_info_log_ptr
+            .unwrap_or_else(|| not_loaded("glGetShaderInfoLog"));
+        #[cfg(feature = "trace")]
+        log::trace!("glGetShaderInfoLog(shader: {shader:?}, buf_size: {buf_size:?}, length: {length:?}, info_log: {info_log:?})");
+        unsafe { function(shader, buf_size, length, info_log) };
+        #[cfg(feature = "trace")]
+        crate::trace::check("glGetShaderInfoLog", || unsafe { self.get_error() }.error());
+    }
+
+    // PROGRAM
+
+    /// # Safety
+    /// See the safety note in `with_loader`
+    #[inline]
+    #[cfg_attr(feature = "trace", track_caller)]
+    #[must_use]
+    pub unsafe fn create_program(&self) -> Program {
+        let function = self
+            .create_program_ptr
+            .unwrap_or_else(|| not_loaded("glCreateProgram"));
+        #[cfg(feature = "trace")]
+        log::trace!("glCreateProgram()");
+        let result = unsafe { function() };
+        #[cfg(feature = "trace")]
+        crate::trace::check("glCreateProgram", || unsafe { self.get_error() }.error());
+        result
+    }
+
+    /// # Safety
+    /// See the safety note in `with_loader`
+    #[inline]
+    #[cfg_attr(feature = "trace", track_caller)]
+    pub unsafe fn attach_shader(&self, program: Program, shader: Shader) {
+        let function = self
+            .attach_shader_ptr
+            .unwrap_or_else(|| not_loaded("glAttachShader"));
+        #[cfg(feature = "trace")]
+        log::trace!("glAttachShader(program: {program:?}, shader: {shader:?})");
+        unsafe { function(program, shader) };
+        #[cfg(feature = "trace")]
+        crate::trace::check("glAttachShader", || unsafe { self.get_error() }.error());
+    }
+
+    /// # Safety
+    /// See the safety note in `with_loader`
+    #[inline]
+    #[cfg_attr(feature = "trace", track_caller)]
+    pub unsafe fn link_program(&self, program: Program) {
+        let function = self
+            .link_program_ptr
+            .unwrap_or_else(|| not_loaded("glLinkProgram"));
+        #[cfg(feature = "trace")]
+        log::trace!("glLinkProgram(program: {program:?})");
+        unsafe { function(program) };
+        #[cfg(feature = "trace")]
+        crate::trace::check("glLinkProgram", || unsafe { self.get_error() }.error());
+    }
+
+    /// # Safety
+    /// See the safety note in `with_loader`
+    #[inline]
+    #[cfg_attr(feature = "trace", track_caller)]
+    pub unsafe fn detach_shader(&self, program: Program, shader: Shader) {
+        let function = self
+            .detach_shader_ptr
+            .unwrap_or_else(|| not_loaded("glDetachShader"));
+        #[cfg(feature = "trace")]
+        log::trace!("glDetachShader(program: {program:?}, shader: {shader:?})");
+        unsafe { function(program, shader) };
+        #[cfg(feature = "trace")]
+        crate::trace::check("glDetachShader", || unsafe { self.get_error() }.error());
+    }
+
+    /// # Safety
+    /// See the safety note in `with_loader`
+    #[inline]
+    #[cfg_attr(feature = "trace", track_caller)]
+    pub unsafe fn get_programiv(
+        &self,
+        program: Program,
+        pname: ProgramParameter,
+        params: *mut GLint,
+    ) {
+        let function = self
+            .get_programiv_ptr
+            .unwrap_or_else(|| not_loaded("glGetProgramiv"));
+        #[cfg(feature = "trace")]
+        log::trace!("glGetProgramiv(program: {program:?}, pname: {pname:?}, params: {params:?})");
+        unsafe { function(program, pname, params) };
+        #[cfg(feature = "trace")]
+        crate::trace::check("glGetProgramiv", || unsafe { self.get_error() }.error());
+    }
+
+    /// Prefer `check_link_status`, which reads and parses the log.
+    ///
+    /// # Safety
+    /// See the safety note in `with_loader`
+    #[inline]
+    #[cfg_attr(feature = "trace", track_caller)]
+    pub unsafe fn get_program_info_log(
+        &self,
+        program: Program,
+        buf_size: GLsizei,
+        length: *mut GLsizei,
+        info_log: *mut GLchar,
+    ) {
+        let function = self
+            .get_program_info_log_ptr
+            .unwrap_or_else(|| not_loaded("glGetProgramInfoLog"));
+        #[cfg(feature = "trace")]
+        log::trace!("glGetProgramInfoLog(program: {program:?}, buf_size: {buf_size:?}, length: {length:?}, info_log: {info_log:?})");
+        unsafe { function(program, buf_size, length, info_log) };
+        #[cfg(feature = "trace")]
+        crate::trace::check("glGetProgramInfoLog", || {
+            unsafe { self.get_error() }.error()
+        });
+    }
+
+    /// # Safety
+    /// See the safety note in `with_loader`
+    #[inline]
+    #[cfg_attr(feature = "trace", track_caller)]
+    pub unsafe fn use_program(&self, program: Program) {
+        let function = self
+            .use_program_ptr
+            .unwrap_or_else(|| not_loaded("glUseProgram"));
+        #[cfg(feature = "trace")]
+        log::trace!("glUseProgram(program: {program:?})");
+        unsafe { function(program) };
+        #[cfg(feature = "trace")]
+        crate::trace::check("glUseProgram", || unsafe { self.get_error() }.error());
+    }
+
+    /// # Safety
+    /// See the safety note in `with_loader`
+    #[inline]
+    #[cfg_attr(feature = "trace", track_caller)]
+    pub unsafe fn delete_program(&self, program: Program) {
+        let function = self
+            .delete_program_ptr
+            .unwrap_or_else(|| not_loaded("glDeleteProgram"));
+        #[cfg(feature = "trace")]
+        log::trace!("glDeleteProgram(program: {program:?})");
+        unsafe { function(program) };
+        #[cfg(feature = "trace")]
+        crate::trace::check("glDeleteProgram", || unsafe { self.get_error() }.error());
+    }
+
+    // TEXTURES
+
+    /// # Safety
+    /// See the safety note in `with_loader`
+    #[inline]
+    #[cfg_attr(feature = "trace", track_caller)]
+    pub unsafe fn gen_textures(&self, n: GLsizei, textures: *mut Texture) {
+        let function = self
+            .gen_textures_ptr
+            .unwrap_or_else(|| not_loaded("glGenTextures"));
+        #[cfg(feature = "trace")]
+        log::trace!("glGenTextures(n: {n:?}, textures: {textures:?})");
+        unsafe { function(n, textures) };
+        #[cfg(feature = "trace")]
+        crate::trace::check("glGenTextures", || unsafe { self.get_error() }.error());
+    }
+
+    /// # Safety
+    /// See the safety note in `with_loader`
+    #[inline]
+    #[cfg_attr(feature = "trace", track_caller)]
+    pub unsafe fn bind_texture(&self, target: TextureTarget, texture: Texture) {
+        let function = self
+            .bind_texture_ptr
+            .unwrap_or_else(|| not_loaded("glBindTexture"));
+        #[cfg(feature = "trace")]
+        log::trace!("glBindTexture(target: {target:?}, texture: {texture:?})");
+        unsafe { function(target, texture) };
+        #[cfg(feature = "trace")]
+        crate::trace::check("glBindTexture", || unsafe { self.get_error() }.error());
+    }
+
+    /// Selects the texture unit `bind_texture` binds to, see `TextureUnit::nth`.
+    ///
+    /// # Safety
+    /// See the safety note in `with_loader`
+    #[inline]
+    #[cfg_attr(feature = "trace", track_caller)]
+    pub unsafe fn active_texture(&self, texture: TextureUnit) {
+        let function = self
+            .active_texture_ptr
+            .unwrap_or_else(|| not_loaded("glActiveTexture"));
+        #[cfg(feature = "trace")]
+        log::trace!("glActiveTexture(texture: {texture:?})");
+        unsafe { function(texture) };
+        #[cfg(feature = "trace")]
+        crate::trace::check("glActiveTexture", || unsafe { self.get_error() }.error());
+    }
+
+    /// # Safety
+    /// See the safety note in `with_loader`
+    #[inline]
+    #[cfg_attr(feature = "trace", track_caller)]
+    pub unsafe fn tex_image_2d(
+        &self,
+        target: TextureTarget,
+        level: GLint,
+        internalformat: InternalFormat,
+        width: GLsizei,
+        height: GLsizei,
+        border: GLint,
+        format: PixelFormat,
+        kind: PixelType,
+        pixels: *const std::ffi::c_void,
+    ) {
+        let function = self
+            .tex_image_2d_ptr
+            .unwrap_or_else(|| not_loaded("glTexImage2D"));
+        #[cfg(feature = "trace")]
+        log::trace!("glTexImage2D(target: {target:?}, level: {level:?}, internalformat: {internalformat:?}, width: {width:?}, height: {height:?}, border: {border:?}, format: {format:?}, kind: {kind:?}, pixels: {pixels:?})");
+        unsafe {
+            function(
+                target,
+                level,
+                internalformat,
+                width,
+                height,
+                border,
+                format,
+                kind,
+                pixels,
+            )
+        };
+        #[cfg(feature = "trace")]
+        crate::trace::check("glTexImage2D", || unsafe { self.get_error() }.error());
+    }
+
+    /// # Safety
+    /// See the safety note in `with_loader`
+    #[inline]
+    #[cfg_attr(feature = "trace", track_caller)]
+    pub unsafe fn tex_image_3d(
+        &self,
+        target: TextureTarget,
+        level: GLint,
+        internalformat: InternalFormat,
+        width: GLsizei,
+        height: GLsizei,
+        depth: GLsizei,
+        border: GLint,
+        format: PixelFormat,
+        kind: PixelType,
+        pixels: *const std::ffi::c_void,
+    ) {
+        let function = self
+            .tex_image_3d_ptr
+            .unwrap_or_else(|| not_loaded("glTexImage3D"));
+        #[cfg(feature = "trace")]
+        log::trace!("glTexImage3D(target: {target:?}, level: {level:?}, internalformat: {internalformat:?}, width: {width:?}, height: {height:?}, depth: {depth:?}, border: {border:?}, format: {format:?}, kind: {kind:?}, pixels: {pixels:?})");
+        unsafe {
+            function(
+                target,
+                level,
+                internalformat,
+                width,
+                height,
+                depth,
+                border,
+                format,
+                kind,
+                pixels,
+            )
+        };
+        #[cfg(feature = "trace")]
+        crate::trace::check("glTexImage3D", || unsafe { self.get_error() }.error());
+    }
+
+    /// Allocates immutable storage for all `levels`, only sized `InternalFormat`s are allowed.
+    ///
+    /// # Safety
+    /// See the safety note in `with_loader`
+    #[inline]
+    #[cfg_attr(feature = "trace", track_caller)]
+    pub unsafe fn tex_storage_2d(
+        &self,
+        target: TextureTarget,
+        levels: GLsizei,
+        internalformat: InternalFormat,
+        width: GLsizei,
+        height: GLsizei,
+    ) {
+        let function = self
+            .tex_storage_2d_ptr
+            .unwrap_or_else(|| not_loaded("glTexStorage2D"));
+        #[cfg(feature = "trace")]
+        log::trace!("glTexStorage2D(target: {target:?}, levels: {levels:?}, internalformat: {internalformat:?}, width: {width:?}, height: {height:?})");
+        unsafe { function(target, levels, internalformat, width, height) };
+        #[cfg(feature = "trace")]
+        crate::trace::check("glTexStorage2D", || unsafe { self.get_error() }.error());
+    }
+
+    /// Allocates immutable storage for all `levels`, only sized `InternalFormat`s are allowed.
+    ///
+    /// # Safety
+    /// See the safety note in `with_loader`
+    #[inline]
+    #[cfg_attr(feature = "trace", track_caller)]
+    pub unsafe fn tex_storage_3d(
+        &self,
+        target: TextureTarget,
+        levels: GLsizei,
+        internalformat: InternalFormat,
+        width: GLsizei,
+        height: GLsizei,
+        depth: GLsizei,
+    ) {
+        let function = self
+            .tex_storage_3d_ptr
+            .unwrap_or_else(|| not_loaded("glTexStorage3D"));
+        #[cfg(feature = "trace")]
+        log::trace!("glTexStorage3D(target: {target:?}, levels: {levels:?}, internalformat: {internalformat:?}, width: {width:?}, height: {height:?}, depth: {depth:?})");
+        unsafe { function(target, levels, internalformat, width, height, depth) };
+        #[cfg(feature = "trace")]
+        crate::trace::check("glTexStorage3D", || unsafe { self.get_error() }.error());
+    }
+
+    /// # Safety
+    /// See the safety note in `with_loader`
+    #[inline]
+    #[cfg_attr(feature = "trace", track_caller)]
+    pub unsafe fn tex_sub_image_2d(
+        &self,
+        target: TextureTarget,
+        level: GLint,
+        xoffset: GLint,
+        yoffset: GLint,
+        width: GLsizei,
+        height: GLsizei,
+        format: PixelFormat,
+        kind: PixelType,
+        pixels: *const std::ffi::c_void,
+    ) {
+        let function = self
+            .tex_sub_image_2d_ptr
+            .unwrap_or_else(|| not_loaded("glTexSubImage2D"));
+        #[cfg(feature = "trace")]
+        log::trace!("glTexSubImage2D(target: {target:?}, level: {level:?}, xoffset: {xoffset:?}, yoffset: {yoffset:?}, width: {width:?}, height: {height:?}, format: {format:?}, kind: {kind:?}, pixels: {pixels:?})");
+        unsafe {
+            function(
+                target, level, xoffset, yoffset, width, height, format, kind, pixels,
+            )
+        };
+        #[cfg(feature = "trace")]
+        crate::trace::check("glTexSubImage2D", || unsafe { self.get_error() }.error());
+    }
+
+    /// # Safety
+    /// See the safety note in `with_loader`
+    #[inline]
+    #[cfg_attr(feature = "trace", track_caller)]
+    pub unsafe fn tex_sub_image_3d(
+        &self,
+        target: TextureTarget,
+        level: GLint,
+        xoffset: GLint,
+        yoffset: GLint,
+        zoffset: GLint,
+        width: GLsizei,
+        height: GLsizei,
+        depth: GLsizei,
+        format: PixelFormat,
+        kind: PixelType,
+        pixels: *const std::ffi::c_void,
+    ) {
+        let function = self
+            .tex_sub_image_3d_ptr
+            .unwrap_or_else(|| not_loaded("glTexSubImage3D"));
+        #[cfg(feature = "trace")]
+        log::trace!("glTexSubImage3D(target: {target:?}, level: {level:?}, xoffset: {xoffset:?}, yoffset: {yoffset:?}, zoffset: {zoffset:?}, width: {width:?}, height: {height:?}, depth: {depth:?}, format: {format:?}, kind: {kind:?}, pixels: {pixels:?})");
+        unsafe {
+            function(
+                target, level, xoffset, yoffset, zoffset, width, height, depth, format, kind,
+                pixels,
+            )
+        };
+        #[cfg(feature = "trace")]
+        crate::trace::check("glTexSubImage3D", || unsafe { self.get_error() }.error());
+    }
+
+    /// Sets a sampling parameter, `TextureFilter` and `TextureWrap` convert into `GLint`.
+    ///
+    /// # Safety
+    /// See the safety note in `with_loader`
+    #[inline]
+    #[cfg_attr(feature = "trace", track_caller)]
+    pub unsafe fn tex_parameteri(
+        &self,
+        target: TextureTarget,
+        pname: TextureParameter,
+        param: GLint,
+    ) {
+        let function = self
+            .tex_parameteri_ptr
+            .unwrap_or_else(|| not_loaded("glTexParameteri"));
+        #[cfg(feature = "trace")]
+        log::trace!("glTexParameteri(target: {target:?}, pname: {pname:?}, param: {param:?})");
+        unsafe { function(target, pname, param) };
+        #[cfg(feature = "trace")]
+        crate::trace::check("glTexParameteri", || unsafe { self.get_error() }.error());
+    }
+
+    /// # Safety
+    /// See the safety note in `with_loader`
+    #[inline]
+    #[cfg_attr(feature = "trace", track_caller)]
+    pub unsafe fn tex_parameterf(
+        &self,
+        target: TextureTarget,
+        pname: TextureParameter,
+        param: GLfloat,
+    ) {
+        let function = self
+            .tex_parameterf_ptr
+            .unwrap_or_else(|| not_loaded("glTexParameterf"));
+        #[cfg(feature = "trace")]
+        log::trace!("glTexParameterf(target: {target:?}, pname: {pname:?}, param: {param:?})");
+        unsafe { function(target, pname, param) };
+        #[cfg(feature = "trace")]
+        crate::trace::check("glTexParameterf", || unsafe { self.get_error() }.error());
+    }
+
+    /// # Safety
+    /// See the safety note in `with_loader`
+    #[inline]
+    #[cfg_attr(feature = "trace", track_caller)]
+    pub unsafe fn generate_mipmap(&self, target: TextureTarget) {
+        let function = self
+            .generate_mipmap_ptr
+            .unwrap_or_else(|| not_loaded("glGenerateMipmap"));
+        #[cfg(feature = "trace")]
+        log::trace!("glGenerateMipmap(target: {target:?})");
+        unsafe { function(target) };
+        #[cfg(feature = "trace")]
+        crate::trace::check("glGenerateMipmap", || unsafe { self.get_error() }.error());
+    }
+
+    /// # Safety
+    /// See the safety note in `with_loader`
+    #[inline]
+    #[cfg_attr(feature = "trace", track_caller)]
+    pub unsafe fn delete_textures(&self, n: GLsizei, textures: *const Texture) {
+        let function = self
+            .delete_textures_ptr
+            .unwrap_or_else(|| not_loaded("glDeleteTextures"));
+        #[cfg(feature = "trace")]
+        log::trace!("glDeleteTextures(n: {n:?}, textures: {textures:?})");
+        unsafe { function(n, textures) };
+        #[cfg(feature = "trace")]
+        crate::trace::check("glDeleteTextures", || unsafe { self.get_error() }.error());
+    }
+
+    // FRAMEBUFFERS
+
+    /// # Safety
+    /// See the safety note in `with_loader`
+    #[inline]
+    #[cfg_attr(feature = "trace", track_caller)]
+    pub unsafe fn gen_framebuffers(&self, n: GLsizei, framebuffers: *mut Framebuffer) {
+        let function = self
+            .gen_framebuffers_ptr
+            .unwrap_or_else(|| not_loaded("glGenFramebuffers"));
+        #[cfg(feature = "trace")]
+        log::trace!("glGenFramebuffers(n: {n:?}, framebuffers: {framebuffers:?})");
+        unsafe { function(n, framebuffers) };
+        #[cfg(feature = "trace")]
+        crate::trace::check("glGenFramebuffers", || unsafe { self.get_error() }.error());
+    }
+
+    /// # Safety
+    /// See the safety note in `with_loader`
+    #[inline]
+    #[cfg_attr(feature = "trace", track_caller)]
+    pub unsafe fn bind_framebuffer(&self, target: FramebufferTarget, framebuffer: Framebuffer) {
+        let function = self
+            .bind_framebuffer_ptr
+            .unwrap_or_else(|| not_loaded("glBindFramebuffer"));
+        #[cfg(feature = "trace")]
+        log::trace!("glBindFramebuffer(target: {target:?}, framebuffer: {framebuffer:?})");
+        unsafe { function(target, framebuffer) };
+        #[cfg(feature = "trace")]
+        crate::trace::check("glBindFramebuffer", || unsafe { self.get_error() }.error());
+    }
+
+    /// # Safety
+    /// See the safety note in `with_loader`
+    #[inline]
+    #[cfg_attr(feature = "trace", track_caller)]
+    pub unsafe fn framebuffer_texture_2d(
+        &self,
+        target: FramebufferTarget,
+        attachment: Attachment,
+        textarget: TextureTarget,
+        texture: Texture,
+        level: GLint,
+    ) {
+        let function = self
+            .framebuffer_texture_2d_ptr
+            .unwrap_or_else(|| not_loaded("glFramebufferTexture2D"));
+        #[cfg(feature = "trace")]
+        log::trace!("glFramebufferTexture2D(target: {target:?}, attachment: {attachment:?}, textarget: {textarget:?}, texture: {texture:?}, level: {level:?})");
+        unsafe { function(target, attachment, textarget, texture, level) };
+        #[cfg(feature = "trace")]
+        crate::trace::check("glFramebufferTexture2D", || {
+            unsafe { self.get_error() }.error()
+        });
+    }
+
+    /// # Safety
+    /// See the safety note in `with_loader`
+    #[inline]
+    #[cfg_attr(feature = "trace", track_caller)]
+    pub unsafe fn framebuffer_renderbuffer(
+        &self,
+        target: FramebufferTarget,
+        attachment: Attachment,
+        renderbuffertarget: RenderbufferTarget,
+        renderbuffer: Renderbuffer,
+    ) {
+        let function = self
+            .framebuffer_renderbuffer_ptr
+            .unwrap_or_else(|| not_loaded("glFramebufferRenderbuffer"));
+        #[cfg(feature = "trace")]
+        log::trace!("glFramebufferRenderbuffer(target: {target:?}, attachment: {attachment:?}, renderbuffertarget: {renderbuffertarget:?}, renderbuffer: {renderbuffer:?})");
+        unsafe { function(target, attachment, renderbuffertarget, renderbuffer) };
+        #[cfg(feature = "trace")]
+        crate::trace::check("glFramebufferRenderbuffer", || {
+            unsafe { self.get_error() }.error()
+        });
+    }
+
+    /// Returns `FramebufferStatus::COMPLETE` if the bound framebuffer can be rendered to, or `0` on error.
+    ///
+    /// # Safety
+    /// See the safety note in `with_loader`
+    #[inline]
+    #[cfg_attr(feature = "trace", track_caller)]
+    #[must_use]
+    pub unsafe fn check_framebuffer_status(&self, target: FramebufferTarget) -> FramebufferStatus {
+        let function = self
+            .check_framebuffer_status_ptr
+            .unwrap_or_else(|| not_loaded("glCheckFramebufferStatus"));
+        #[cfg(feature = "trace")]
+        log::trace!("glCheckFramebufferStatus(target: {target:?})");
+        let result = unsafe { function(target) };
+        #[cfg(feature = "trace")]
+        crate::trace::check("glCheckFramebufferStatus", || {
+            unsafe { self.get_error() }.error()
+        });
+        result
+    }
+
+    /// # Safety
+    /// See the safety note in `with_loader`
+    #[inline]
+    #[cfg_attr(feature = "trace", track_caller)]
+    pub unsafe fn draw_buffers(&self, n: GLsizei, bufs: *const ColorBuffer) {
+        let function = self
+            .draw_buffers_ptr
+            .unwrap_or_else(|| not_loaded("glDrawBuffers"));
+        #[cfg(feature = "trace")]
+        log::trace!("glDrawBuffers(n: {n:?}, bufs: {bufs:?})");
+        unsafe { function(n, bufs) };
+        #[cfg(feature = "trace")]
+        crate::trace::check("glDrawBuffers", || unsafe { self.get_error() }.error());
+    }
+
+    /// # Safety
+    /// See the safety note in `with_loader`
+    #[inline]
+    #[cfg_attr(feature = "trace", track_caller)]
+    pub unsafe fn read_buffer(&self, src: ColorBuffer) {
+        let function = self
+            .read_buffer_ptr
+            .unwrap_or_else(|| not_loaded("glReadBuffer"));
+        #[cfg(feature = "trace")]
+        log::trace!("glReadBuffer(src: {src:?})");
+        unsafe { function(src) };
+        #[cfg(feature = "trace")]
+        crate::trace::check("glReadBuffer", || unsafe { self.get_error() }.error());
+    }
+
+    /// Copies a rectangle from the `READ_FRAMEBUFFER` to the `DRAW_FRAMEBUFFER`, only `NEAREST` and `LINEAR` are valid filters.
+    ///
+    /// # Safety
+    /// See the safety note in `with_loader`
+    #[inline]
+    #[cfg_attr(feature = "trace", track_caller)]
+    pub unsafe fn blit_framebuffer(
+        &self,
+        src_x_0: GLint,
+        src_y_0: GLint,
+        src_x_1: GLint,
+        src_y_1: GLint,
+        dst_x_0: GLint,
+        dst_y_0: GLint,
+        dst_x_1: GLint,
+        dst_y_1: GLint,
+        mask: ClearMask,
+        filter: TextureFilter,
+    ) {
+        let function = self
+            .blit_framebuffer_ptr
+            .unwrap_or_else(|| not_loaded("glBlitFramebuffer"));
+        #[cfg(feature = "trace")]
+        log::trace!("glBlitFramebuffer(src_x_0: {src_x_0:?}, src_y_0: {src_y_0:?}, src_x_1: {src_x_1:?}, src_y_1: {src_y_1:?}, dst_x_0: {dst_x_0:?}, dst_y_0: {dst_y_0:?}, dst_x_1: {dst_x_1:?}, dst_y_1: {dst_y_1:?}, mask: {mask:?}, filter: {filter:?})");
+        unsafe {
+            function(
+                src_x_0, src_y_0, src_x_1, src_y_1, dst_x_0, dst_y_0, dst_x_1, dst_y_1, mask,
+                filter,
+            )
+        };
+        #[cfg(feature = "trace")]
+        crate::trace::check("glBlitFramebuffer", || unsafe { self.get_error() }.error());
+    }
+
+    /// # Safety
+    /// See the safety note in `with_loader`
+    #[inline]
+    #[cfg_attr(feature = "trace", track_caller)]
+    pub unsafe fn delete_framebuffers(&self, n: GLsizei, framebuffers: *const Framebuffer) {
+        let function = self
+            .delete_framebuffers_ptr
+            .unwrap_or_else(|| not_loaded("glDeleteFramebuffers"));
+        #[cfg(feature = "trace")]
+        log::trace!("glDeleteFramebuffers(n: {n:?}, framebuffers: {framebuffers:?})");
+        unsafe { function(n, framebuffers) };
+        #[cfg(feature = "trace")]
+        crate::trace::check("glDeleteFramebuffers", || {
+            unsafe { self.get_error() }.error()
+        });
+    }
+
+    // RENDERBUFFERS
+
+    /// # Safety
+    /// See the safety note in `with_loader`
+    #[inline]
+    #[cfg_attr(feature = "trace", track_caller)]
+    pub unsafe fn gen_renderbuffers(&self, n: GLsizei, renderbuffers: *mut Renderbuffer) {
+        let function = self
+            .gen_renderbuffers_ptr
+            .unwrap_or_else(|| not_loaded("glGenRenderbuffers"));
+        #[cfg(feature = "trace")]
+        log::trace!("glGenRenderbuffers(n: {n:?}, renderbuffers: {renderbuffers:?})");
+        unsafe { function(n, renderbuffers) };
+        #[cfg(feature = "trace")]
+        crate::trace::check("glGenRenderbuffers", || unsafe { self.get_error() }.error());
+    }
+
+    /// # Safety
+    /// See the safety note in `with_loader`
+    #[inline]
+    #[cfg_attr(feature = "trace", track_caller)]
+    pub unsafe fn bind_renderbuffer(&self, target: RenderbufferTarget, renderbuffer: Renderbuffer) {
+        let function = self
+            .bind_renderbuffer_ptr
+            .unwrap_or_else(|| not_loaded("glBindRenderbuffer"));
+        #[cfg(feature = "trace")]
+        log::trace!("glBindRenderbuffer(target: {target:?}, renderbuffer: {renderbuffer:?})");
+        unsafe { function(target, renderbuffer) };
+        #[cfg(feature = "trace")]
+        crate::trace::check("glBindRenderbuffer", || unsafe { self.get_error() }.error());
+    }
+
+    /// # Safety
+    /// See the safety note in `with_loader`
+    #[inline]
+    #[cfg_attr(feature = "trace", track_caller)]
+    pub unsafe fn renderbuffer_storage(
+        &self,
+        target: RenderbufferTarget,
+        internalformat: InternalFormat,
+        width: GLsizei,
+        height: GLsizei,
+    ) {
+        let function = self
+            .renderbuffer_storage_ptr
+            .unwrap_or_else(|| not_loaded("glRenderbufferStorage"));
+        #[cfg(feature = "trace")]
+        log::trace!("glRenderbufferStorage(target: {target:?}, internalformat: {internalformat:?}, width: {width:?}, height: {height:?})");
+        unsafe { function(target, internalformat, width, height) };
+        #[cfg(feature = "trace")]
+        crate::trace::check("glRenderbufferStorage", || {
+            unsafe { self.get_error() }.error()
+        });
+    }
+
+    /// # Safety
+    /// See the safety note in `with_loader`
+    #[inline]
+    #[cfg_attr(feature = "trace", track_caller)]
+    pub unsafe fn renderbuffer_storage_multisample(
+        &self,
+        target: RenderbufferTarget,
+        samples: GLsizei,
+        internalformat: InternalFormat,
+        width: GLsizei,
+        height: GLsizei,
+    ) {
+        let function = self
+            .renderbuffer_storage_multisample_ptr
+            .unwrap_or_else(|| not_loaded("glRenderbufferStorageMultisample"));
+        #[cfg(feature = "trace")]
+        log::trace!("glRenderbufferStorageMultisample(target: {target:?}, samples: {samples:?}, internalformat: {internalformat:?}, width: {width:?}, height: {height:?})");
+        unsafe { function(target, samples, internalformat, width, height) };
+        #[cfg(feature = "trace")]
+        crate::trace::check("glRenderbufferStorageMultisample", || {
+            unsafe { self.get_error() }.error()
+        });
+    }
+
+    /// # Safety
+    /// See the safety note in `with_loader`
+    #[inline]
+    #[cfg_attr(feature = "trace", track_caller)]
+    pub unsafe fn delete_renderbuffers(&self, n: GLsizei, renderbuffers: *const Renderbuffer) {
+        let function = self
+            .delete_renderbuffers_ptr
+            .unwrap_or_else(|| not_loaded("glDeleteRenderbuffers"));
+        #[cfg(feature = "trace")]
+        log::trace!("glDeleteRenderbuffers(n: {n:?}, renderbuffers: {renderbuffers:?})");
+        unsafe { function(n, renderbuffers) };
+        #[cfg(feature = "trace")]
+        crate::trace::check("glDeleteRenderbuffers", || {
+            unsafe { self.get_error() }.error()
+        });
+    }
+
+    // UNIFORMS
+
+    /// Returns `UniformLocation::NONE` if `name` is not an active uniform of `program`.
+    ///
+    /// # Safety
+    /// See the safety note in `with_loader`
+    #[inline]
+    #[cfg_attr(feature = "trace", track_caller)]
+    #[must_use]
+    pub unsafe fn get_uniform_location(
+        &self,
+        program: Program,
+        name: *const GLchar,
+    ) -> UniformLocation {
+        let function = self
+            .get_uniform_location_ptr
+            .unwrap_or_else(|| not_loaded("glGetUniformLocation"));
+        #[cfg(feature = "trace")]
+        log::trace!("glGetUniformLocation(program: {program:?}, name: {name:?})");
+        let result = unsafe { function(program, name) };
+        #[cfg(feature = "trace")]
+        crate::trace::check("glGetUniformLocation", || {
+            unsafe { self.get_error() }.error()
+        });
+        result
+    }
+
+    /// # Safety
+    /// See the safety note in `with_loader`
+    #[inline]
+    #[cfg_attr(feature = "trace", track_caller)]
+    pub unsafe fn uniform_1f(&self, location: UniformLocation, v0: GLfloat) {
+        let function = self
+            .uniform_1f_ptr
+            .unwrap_or_else(|| not_loaded("glUniform1f"));
+        #[cfg(feature = "trace")]
+        log::trace!("glUniform1f(location: {location:?}, v0: {v0:?})");
+        unsafe { function(location, v0) };
+        #[cfg(feature = "trace")]
+        crate::trace::check("glUniform1f", || unsafe { self.get_error() }.error());
+    }
+
+    /// # Safety
+    /// See the safety note in `with_loader`
+    #[inline]
+    #[cfg_attr(feature = "trace", track_caller)]
+    pub unsafe fn uniform_1fv(
+        &self,
+        location: UniformLocation,
+        count: GLsizei,
+        value: *const GLfloat,
+    ) {
+        let function = self
+            .uniform_1fv_ptr
+            .unwrap_or_else(|| not_loaded("glUniform1fv"));
+        #[cfg(feature = "trace")]
+        log::trace!("glUniform1fv(location: {location:?}, count: {count:?}, value: {value:?})");
+        unsafe { function(location, count, value) };
+        #[cfg(feature = "trace")]
+        crate::trace::check("glUniform1fv", || unsafe { self.get_error() }.error());
+    }
+
+    /// # Safety
+    /// See the safety note in `with_loader`
+    #[inline]
+    #[cfg_attr(feature = "trace", track_caller)]
+    pub unsafe fn uniform_1i(&self, location: UniformLocation, v0: GLint) {
+        let function = self
+            .uniform_1i_ptr
+            .unwrap_or_else(|| not_loaded("glUniform1i"));
+        #[cfg(feature = "trace")]
+        log::trace!("glUniform1i(location: {location:?}, v0: {v0:?})");
+        unsafe { function(location, v0) };
+        #[cfg(feature = "trace")]
+        crate::trace::check("glUniform1i", || unsafe { self.get_error() }.error());
+    }
+
+    /// # Safety
+    /// See the safety note in `with_loader`
+    #[inline]
+    #[cfg_attr(feature = "trace", track_caller)]
+    pub unsafe fn uniform_1iv(
+        &self,
+        location: UniformLocation,
+        count: GLsizei,
+        value: *const GLint,
+    ) {
+        let function = self
+            .uniform_1iv_ptr
+            .unwrap_or_else(|| not_loaded("glUniform1iv"));
+        #[cfg(feature = "trace")]
+        log::trace!("glUniform1iv(location: {location:?}, count: {count:?}, value: {value:?})");
+        unsafe { function(location, count, value) };
+        #[cfg(feature = "trace")]
+        crate::trace::check("glUniform1iv", || unsafe { self.get_error() }.error());
+    }
+
+    /// # Safety
+    /// See the safety note in `with_loader`
+    #[inline]
+    #[cfg_attr(feature = "trace", track_caller)]
+    pub unsafe fn uniform_1ui(&self, location: UniformLocation, v0: GLuint) {
+        let function = self
+            .uniform_1ui_ptr
+            .unwrap_or_else(|| not_loaded("glUniform1ui"));
+        #[cfg(feature = "trace")]
+        log::trace!("glUniform1ui(location: {location:?}, v0: {v0:?})");
+        unsafe { function(location, v0) };
+        #[cfg(feature = "trace")]
+        crate::trace::check("glUniform1ui", || unsafe { self.get_error() }.error());
+    }
+
+    /// # Safety
+    /// See the safety note in `with_loader`
+    #[inline]
+    #[cfg_attr(feature = "trace", track_caller)]
+    pub unsafe fn uniform_1uiv(
+        &self,
+        location: UniformLocation,
+        count: GLsizei,
+        value: *const GLuint,
+    ) {
+        let function = self
+            .uniform_1uiv_ptr
+            .unwrap_or_else(|| not_loaded("glUniform1uiv"));
+        #[cfg(feature = "trace")]
+        log::trace!("glUniform1uiv(location: {location:?}, count: {count:?}, value: {value:?})");
+        unsafe { function(location, count, value) };
+        #[cfg(feature = "trace")]
+        crate::trace::check("glUniform1uiv", || unsafe { self.get_error() }.error());
+    }
+
+    /// # Safety
+    /// See the safety note in `with_loader`
+    #[inline]
+    #[cfg_attr(feature = "trace", track_caller)]
+    pub unsafe fn uniform_2f(&self, location: UniformLocation, v0: GLfloat, v1: GLfloat) {
+        let function = self
+            .uniform_2f_ptr
+            .unwrap_or_else(|| not_loaded("glUniform2f"));
+        #[cfg(feature = "trace")]
+        log::trace!("glUniform2f(location: {location:?}, v0: {v0:?}, v1: {v1:?})");
+        unsafe { function(location, v0, v1) };
+        #[cfg(feature = "trace")]
+        crate::trace::check("glUniform2f", || unsafe { self.get_error() }.error());
+    }
+
+    /// # Safety
+    /// See the safety note in `with_loader`
+    #[inline]
+    #[cfg_attr(feature = "trace", track_caller)]
+    pub unsafe fn uniform_2fv(
+        &self,
+        location: UniformLocation,
+        count: GLsizei,
+        value: *const GLfloat,
+    ) {
+        let function = self
+            .uniform_2fv_ptr
+            .unwrap_or_else(|| not_loaded("glUniform2fv"));
+        #[cfg(feature = "trace")]
+        log::trace!("glUniform2fv(location: {location:?}, count: {count:?}, value: {value:?})");
+        unsafe { function(location, count, value) };
+        #[cfg(feature = "trace")]
+        crate::trace::check("glUniform2fv", || unsafe { self.get_error() }.error());
+    }
+
+    /// # Safety
+    /// See the safety note in `with_loader`
+    #[inline]
+    #[cfg_attr(feature = "trace", track_caller)]
+    pub unsafe fn uniform_2i(&self, location: UniformLocation, v0: GLint, v1: GLint) {
+        let function = self
+            .uniform_2i_ptr
+            .unwrap_or_else(|| not_loaded("glUniform2i"));
+        #[cfg(feature = "trace")]
+        log::trace!("glUniform2i(location: {location:?}, v0: {v0:?}, v1: {v1:?})");
+        unsafe { function(location, v0, v1) };
+        #[cfg(feature = "trace")]
+        crate::trace::check("glUniform2i", || unsafe { self.get_error() }.error());
+    }
+
+    /// # Safety
+    /// See the safety note in `with_loader`
+    #[inline]
+    #[cfg_attr(feature = "trace", track_caller)]
+    pub unsafe fn uniform_2iv(
+        &self,
+        location: UniformLocation,
+        count: GLsizei,
+        value: *const GLint,
+    ) {
+        let function = self
+            .uniform_2iv_ptr
+            .unwrap_or_else(|| not_loaded("glUniform2iv"));
+        #[cfg(feature = "trace")]
+        log::trace!("glUniform2iv(location: {location:?}, count: {count:?}, value: {value:?})");
+        unsafe { function(location, count, value) };
+        #[cfg(feature = "trace")]
+        crate::trace::check("glUniform2iv", || unsafe { self.get_error() }.error());
+    }
+
+    /// # Safety
+    /// See the safety note in `with_loader`
+    #[inline]
+    #[cfg_attr(feature = "trace", track_caller)]
+    pub unsafe fn uniform_2ui(&self, location: UniformLocation, v0: GLuint, v1: GLuint) {
+        let function = self
+            .uniform_2ui_ptr
+            .unwrap_or_else(|| not_loaded("glUniform2ui"));
+        #[cfg(feature = "trace")]
+        log::trace!("glUniform2ui(location: {location:?}, v0: {v0:?}, v1: {v1:?})");
+        unsafe { function(location, v0, v1) };
+        #[cfg(feature = "trace")]
+        crate::trace::check("glUniform2ui", || unsafe { self.get_error() }.error());
+    }
+
+    /// # Safety
+    /// See the safety note in `with_loader`
+    #[inline]
+    #[cfg_attr(feature = "trace", track_caller)]
+    pub unsafe fn uniform_2uiv(
+        &self,
+        location: UniformLocation,
+        count: GLsizei,
+        value: *const GLuint,
+    ) {
+        let function = self
+            .uniform_2uiv_ptr
+            .unwrap_or_else(|| not_loaded("glUniform2uiv"));
+        #[cfg(feature = "trace")]
+        log::trace!("glUniform2uiv(location: {location:?}, count: {count:?}, value: {value:?})");
+        unsafe { function(location, count, value) };
+        #[cfg(feature = "trace")]
+        crate::trace::check("glUniform2uiv", || unsafe { self.get_error() }.error());
+    }
+
+    /// # Safety
+    /// See the safety note in `with_loader`
+    #[inline]
+    #[cfg_attr(feature = "trace", track_caller)]
+    pub unsafe fn uniform_3f(
+        &self,
+        location: UniformLocation,
+        v0: GLfloat,
+        v1: GLfloat,
+        v2: GLfloat,
+    ) {
+        let function = self
+            .uniform_3f_ptr
+            .unwrap_or_else(|| not_loaded("glUniform3f"));
+        #[cfg(feature = "trace")]
+        log::trace!("glUniform3f(location: {location:?}, v0: {v0:?}, v1: {v1:?}, v2: {v2:?})");
+        unsafe { function(location, v0, v1, v2) };
+        #[cfg(feature = "trace")]
+        crate::trace::check("glUniform3f", || unsafe { self.get_error() }.error());
+    }
+
+    /// # Safety
+    /// See the safety note in `with_loader`
+    #[inline]
+    #[cfg_attr(feature = "trace", track_caller)]
+    pub unsafe fn uniform_3fv(
+        &self,
+        location: UniformLocation,
+        count: GLsizei,
+        value: *const GLfloat,
+    ) {
+        let function = self
+            .uniform_3fv_ptr
+            .unwrap_or_else(|| not_loaded("glUniform3fv"));
+        #[cfg(feature = "trace")]
+        log::trace!("glUniform3fv(location: {location:?}, count: {count:?}, value: {value:?})");
+        unsafe { function(location, count, value) };
+        #[cfg(feature = "trace")]
+        crate::trace::check("glUniform3fv", || unsafe { self.get_error() }.error());
+    }
+
+    /// # Safety
+    /// See the safety note in `with_loader`
+    #[inline]
+    #[cfg_attr(feature = "trace", track_caller)]
+    pub unsafe fn uniform_3i(&self, location: UniformLocation, v0: GLint, v1: GLint, v2: GLint) {
+        let function = self
+            .uniform_3i_ptr
+            .unwrap_or_else(|| not_loaded("glUniform3i"));
+        #[cfg(feature = "trace")]
+        log::trace!("glUniform3i(location: {location:?}, v0: {v0:?}, v1: {v1:?}, v2: {v2:?})");
+        unsafe { function(location, v0, v1, v2) };
+        #[cfg(feature = "trace")]
+        crate::trace::check("glUniform3i", || unsafe { self.get_error() }.error());
+    }
+
+    /// # Safety
+    /// See the safety note in `with_loader`
+    #[inline]
+    #[cfg_attr(feature = "trace", track_caller)]
+    pub unsafe fn uniform_3iv(
+        &self,
+        location: UniformLocation,
+        count: GLsizei,
+        value: *const GLint,
+    ) {
+        let function = self
+            .uniform_3iv_ptr
+            .unwrap_or_else(|| not_loaded("glUniform3iv"));
+        #[cfg(feature = "trace")]
+        log::trace!("glUniform3iv(location: {location:?}, count: {count:?}, value: {value:?})");
+        unsafe { function(location, count, value) };
+        #[cfg(feature = "trace")]
+        crate::trace::check("glUniform3iv", || unsafe { self.get_error() }.error());
+    }
+
+    /// # Safety
+    /// See the safety note in `with_loader`
+    #[inline]
+    #[cfg_attr(feature = "trace", track_caller)]
+    pub unsafe fn uniform_3ui(
+        &self,
+        location: UniformLocation,
+        v0: GLuint,
+        v1: GLuint,
+        v2: GLuint,
+    ) {
+        let function = self
+            .uniform_3ui_ptr
+            .unwrap_or_else(|| not_loaded("glUniform3ui"));
+        #[cfg(feature = "trace")]
+        log::trace!("glUniform3ui(location: {location:?}, v0: {v0:?}, v1: {v1:?}, v2: {v2:?})");
+        unsafe { function(location, v0, v1, v2) };
+        #[cfg(feature = "trace")]
+        crate::trace::check("glUniform3ui", || unsafe { self.get_error() }.error());
+    }
+
+    /// # Safety
+    /// See the safety note in `with_loader`
+    #[inline]
+    #[cfg_attr(feature = "trace", track_caller)]
+    pub unsafe fn uniform_3uiv(
+        &self,
+        location: UniformLocation,
+        count: GLsizei,
+        value: *const GLuint,
+    ) {
+        let function = self
+            .uniform_3uiv_ptr
+            .unwrap_or_else(|| not_loaded("glUniform3uiv"));
+        #[cfg(feature = "trace")]
+        log::trace!("glUniform3uiv(location: {location:?}, count: {count:?}, value: {value:?})");
+        unsafe { function(location, count, value) };
+        #[cfg(feature = "trace")]
+        crate::trace::check("glUniform3uiv", || unsafe { self.get_error() }.error());
+    }
+
+    /// # Safety
+    /// See the safety note in `with_loader`
+    #[inline]
+    #[cfg_attr(feature = "trace", track_caller)]
+    pub unsafe fn uniform_4f(
+        &self,
+        location: UniformLocation,
+        v0: GLfloat,
+        v1: GLfloat,
+        v2: GLfloat,
+        v3: GLfloat,
+    ) {
+        let function = self
+            .uniform_4f_ptr
+            .unwrap_or_else(|| not_loaded("glUniform4f"));
+        #[cfg(feature = "trace")]
+        log::trace!(
+            "glUniform4f(location: {location:?}, v0: {v0:?}, v1: {v1:?}, v2: {v2:?}, v3: {v3:?})"
+        );
+        unsafe { function(location, v0, v1, v2, v3) };
+        #[cfg(feature = "trace")]
+        crate::trace::check("glUniform4f", || unsafe { self.get_error() }.error());
+    }
+
+    /// # Safety
+    /// See the safety note in `with_loader`
+    #[inline]
+    #[cfg_attr(feature = "trace", track_caller)]
+    pub unsafe fn uniform_4fv(
+        &self,
+        location: UniformLocation,
+        count: GLsizei,
+        value: *const GLfloat,
+    ) {
+        let function = self
+            .uniform_4fv_ptr
+            .unwrap_or_else(|| not_loaded("glUniform4fv"));
+        #[cfg(feature = "trace")]
+        log::trace!("glUniform4fv(location: {location:?}, count: {count:?}, value: {value:?})");
+        unsafe { function(location, count, value) };
+        #[cfg(feature = "trace")]
+        crate::trace::check("glUniform4fv", || unsafe { self.get_error() }.error());
+    }
+
+    /// # Safety
+    /// See the safety note in `with_loader`
+    #[inline]
+    #[cfg_attr(feature = "trace", track_caller)]
+    pub unsafe fn uniform_4i(
+        &self,
+        location: UniformLocation,
+        v0: GLint,
+        v1: GLint,
+        v2: GLint,
+        v3: GLint,
+    ) {
+        let function = self
+            .uniform_4i_ptr
+            .unwrap_or_else(|| not_loaded("glUniform4i"));
+        #[cfg(feature = "trace")]
+        log::trace!(
+            "glUniform4i(location: {location:?}, v0: {v0:?}, v1: {v1:?}, v2: {v2:?}, v3: {v3:?})"
+        );
+        unsafe { function(location, v0, v1, v2, v3) };
+        #[cfg(feature = "trace")]
+        crate::trace::check("glUniform4i", || unsafe { self.get_error() }.error());
+    }
+
+    /// # Safety
+    /// See the safety note in `with_loader`
+    #[inline]
+    #[cfg_attr(feature = "trace", track_caller)]
+    pub unsafe fn uniform_4iv(
+        &self,
+        location: UniformLocation,
+        count: GLsizei,
+        value: *const GLint,
+    ) {
+        let function = self
+            .uniform_4iv_ptr
+            .unwrap_or_else(|| not_loaded("glUniform4iv"));
+        #[cfg(feature = "trace")]
+        log::trace!("glUniform4iv(location: {location:?}, count: {count:?}, value: {value:?})");
+        unsafe { function(location, count, value) };
+        #[cfg(feature = "trace")]
+        crate::trace::check("glUniform4iv", || unsafe { self.get_error() }.error());
+    }
+
+    /// # Safety
+    /// See the safety note in `with_loader`
+    #[inline]
+    #[cfg_attr(feature = "trace", track_caller)]
+    pub unsafe fn uniform_4ui(
+        &self,
+        location: UniformLocation,
+        v0: GLuint,
+        v1: GLuint,
+        v2: GLuint,
+        v3: GLuint,
+    ) {
+        let function = self
+            .uniform_4ui_ptr
+            .unwrap_or_else(|| not_loaded("glUniform4ui"));
+        #[cfg(feature = "trace")]
+        log::trace!(
+            "glUniform4ui(location: {location:?}, v0: {v0:?}, v1: {v1:?}, v2: {v2:?}, v3: {v3:?})"
+        );
+        unsafe { function(location, v0, v1, v2, v3) };
+        #[cfg(feature = "trace")]
+        crate::trace::check("glUniform4ui", || unsafe { self.get_error() }.error());
+    }
+
+    /// # Safety
+    /// See the safety note in `with_loader`
+    #[inline]
+    #[cfg_attr(feature = "trace", track_caller)]
+    pub unsafe fn uniform_4uiv(
+        &self,
+        location: UniformLocation,
+        count: GLsizei,
+        value: *const GLuint,
+    ) {
+        let function = self
+            .uniform_4uiv_ptr
+            .unwrap_or_else(|| not_loaded("glUniform4uiv"));
+        #[cfg(feature = "trace")]
+        log::trace!("glUniform4uiv(location: {location:?}, count: {count:?}, value: {value:?})");
+        unsafe { function(location, count, value) };
+        #[cfg(feature = "trace")]
+        crate::trace::check("glUniform4uiv", || unsafe { self.get_error() }.error());
+    }
+
+    /// # Safety
+    /// See the safety note in `with_loader`
+    #[inline]
+    #[cfg_attr(feature = "trace", track_caller)]
+    pub unsafe fn uniform_matrix_2fv(
+        &self,
+        location: UniformLocation,
+        count: GLsizei,
+        transpose: GLboolean,
+        value: *const GLfloat,
+    ) {
+        let function = self
+            .uniform_matrix_2fv_ptr
+            .unwrap_or_else(|| not_loaded("glUniformMatrix2fv"));
+        #[cfg(feature = "trace")]
+        log::trace!("glUniformMatrix2fv(location: {location:?}, count: {count:?}, transpose: {transpose:?}, value: {value:?})");
+        unsafe { function(location, count, transpose, value) };
+        #[cfg(feature = "trace")]
+        crate::trace::check("glUniformMatrix2fv", || unsafe { self.get_error() }.error());
+    }
+
+    /// # Safety
+    /// See the safety note in `with_loader`
+    #[inline]
+    #[cfg_attr(feature = "trace", track_caller)]
+    pub unsafe fn uniform_matrix_3fv(
+        &self,
+        location: UniformLocation,
+        count: GLsizei,
+        transpose: GLboolean,
+        value: *const GLfloat,
+    ) {
+        let function = self
+            .uniform_matrix_3fv_ptr
+            .unwrap_or_else(|| not_loaded("glUniformMatrix3fv"));
+        #[cfg(feature = "trace")]
+        log::trace!("glUniformMatrix3fv(location: {location:?}, count: {count:?}, transpose: {transpose:?}, value: {value:?})");
+        unsafe { function(location, count, transpose, value) };
+        #[cfg(feature = "trace")]
+        crate::trace::check("glUniformMatrix3fv", || unsafe { self.get_error() }.error());
+    }
+
+    /// # Safety
+    /// See the safety note in `with_loader`
+    #[inline]
+    #[cfg_attr(feature = "trace", track_caller)]
+    pub unsafe fn uniform_matrix_4fv(
+        &self,
+        location: UniformLocation,
+        count: GLsizei,
+        transpose: GLboolean,
+        value: *const GLfloat,
+    ) {
+        let function = self
+            .uniform_matrix_4fv_ptr
+            .unwrap_or_else(|| not_loaded("glUniformMatrix4fv"));
+        #[cfg(feature = "trace")]
+        log::trace!("glUniformMatrix4fv(location: {location:?}, count: {count:?}, transpose: {transpose:?}, value: {value:?})");
+        unsafe { function(location, count, transpose, value) };
+        #[cfg(feature = "trace")]
+        crate::trace::check("glUniformMatrix4fv", || unsafe { self.get_error() }.error());
+    }
+
+    /// # Safety
+    /// See the safety note in `with_loader`
+    #[inline]
+    #[cfg_attr(feature = "trace", track_caller)]
+    pub unsafe fn uniform_matrix_2x3fv(
+        &self,
+        location: UniformLocation,
+        count: GLsizei,
+        transpose: GLboolean,
+        value: *const GLfloat,
+    ) {
+        let function = self
+            .uniform_matrix_2x3fv_ptr
+            .unwrap_or_else(|| not_loaded("glUniformMatrix2x3fv"));
+        #[cfg(feature = "trace")]
+        log::trace!("glUniformMatrix2x3fv(location: {location:?}, count: {count:?}, transpose: {transpose:?}, value: {value:?})");
+        unsafe { function(location, count, transpose, value) };
+        #[cfg(feature = "trace")]
+        crate::trace::check("glUniformMatrix2x3fv", || {
+            unsafe { self.get_error() }.error()
+        });
+    }
+
+    /// # Safety
+    /// See the safety note in `with_loader`
+    #[inline]
+    #[cfg_attr(feature = "trace", track_caller)]
+    pub unsafe fn uniform_matrix_3x2fv(
+        &self,
+        location: UniformLocation,
+        count: GLsizei,
+        transpose: GLboolean,
+        value: *const GLfloat,
+    ) {
+        let function = self
+            .uniform_matrix_3x2fv_ptr
+            .unwrap_or_else(|| not_loaded("glUniformMatrix3x2fv"));
+        #[cfg(feature = "trace")]
+        log::trace!("glUniformMatrix3x2fv(location: {location:?}, count: {count:?}, transpose: {transpose:?}, value: {value:?})");
+        unsafe { function(location, count, transpose, value) };
+        #[cfg(feature = "trace")]
+        crate::trace::check("glUniformMatrix3x2fv", || {
+            unsafe { self.get_error() }.error()
+        });
+    }
+
+    /// # Safety
+    /// See the safety note in `with_loader`
+    #[inline]
+    #[cfg_attr(feature = "trace", track_caller)]
+    pub unsafe fn uniform_matrix_2x4fv(
+        &self,
+        location: UniformLocation,
+        count: GLsizei,
+        transpose: GLboolean,
+        value: *const GLfloat,
+    ) {
+        let function = self
+            .uniform_matrix_2x4fv_ptr
+            .unwrap_or_else(|| not_loaded("glUniformMatrix2x4fv"));
+        #[cfg(feature = "trace")]
+        log::trace!("glUniformMatrix2x4fv(location: {location:?}, count: {count:?}, transpose: {transpose:?}, value: {value:?})");
+        unsafe { function(location, count, transpose, value) };
+        #[cfg(feature = "trace")]
+        crate::trace::check("glUniformMatrix2x4fv", || {
+            unsafe { self.get_error() }.error()
+        });
+    }
+
+    /// # Safety
+    /// See the safety note in `with_loader`
+    #[inline]
+    #[cfg_attr(feature = "trace", track_caller)]
+    pub unsafe fn uniform_matrix_4x2fv(
+        &self,
+        location: UniformLocation,
+        count: GLsizei,
+        transpose: GLboolean,
+        value: *const GLfloat,
+    ) {
+        let function = self
+            .uniform_matrix_4x2fv_ptr
+            .unwrap_or_else(|| not_loaded("glUniformMatrix4x2fv"));
+        #[cfg(feature = "trace")]
+        log::trace!("glUniformMatrix4x2fv(location: {location:?}, count: {count:?}, transpose: {transpose:?}, value: {value:?})");
+        unsafe { function(location, count, transpose, value) };
+        #[cfg(feature = "trace")]
+        crate::trace::check("glUniformMatrix4x2fv", || {
+            unsafe { self.get_error() }.error()
+        });
+    }
+
+    /// # Safety
+    /// See the safety note in `with_loader`
+    #[inline]
+    #[cfg_attr(feature = "trace", track_caller)]
+    pub unsafe fn uniform_matrix_3x4fv(
+        &self,
+        location: UniformLocation,
+        count: GLsizei,
+        transpose: GLboolean,
+        value: *const GLfloat,
+    ) {
+        let function = self
+            .uniform_matrix_3x4fv_ptr
+            .unwrap_or_else(|| not_loaded("glUniformMatrix3x4fv"));
+        #[cfg(feature = "trace")]
+        log::trace!("glUniformMatrix3x4fv(location: {location:?}, count: {count:?}, transpose: {transpose:?}, value: {value:?})");
+        unsafe { function(location, count, transpose, value) };
+        #[cfg(feature = "trace")]
+        crate::trace::check("glUniformMatrix3x4fv", || {
+            unsafe { self.get_error() }.error()
+        });
+    }
+
+    /// # Safety
+    /// See the safety note in `with_loader`
+    #[inline]
+    #[cfg_attr(feature = "trace", track_caller)]
+    pub unsafe fn uniform_matrix_4x3fv(
+        &self,
+        location: UniformLocation,
+        count: GLsizei,
+        transpose: GLboolean,
+        value: *const GLfloat,
+    ) {
+        let function = self
+            .uniform_matrix_4x3fv_ptr
+            .unwrap_or_else(|| not_loaded("glUniformMatrix4x3fv"));
+        #[cfg(feature = "trace")]
+        log::trace!("glUniformMatrix4x3fv(location: {location:?}, count: {count:?}, transpose: {transpose:?}, value: {value:?})");
+        unsafe { function(location, count, transpose, value) };
+        #[cfg(feature = "trace")]
+        crate::trace::check("glUniformMatrix4x3fv", || {
+            unsafe { self.get_error() }.error()
+        });
+    }
+
+    /// Queries the uniform at `index`, which ranges up to the `ACTIVE_RESOURCES` of `ProgramInterface::UNIFORM`.
+    ///
+    /// # Safety
+    /// See the safety note in `with_loader`
+    #[inline]
+    #[cfg_attr(feature = "trace", track_caller)]
+    pub unsafe fn get_active_uniform(
+        &self,
+        program: Program,
+        index: GLuint,
+        buf_size: GLsizei,
+        length: *mut GLsizei,
+        size: *mut GLint,
+        kind: *mut UniformKind,
+        name: *mut GLchar,
+    ) {
+        let function = self
+            .get_active_uniform_ptr
+            .unwrap_or_else(|| not_loaded("glGetActiveUniform"));
+        #[cfg(feature = "trace")]
+        log::trace!("glGetActiveUniform(program: {program:?}, index: {index:?}, buf_size: {buf_size:?}, length: {length:?}, size: {size:?}, kind: {kind:?}, name: {name:?})");
+        unsafe { function(program, index, buf_size, length, size, kind, name) };
+        #[cfg(feature = "trace")]
+        crate::trace::check("glGetActiveUniform", || unsafe { self.get_error() }.error());
+    }
+
+    // QUERIES
+
+    /// # Safety
+    /// See the safety note in `with_loader`
+    #[inline]
+    #[cfg_attr(feature = "trace", track_caller)]
+    pub unsafe fn get_integerv(&self, pname: IntegerParameter, data: *mut GLint) {
+        let function = self
+            .get_integerv_ptr
+            .unwrap_or_else(|| not_loaded("glGetIntegerv"));
+        #[cfg(feature = "trace")]
+        log::trace!("glGetIntegerv(pname: {pname:?}, data: {data:?})");
+        unsafe { function(pname, data) };
+        #[cfg(feature = "trace")]
+        crate::trace::check("glGetIntegerv", || unsafe { self.get_error() }.error());
+    }
+
+    /// # Safety
+    /// See the safety note in `with_loader`
+    #[inline]
+    #[cfg_attr(feature = "trace", track_caller)]
+    pub unsafe fn get_integeri_v(&self, target: IndexedParameter, index: GLuint, data: *mut GLint) {
+        let function = self
+            .get_integeri_v_ptr
+            .unwrap_or_else(|| not_loaded("glGetIntegeri_v"));
+        #[cfg(feature = "trace")]
+        log::trace!("glGetIntegeri_v(target: {target:?}, index: {index:?}, data: {data:?})");
+        unsafe { function(target, index, data) };
+        #[cfg(feature = "trace")]
+        crate::trace::check("glGetIntegeri_v", || unsafe { self.get_error() }.error());
+    }
+}
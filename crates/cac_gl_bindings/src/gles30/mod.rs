@@ -0,0 +1,6 @@
+pub use crate::gles::*;
+use crate::{not_loaded, Error, Loader};
+
+// `Api` and its wrappers for everything new in OpenGL ES 3.0, see `cac_gl_generator`
+include!("generated.rs");
+include!("../checks.rs");
@@ -0,0 +1,389 @@
+// This file is generated by `cac_gl_generator` from the Khronos registry, do not edit it by hand.
+// Change `crates/cac_gl_generator/allowlist.toml` and run `cargo run -p cac_gl_generator` instead.
+
+/// Bindings to a curated subset of OpenGL ES 3.1
+///
+/// Everything up to OpenGL ES 3.0 is available through `Deref` to `gles30::Api`.
+#[allow(clippy::struct_field_names)]
+pub struct Api {
+    base: super::gles30::Api,
+
+    //draw
+    draw_arrays_indirect_ptr:
+        Option<unsafe extern "system" fn(mode: Primitive, indirect: *const std::ffi::c_void)>,
+    draw_elements_indirect_ptr: Option<
+        unsafe extern "system" fn(
+            mode: Primitive,
+            kind: IndexKind,
+            indirect: *const std::ffi::c_void,
+        ),
+    >,
+
+    //vertex arrays
+    bind_vertex_buffer_ptr: Option<
+        unsafe extern "system" fn(
+            bindingindex: GLuint,
+            buffer: Buffer,
+            offset: GLintptr,
+            stride: GLsizei,
+        ),
+    >,
+    vertex_attrib_format_ptr: Option<
+        unsafe extern "system" fn(
+            attribindex: GLuint,
+            size: GLint,
+            kind: VertexAttributeKind,
+            normalized: GLboolean,
+            relativeoffset: GLuint,
+        ),
+    >,
+    vertex_attrib_binding_ptr:
+        Option<unsafe extern "system" fn(attribindex: GLuint, bindingindex: GLuint)>,
+
+    //uniforms
+    get_program_interfaceiv_ptr: Option<
+        unsafe extern "system" fn(
+            program: Program,
+            program_interface: ProgramInterface,
+            pname: ProgramInterfaceParameter,
+            params: *mut GLint,
+        ),
+    >,
+
+    //compute
+    dispatch_compute_ptr: Option<
+        unsafe extern "system" fn(num_groups_x: GLuint, num_groups_y: GLuint, num_groups_z: GLuint),
+    >,
+    dispatch_compute_indirect_ptr: Option<unsafe extern "system" fn(indirect: GLintptr)>,
+    memory_barrier_ptr: Option<unsafe extern "system" fn(barriers: BarrierBits)>,
+}
+
+impl std::ops::Deref for Api {
+    type Target = super::gles30::Api;
+
+    fn deref(&self) -> &super::gles30::Api {
+        &self.base
+    }
+}
+
+#[allow(
+    clippy::let_and_return,
+    clippy::semicolon_if_nothing_returned,
+    clippy::similar_names,
+    clippy::too_many_arguments
+)]
+impl Api {
+    /// Loads all function pointers using a context function loader,
+    /// also called `ProcAddress`. Works with GLFW, SDL2 any pretty much any OpenGL ES context.
+    ///
+    /// # Errors
+    /// This function will return an error if any function pointer returns a null pointer.
+    ///
+    /// # Safety
+    /// Unfortunately, some drivers return wrong addresses that are indistinguishable from correct
+    /// ones, instead of being null pointers.
+    ///
+    #[allow(clippy::too_many_lines)]
+    pub unsafe fn with_loader(
+        loader: &impl Fn(&str) -> *const std::ffi::c_void,
+    ) -> Result<Self, Error> {
+        Ok(Self {
+            base: unsafe { super::gles30::Api::with_loader(loader)? },
+
+            //draw
+            draw_arrays_indirect_ptr: Some(loader.load("glDrawArraysIndirect")?),
+            draw_elements_indirect_ptr: Some(loader.load("glDrawElementsIndirect")?),
+
+            //vertex arrays
+            bind_vertex_buffer_ptr: Some(loader.load("glBindVertexBuffer")?),
+            vertex_attrib_format_ptr: Some(loader.load("glVertexAttribFormat")?),
+            vertex_attrib_binding_ptr: Some(loader.load("glVertexAttribBinding")?),
+
+            //uniforms
+            get_program_interfaceiv_ptr: Some(loader.load("glGetProgramInterfaceiv")?),
+
+            //compute
+            dispatch_compute_ptr: Some(loader.load("glDispatchCompute")?),
+            dispatch_compute_indirect_ptr: Some(loader.load("glDispatchComputeIndirect")?),
+            memory_barrier_ptr: Some(loader.load("glMemoryBarrier")?),
+        })
+    }
+
+    /// Like `with_loader`, but keeps going when a function pointer is null, e.g. for entry points
+    /// a driver doesn't expose. Use `is_loaded` or `missing_functions` to find out what is
+    /// available, calling a wrapper whose function is missing panics.
+    ///
+    /// # Safety
+    /// See the safety note in `with_loader`
+    #[must_use]
+    #[allow(clippy::too_many_lines)]
+    pub unsafe fn with_loader_partial(loader: &impl Fn(&str) -> *const std::ffi::c_void) -> Self {
+        Self {
+            base: unsafe { super::gles30::Api::with_loader_partial(loader) },
+
+            //draw
+            draw_arrays_indirect_ptr: loader.load("glDrawArraysIndirect").ok(),
+            draw_elements_indirect_ptr: loader.load("glDrawElementsIndirect").ok(),
+
+            //vertex arrays
+            bind_vertex_buffer_ptr: loader.load("glBindVertexBuffer").ok(),
+            vertex_attrib_format_ptr: loader.load("glVertexAttribFormat").ok(),
+            vertex_attrib_binding_ptr: loader.load("glVertexAttribBinding").ok(),
+
+            //uniforms
+            get_program_interfaceiv_ptr: loader.load("glGetProgramInterfaceiv").ok(),
+
+            //compute
+            dispatch_compute_ptr: loader.load("glDispatchCompute").ok(),
+            dispatch_compute_indirect_ptr: loader.load("glDispatchComputeIndirect").ok(),
+            memory_barrier_ptr: loader.load("glMemoryBarrier").ok(),
+        }
+    }
+
+    /// Returns true if `symbol`, e.g. `"glDebugMessageCallback"`, is part of this `Api` and was
+    /// found by the loader.
+    #[must_use]
+    pub fn is_loaded(&self, symbol: &str) -> bool {
+        self.symbols().any(|(s, loaded)| s == symbol && loaded) || self.base.is_loaded(symbol)
+    }
+
+    /// Symbols the loader returned a null pointer for, always empty after `with_loader`.
+    pub fn missing_functions(&self) -> impl Iterator<Item = &'static str> {
+        self.base.missing_functions().chain(
+            self.symbols()
+                .filter_map(|(symbol, loaded)| (!loaded).then_some(symbol)),
+        )
+    }
+
+    #[allow(clippy::too_many_lines)]
+    fn symbols(&self) -> impl Iterator<Item = (&'static str, bool)> {
+        [
+            (
+                "glDrawArraysIndirect",
+                self.draw_arrays_indirect_ptr.is_some(),
+            ),
+            (
+                "glDrawElementsIndirect",
+                self.draw_elements_indirect_ptr.is_some(),
+            ),
+            ("glBindVertexBuffer", self.bind_vertex_buffer_ptr.is_some()),
+            (
+                "glVertexAttribFormat",
+                self.vertex_attrib_format_ptr.is_some(),
+            ),
+            (
+                "glVertexAttribBinding",
+                self.vertex_attrib_binding_ptr.is_some(),
+            ),
+            (
+                "glGetProgramInterfaceiv",
+                self.get_program_interfaceiv_ptr.is_some(),
+            ),
+            ("glDispatchCompute", self.dispatch_compute_ptr.is_some()),
+            (
+                "glDispatchComputeIndirect",
+                self.dispatch_compute_indirect_ptr.is_some(),
+            ),
+            ("glMemoryBarrier", self.memory_barrier_ptr.is_some()),
+        ]
+        .into_iter()
+    }
+
+    // DRAW
+
+    /// `indirect` is a byte offset to a `DrawArraysIndirectCommand` in the bound `DRAW_INDIRECT_BUFFER`.
+    ///
+    /// # Safety
+    /// See the safety note in `with_loader`
+    #[inline]
+    #[cfg_attr(feature = "trace", track_caller)]
+    pub unsafe fn draw_arrays_indirect(&self, mode: Primitive, indirect: *const std::ffi::c_void) {
+        let function = self
+            .draw_arrays_indirect_ptr
+            .unwrap_or_else(|| not_loaded("glDrawArraysIndirect"));
+        #[cfg(feature = "trace")]
+        log::trace!("glDrawArraysIndirect(mode: {mode:?}, indirect: {indirect:?})");
+        unsafe { function(mode, indirect) };
+        #[cfg(feature = "trace")]
+        crate::trace::check("glDrawArraysIndirect", || {
+            unsafe { self.get_error() }.error()
+        });
+    }
+
+    /// `indirect` is a byte offset to a `DrawElementsIndirectCommand` in the bound `DRAW_INDIRECT_BUFFER`.
+    ///
+    /// # Safety
+    /// See the safety note in `with_loader`
+    #[inline]
+    #[cfg_attr(feature = "trace", track_caller)]
+    pub unsafe fn draw_elements_indirect(
+        &self,
+        mode: Primitive,
+        kind: IndexKind,
+        indirect: *const std::ffi::c_void,
+    ) {
+        let function = self
+            .draw_elements_indirect_ptr
+            .unwrap_or_else(|| not_loaded("glDrawElementsIndirect"));
+        #[cfg(feature = "trace")]
+        log::trace!(
+            "glDrawElementsIndirect(mode: {mode:?}, kind: {kind:?}, indirect: {indirect:?})"
+        );
+        unsafe { function(mode, kind, indirect) };
+        #[cfg(feature = "trace")]
+        crate::trace::check("glDrawElementsIndirect", || {
+            unsafe { self.get_error() }.error()
+        });
+    }
+
+    // VERTEX ARRAYS
+
+    /// # Safety
+    /// See the safety note in `with_loader`
+    #[inline]
+    #[cfg_attr(feature = "trace", track_caller)]
+    pub unsafe fn bind_vertex_buffer(
+        &self,
+        bindingindex: GLuint,
+        buffer: Buffer,
+        offset: GLintptr,
+        stride: GLsizei,
+    ) {
+        let function = self
+            .bind_vertex_buffer_ptr
+            .unwrap_or_else(|| not_loaded("glBindVertexBuffer"));
+        #[cfg(feature = "trace")]
+        log::trace!("glBindVertexBuffer(bindingindex: {bindingindex:?}, buffer: {buffer:?}, offset: {offset:?}, stride: {stride:?})");
+        unsafe { function(bindingindex, buffer, offset, stride) };
+        #[cfg(feature = "trace")]
+        crate::trace::check("glBindVertexBuffer", || unsafe { self.get_error() }.error());
+    }
+
+    /// # Safety
+    /// See the safety note in `with_loader`
+    #[inline]
+    #[cfg_attr(feature = "trace", track_caller)]
+    pub unsafe fn vertex_attrib_format(
+        &self,
+        attribindex: GLuint,
+        size: GLint,
+        kind: VertexAttributeKind,
+        normalized: GLboolean,
+        relativeoffset: GLuint,
+    ) {
+        let function = self
+            .vertex_attrib_format_ptr
+            .unwrap_or_else(|| not_loaded("glVertexAttribFormat"));
+        #[cfg(feature = "trace")]
+        log::trace!("glVertexAttribFormat(attribindex: {attribindex:?}, size: {size:?}, kind: {kind:?}, normalized: {normalized:?}, relativeoffset: {relativeoffset:?})");
+        unsafe { function(attribindex, size, kind, normalized, relativeoffset) };
+        #[cfg(feature = "trace")]
+        crate::trace::check("glVertexAttribFormat", || {
+            unsafe { self.get_error() }.error()
+        });
+    }
+
+    /// # Safety
+    /// See the safety note in `with_loader`
+    #[inline]
+    #[cfg_attr(feature = "trace", track_caller)]
+    pub unsafe fn vertex_attrib_binding(&self, attribindex: GLuint, bindingindex: GLuint) {
+        let function = self
+            .vertex_attrib_binding_ptr
+            .unwrap_or_else(|| not_loaded("glVertexAttribBinding"));
+        #[cfg(feature = "trace")]
+        log::trace!(
+            "glVertexAttribBinding(attribindex: {attribindex:?}, bindingindex: {bindingindex:?})"
+        );
+        unsafe { function(attribindex, bindingindex) };
+        #[cfg(feature = "trace")]
+        crate::trace::check("glVertexAttribBinding", || {
+            unsafe { self.get_error() }.error()
+        });
+    }
+
+    // UNIFORMS
+
+    /// # Safety
+    /// See the safety note in `with_loader`
+    #[inline]
+    #[cfg_attr(feature = "trace", track_caller)]
+    pub unsafe fn get_program_interfaceiv(
+        &self,
+        program: Program,
+        program_interface: ProgramInterface,
+        pname: ProgramInterfaceParameter,
+        params: *mut GLint,
+    ) {
+        let function = self
+            .get_program_interfaceiv_ptr
+            .unwrap_or_else(|| not_loaded("glGetProgramInterfaceiv"));
+        #[cfg(feature = "trace")]
+        log::trace!("glGetProgramInterfaceiv(program: {program:?}, program_interface: {program_interface:?}, pname: {pname:?}, params: {params:?})");
+        unsafe { function(program, program_interface, pname, params) };
+        #[cfg(feature = "trace")]
+        crate::trace::check("glGetProgramInterfaceiv", || {
+            unsafe { self.get_error() }.error()
+        });
+    }
+
+    // COMPUTE
+
+    /// Runs the compute shader of the current program in `x * y * z` work groups.
+    ///
+    /// # Safety
+    /// See the safety note in `with_loader`
+    #[inline]
+    #[cfg_attr(feature = "trace", track_caller)]
+    pub unsafe fn dispatch_compute(
+        &self,
+        num_groups_x: GLuint,
+        num_groups_y: GLuint,
+        num_groups_z: GLuint,
+    ) {
+        let function = self
+            .dispatch_compute_ptr
+            .unwrap_or_else(|| not_loaded("glDispatchCompute"));
+        #[cfg(feature = "trace")]
+        log::trace!("glDispatchCompute(num_groups_x: {num_groups_x:?}, num_groups_y: {num_groups_y:?}, num_groups_z: {num_groups_z:?})");
+        unsafe { function(num_groups_x, num_groups_y, num_groups_z) };
+        #[cfg(feature = "trace")]
+        crate::trace::check("glDispatchCompute", || unsafe { self.get_error() }.error());
+    }
+
+    /// `indirect` is a byte offset to a `DispatchIndirectCommand` in the bound `DISPATCH_INDIRECT_BUFFER`.
+    ///
+    /// # Safety
+    /// See the safety note in `with_loader`
+    #[inline]
+    #[cfg_attr(feature = "trace", track_caller)]
+    pub unsafe fn dispatch_compute_indirect(&self, indirect: GLintptr) {
+        let function = self
+            .dispatch_compute_indirect_ptr
+            .unwrap_or_else(|| not_loaded("glDispatchComputeIndirect"));
+        #[cfg(feature = "trace")]
+        log::trace!("glDispatchComputeIndirect(indirect: {indirect:?})");
+        unsafe { function(indirect) };
+        #[cfg(feature = "trace")]
+        crate::trace::check("glDispatchComputeIndirect", || {
+            unsafe { self.get_error() }.error()
+        });
+    }
+
+    /// Makes the writes of earlier shaders visible to the kinds of access in `barriers`.
+    ///
+    /// # Safety
+    /// See the safety note in `with_loader`
+    #[inline]
+    #[cfg_attr(feature = "trace", track_caller)]
+    pub unsafe fn memory_barrier(&self, barriers: BarrierBits) {
+        let function = self
+            .memory_barrier_ptr
+            .unwrap_or_else(|| not_loaded("glMemoryBarrier"));
+        #[cfg(feature = "trace")]
+        log::trace!("glMemoryBarrier(barriers: {barriers:?})");
+        unsafe { function(barriers) };
+        #[cfg(feature = "trace")]
+        crate::trace::check("glMemoryBarrier", || unsafe { self.get_error() }.error());
+    }
+}
@@ -0,0 +1,565 @@
+// This file is generated by `cac_gl_generator` from the Khronos registry, do not edit it by hand.
+// Change `crates/cac_gl_generator/allowlist.toml` and run `cargo run -p cac_gl_generator` instead.
+
+/// Bindings to a curated subset of OpenGL ES 3.2
+///
+/// Everything up to OpenGL ES 3.1 is available through `Deref` to `gles31::Api`.
+#[allow(clippy::struct_field_names)]
+pub struct Api {
+    base: super::gles31::Api,
+
+    debug_handler: DebugRegistration,
+
+    //debug
+    debug_message_callback_ptr: Option<
+        unsafe extern "system" fn(
+            callback: Option<DebugMessageCallback>,
+            user_param: *const std::ffi::c_void,
+        ),
+    >,
+    debug_message_control_ptr: Option<
+        unsafe extern "system" fn(
+            source: DebugSource,
+            kind: DebugType,
+            severity: DebugSeverity,
+            count: GLsizei,
+            ids: *const GLuint,
+            enabled: GLboolean,
+        ),
+    >,
+    debug_message_insert_ptr: Option<
+        unsafe extern "system" fn(
+            source: DebugSource,
+            kind: DebugType,
+            id: GLuint,
+            severity: DebugSeverity,
+            length: GLsizei,
+            buf: *const GLchar,
+        ),
+    >,
+    get_debug_message_log_ptr: Option<
+        unsafe extern "system" fn(
+            count: GLuint,
+            buf_size: GLsizei,
+            sources: *mut DebugSource,
+            types: *mut DebugType,
+            ids: *mut GLuint,
+            severities: *mut DebugSeverity,
+            lengths: *mut GLsizei,
+            message_log: *mut GLchar,
+        ) -> GLuint,
+    >,
+    push_debug_group_ptr: Option<
+        unsafe extern "system" fn(
+            source: DebugSource,
+            id: GLuint,
+            length: GLsizei,
+            message: *const GLchar,
+        ),
+    >,
+    pop_debug_group_ptr: Option<unsafe extern "system" fn()>,
+    object_label_ptr: Option<
+        unsafe extern "system" fn(
+            identifier: ObjectIdentifier,
+            name: GLuint,
+            length: GLsizei,
+            label: *const GLchar,
+        ),
+    >,
+    object_ptr_label_ptr: Option<
+        unsafe extern "system" fn(
+            ptr: *const std::ffi::c_void,
+            length: GLsizei,
+            label: *const GLchar,
+        ),
+    >,
+
+    //state
+    enablei_ptr: Option<unsafe extern "system" fn(target: Capability, index: GLuint)>,
+    disablei_ptr: Option<unsafe extern "system" fn(target: Capability, index: GLuint)>,
+    is_enabledi_ptr:
+        Option<unsafe extern "system" fn(target: Capability, index: GLuint) -> GLboolean>,
+
+    //draw
+    draw_elements_base_vertex_ptr: Option<
+        unsafe extern "system" fn(
+            mode: Primitive,
+            count: GLsizei,
+            kind: IndexKind,
+            indices: *const std::ffi::c_void,
+            basevertex: GLint,
+        ),
+    >,
+    draw_elements_instanced_base_vertex_ptr: Option<
+        unsafe extern "system" fn(
+            mode: Primitive,
+            count: GLsizei,
+            kind: IndexKind,
+            indices: *const std::ffi::c_void,
+            instancecount: GLsizei,
+            basevertex: GLint,
+        ),
+    >,
+}
+
+impl std::ops::Deref for Api {
+    type Target = super::gles31::Api;
+
+    fn deref(&self) -> &super::gles31::Api {
+        &self.base
+    }
+}
+
+#[allow(
+    clippy::let_and_return,
+    clippy::semicolon_if_nothing_returned,
+    clippy::similar_names,
+    clippy::too_many_arguments
+)]
+impl Api {
+    /// Loads all function pointers using a context function loader,
+    /// also called `ProcAddress`. Works with GLFW, SDL2 any pretty much any OpenGL ES context.
+    ///
+    /// # Errors
+    /// This function will return an error if any function pointer returns a null pointer.
+    ///
+    /// # Safety
+    /// Unfortunately, some drivers return wrong addresses that are indistinguishable from correct
+    /// ones, instead of being null pointers.
+    ///
+    #[allow(clippy::too_many_lines)]
+    pub unsafe fn with_loader(
+        loader: &impl Fn(&str) -> *const std::ffi::c_void,
+    ) -> Result<Self, Error> {
+        Ok(Self {
+            base: unsafe { super::gles31::Api::with_loader(loader)? },
+
+            debug_handler: DebugRegistration::default(),
+
+            //debug
+            debug_message_callback_ptr: Some(loader.load("glDebugMessageCallback")?),
+            debug_message_control_ptr: Some(loader.load("glDebugMessageControl")?),
+            debug_message_insert_ptr: Some(loader.load("glDebugMessageInsert")?),
+            get_debug_message_log_ptr: Some(loader.load("glGetDebugMessageLog")?),
+            push_debug_group_ptr: Some(loader.load("glPushDebugGroup")?),
+            pop_debug_group_ptr: Some(loader.load("glPopDebugGroup")?),
+            object_label_ptr: Some(loader.load("glObjectLabel")?),
+            object_ptr_label_ptr: Some(loader.load("glObjectPtrLabel")?),
+
+            //state
+            enablei_ptr: Some(loader.load("glEnablei")?),
+            disablei_ptr: Some(loader.load("glDisablei")?),
+            is_enabledi_ptr: Some(loader.load("glIsEnabledi")?),
+
+            //draw
+            draw_elements_base_vertex_ptr: Some(loader.load("glDrawElementsBaseVertex")?),
+            draw_elements_instanced_base_vertex_ptr: Some(
+                loader.load("glDrawElementsInstancedBaseVertex")?,
+            ),
+        })
+    }
+
+    /// Like `with_loader`, but keeps going when a function pointer is null, e.g. for entry points
+    /// a driver doesn't expose. Use `is_loaded` or `missing_functions` to find out what is
+    /// available, calling a wrapper whose function is missing panics.
+    ///
+    /// # Safety
+    /// See the safety note in `with_loader`
+    #[must_use]
+    #[allow(clippy::too_many_lines)]
+    pub unsafe fn with_loader_partial(loader: &impl Fn(&str) -> *const std::ffi::c_void) -> Self {
+        Self {
+            base: unsafe { super::gles31::Api::with_loader_partial(loader) },
+
+            debug_handler: DebugRegistration::default(),
+
+            //debug
+            debug_message_callback_ptr: loader.load("glDebugMessageCallback").ok(),
+            debug_message_control_ptr: loader.load("glDebugMessageControl").ok(),
+            debug_message_insert_ptr: loader.load("glDebugMessageInsert").ok(),
+            get_debug_message_log_ptr: loader.load("glGetDebugMessageLog").ok(),
+            push_debug_group_ptr: loader.load("glPushDebugGroup").ok(),
+            pop_debug_group_ptr: loader.load("glPopDebugGroup").ok(),
+            object_label_ptr: loader.load("glObjectLabel").ok(),
+            object_ptr_label_ptr: loader.load("glObjectPtrLabel").ok(),
+
+            //state
+            enablei_ptr: loader.load("glEnablei").ok(),
+            disablei_ptr: loader.load("glDisablei").ok(),
+            is_enabledi_ptr: loader.load("glIsEnabledi").ok(),
+
+            //draw
+            draw_elements_base_vertex_ptr: loader.load("glDrawElementsBaseVertex").ok(),
+            draw_elements_instanced_base_vertex_ptr: loader
+                .load("glDrawElementsInstancedBaseVertex")
+                .ok(),
+        }
+    }
+
+    /// Returns true if `symbol`, e.g. `"glDebugMessageCallback"`, is part of this `Api` and was
+    /// found by the loader.
+    #[must_use]
+    pub fn is_loaded(&self, symbol: &str) -> bool {
+        self.symbols().any(|(s, loaded)| s == symbol && loaded) || self.base.is_loaded(symbol)
+    }
+
+    /// Symbols the loader returned a null pointer for, always empty after `with_loader`.
+    pub fn missing_functions(&self) -> impl Iterator<Item = &'static str> {
+        self.base.missing_functions().chain(
+            self.symbols()
+                .filter_map(|(symbol, loaded)| (!loaded).then_some(symbol)),
+        )
+    }
+
+    #[allow(clippy::too_many_lines)]
+    fn symbols(&self) -> impl Iterator<Item = (&'static str, bool)> {
+        [
+            (
+                "glDebugMessageCallback",
+                self.debug_message_callback_ptr.is_some(),
+            ),
+            (
+                "glDebugMessageControl",
+                self.debug_message_control_ptr.is_some(),
+            ),
+            (
+                "glDebugMessageInsert",
+                self.debug_message_insert_ptr.is_some(),
+            ),
+            (
+                "glGetDebugMessageLog",
+                self.get_debug_message_log_ptr.is_some(),
+            ),
+            ("glPushDebugGroup", self.push_debug_group_ptr.is_some()),
+            ("glPopDebugGroup", self.pop_debug_group_ptr.is_some()),
+            ("glObjectLabel", self.object_label_ptr.is_some()),
+            ("glObjectPtrLabel", self.object_ptr_label_ptr.is_some()),
+            ("glEnablei", self.enablei_ptr.is_some()),
+            ("glDisablei", self.disablei_ptr.is_some()),
+            ("glIsEnabledi", self.is_enabledi_ptr.is_some()),
+            (
+                "glDrawElementsBaseVertex",
+                self.draw_elements_base_vertex_ptr.is_some(),
+            ),
+            (
+                "glDrawElementsInstancedBaseVertex",
+                self.draw_elements_instanced_base_vertex_ptr.is_some(),
+            ),
+        ]
+        .into_iter()
+    }
+
+    // DEBUG
+
+    /// Set the debug message callback.
+    /// Make sure to `enable` `Capability::DEBUG_OUTPUT` and to use a debug context.
+    /// `set_debug_handler` takes a closure instead.
+    ///
+    /// # Safety
+    /// See the safety note in `with_loader`
+    #[inline]
+    #[cfg_attr(feature = "trace", track_caller)]
+    pub unsafe fn debug_message_callback(
+        &self,
+        callback: Option<DebugMessageCallback>,
+        user_param: *const std::ffi::c_void,
+    ) {
+        let function = self
+            .debug_message_callback_ptr
+            .unwrap_or_else(|| not_loaded("glDebugMessageCallback"));
+        #[cfg(feature = "trace")]
+        log::trace!("glDebugMessageCallback(callback: {callback:?}, user_param: {user_param:?})");
+        unsafe { function(callback, user_param) };
+        #[cfg(feature = "trace")]
+        crate::trace::check("glDebugMessageCallback", || {
+            unsafe { self.get_error() }.error()
+        });
+    }
+
+    /// Enables or disables the messages matching `source`, `kind` and `severity`, `DONT_CARE` matches any.
+    /// With `count` ids, only those messages are affected, which requires `severity` to be `DONT_CARE`.
+    ///
+    /// # Safety
+    /// See the safety note in `with_loader`
+    #[inline]
+    #[cfg_attr(feature = "trace", track_caller)]
+    pub unsafe fn debug_message_control(
+        &self,
+        source: DebugSource,
+        kind: DebugType,
+        severity: DebugSeverity,
+        count: GLsizei,
+        ids: *const GLuint,
+        enabled: GLboolean,
+    ) {
+        let function = self
+            .debug_message_control_ptr
+            .unwrap_or_else(|| not_loaded("glDebugMessageControl"));
+        #[cfg(feature = "trace")]
+        log::trace!("glDebugMessageControl(source: {source:?}, kind: {kind:?}, severity: {severity:?}, count: {count:?}, ids: {ids:?}, enabled: {enabled:?})");
+        unsafe { function(source, kind, severity, count, ids, enabled) };
+        #[cfg(feature = "trace")]
+        crate::trace::check("glDebugMessageControl", || {
+            unsafe { self.get_error() }.error()
+        });
+    }
+
+    /// Sends a message of `source` `APPLICATION` or `THIRD_PARTY` through the debug output.
+    ///
+    /// # Safety
+    /// See the safety note in `with_loader`
+    #[inline]
+    #[cfg_attr(feature = "trace", track_caller)]
+    pub unsafe fn debug_message_insert(
+        &self,
+        source: DebugSource,
+        kind: DebugType,
+        id: GLuint,
+        severity: DebugSeverity,
+        length: GLsizei,
+        buf: *const GLchar,
+    ) {
+        let function = self
+            .debug_message_insert_ptr
+            .unwrap_or_else(|| not_loaded("glDebugMessageInsert"));
+        #[cfg(feature = "trace")]
+        log::trace!("glDebugMessageInsert(source: {source:?}, kind: {kind:?}, id: {id:?}, severity: {severity:?}, length: {length:?}, buf: {buf:?})");
+        unsafe { function(source, kind, id, severity, length, buf) };
+        #[cfg(feature = "trace")]
+        crate::trace::check("glDebugMessageInsert", || {
+            unsafe { self.get_error() }.error()
+        });
+    }
+
+    /// Moves up to `count` messages out of the log, returning how many were written.
+    /// Messages are only logged while no callback is set, `debug_message_log` drains them one by one.
+    ///
+    /// # Safety
+    /// See the safety note in `with_loader`
+    #[inline]
+    #[cfg_attr(feature = "trace", track_caller)]
+    #[must_use]
+    pub unsafe fn get_debug_message_log(
+        &self,
+        count: GLuint,
+        buf_size: GLsizei,
+        sources: *mut DebugSource,
+        types: *mut DebugType,
+        ids: *mut GLuint,
+        severities: *mut DebugSeverity,
+        lengths: *mut GLsizei,
+        message_log: *mut GLchar,
+    ) -> GLuint {
+        let function = self
+            .get_debug_message_log_ptr
+            .unwrap_or_else(|| not_loaded("glGetDebugMessageLog"));
+        #[cfg(feature = "trace")]
+        log::trace!("glGetDebugMessageLog(count: {count:?}, buf_size: {buf_size:?}, sources: {sources:?}, types: {types:?}, ids: {ids:?}, severities: {severities:?}, lengths: {lengths:?}, message_log: {message_log:?})");
+        let result = unsafe {
+            function(
+                count,
+                buf_size,
+                sources,
+                types,
+                ids,
+                severities,
+                lengths,
+                message_log,
+            )
+        };
+        #[cfg(feature = "trace")]
+        crate::trace::check("glGetDebugMessageLog", || {
+            unsafe { self.get_error() }.error()
+        });
+        result
+    }
+
+    /// Starts a named group of commands, shown by graphics debuggers.
+    /// Also sends a `PUSH_GROUP` message through the debug output.
+    ///
+    /// # Safety
+    /// See the safety note in `with_loader`
+    #[inline]
+    #[cfg_attr(feature = "trace", track_caller)]
+    pub unsafe fn push_debug_group(
+        &self,
+        source: DebugSource,
+        id: GLuint,
+        length: GLsizei,
+        message: *const GLchar,
+    ) {
+        let function = self
+            .push_debug_group_ptr
+            .unwrap_or_else(|| not_loaded("glPushDebugGroup"));
+        #[cfg(feature = "trace")]
+        log::trace!("glPushDebugGroup(source: {source:?}, id: {id:?}, length: {length:?}, message: {message:?})");
+        unsafe { function(source, id, length, message) };
+        #[cfg(feature = "trace")]
+        crate::trace::check("glPushDebugGroup", || unsafe { self.get_error() }.error());
+    }
+
+    /// Ends the group of the last `push_debug_group`.
+    ///
+    /// # Safety
+    /// See the safety note in `with_loader`
+    #[inline]
+    #[cfg_attr(feature = "trace", track_caller)]
+    pub unsafe fn pop_debug_group(&self) {
+        let function = self
+            .pop_debug_group_ptr
+            .unwrap_or_else(|| not_loaded("glPopDebugGroup"));
+        #[cfg(feature = "trace")]
+        log::trace!("glPopDebugGroup()");
+        unsafe { function() };
+        #[cfg(feature = "trace")]
+        crate::trace::check("glPopDebugGroup", || unsafe { self.get_error() }.error());
+    }
+
+    /// Names object `name` of kind `identifier` in debug messages and tools.
+    /// `label_object` does the same for typed handles.
+    ///
+    /// # Safety
+    /// See the safety note in `with_loader`
+    #[inline]
+    #[cfg_attr(feature = "trace", track_caller)]
+    pub unsafe fn object_label(
+        &self,
+        identifier: ObjectIdentifier,
+        name: GLuint,
+        length: GLsizei,
+        label: *const GLchar,
+    ) {
+        let function = self
+            .object_label_ptr
+            .unwrap_or_else(|| not_loaded("glObjectLabel"));
+        #[cfg(feature = "trace")]
+        log::trace!("glObjectLabel(identifier: {identifier:?}, name: {name:?}, length: {length:?}, label: {label:?})");
+        unsafe { function(identifier, name, length, label) };
+        #[cfg(feature = "trace")]
+        crate::trace::check("glObjectLabel", || unsafe { self.get_error() }.error());
+    }
+
+    /// Names a sync object in debug messages and tools.
+    ///
+    /// # Safety
+    /// See the safety note in `with_loader`
+    #[inline]
+    #[cfg_attr(feature = "trace", track_caller)]
+    pub unsafe fn object_ptr_label(
+        &self,
+        ptr: *const std::ffi::c_void,
+        length: GLsizei,
+        label: *const GLchar,
+    ) {
+        let function = self
+            .object_ptr_label_ptr
+            .unwrap_or_else(|| not_loaded("glObjectPtrLabel"));
+        #[cfg(feature = "trace")]
+        log::trace!("glObjectPtrLabel(ptr: {ptr:?}, length: {length:?}, label: {label:?})");
+        unsafe { function(ptr, length, label) };
+        #[cfg(feature = "trace")]
+        crate::trace::check("glObjectPtrLabel", || unsafe { self.get_error() }.error());
+    }
+
+    // STATE
+
+    /// Enables `target` for one draw buffer or viewport, e.g. `BLEND` or `SCISSOR_TEST`.
+    ///
+    /// # Safety
+    /// See the safety note in `with_loader`
+    #[inline]
+    #[cfg_attr(feature = "trace", track_caller)]
+    pub unsafe fn enablei(&self, target: Capability, index: GLuint) {
+        let function = self.enablei_ptr.unwrap_or_else(|| not_loaded("glEnablei"));
+        #[cfg(feature = "trace")]
+        log::trace!("glEnablei(target: {target:?}, index: {index:?})");
+        unsafe { function(target, index) };
+        #[cfg(feature = "trace")]
+        crate::trace::check("glEnablei", || unsafe { self.get_error() }.error());
+    }
+
+    /// # Safety
+    /// See the safety note in `with_loader`
+    #[inline]
+    #[cfg_attr(feature = "trace", track_caller)]
+    pub unsafe fn disablei(&self, target: Capability, index: GLuint) {
+        let function = self
+            .disablei_ptr
+            .unwrap_or_else(|| not_loaded("glDisablei"));
+        #[cfg(feature = "trace")]
+        log::trace!("glDisablei(target: {target:?}, index: {index:?})");
+        unsafe { function(target, index) };
+        #[cfg(feature = "trace")]
+        crate::trace::check("glDisablei", || unsafe { self.get_error() }.error());
+    }
+
+    /// # Safety
+    /// See the safety note in `with_loader`
+    #[inline]
+    #[cfg_attr(feature = "trace", track_caller)]
+    #[must_use]
+    pub unsafe fn is_enabledi(&self, target: Capability, index: GLuint) -> GLboolean {
+        let function = self
+            .is_enabledi_ptr
+            .unwrap_or_else(|| not_loaded("glIsEnabledi"));
+        #[cfg(feature = "trace")]
+        log::trace!("glIsEnabledi(target: {target:?}, index: {index:?})");
+        let result = unsafe { function(target, index) };
+        #[cfg(feature = "trace")]
+        crate::trace::check("glIsEnabledi", || unsafe { self.get_error() }.error());
+        result
+    }
+
+    // DRAW
+
+    /// Like `draw_elements`, but `basevertex` is added to every index before fetching the vertex.
+    ///
+    /// # Safety
+    /// See the safety note in `with_loader`
+    #[inline]
+    #[cfg_attr(feature = "trace", track_caller)]
+    pub unsafe fn draw_elements_base_vertex(
+        &self,
+        mode: Primitive,
+        count: GLsizei,
+        kind: IndexKind,
+        indices: *const std::ffi::c_void,
+        basevertex: GLint,
+    ) {
+        let function = self
+            .draw_elements_base_vertex_ptr
+            .unwrap_or_else(|| not_loaded("glDrawElementsBaseVertex"));
+        #[cfg(feature = "trace")]
+        log::trace!("glDrawElementsBaseVertex(mode: {mode:?}, count: {count:?}, kind: {kind:?}, indices: {indices:?}, basevertex: {basevertex:?})");
+        unsafe { function(mode, count, kind, indices, basevertex) };
+        #[cfg(feature = "trace")]
+        crate::trace::check("glDrawElementsBaseVertex", || {
+            unsafe { self.get_error() }.error()
+        });
+    }
+
+    /// # Safety
+    /// See the safety note in `with_loader`
+    #[inline]
+    #[cfg_attr(feature = "trace", track_caller)]
+    pub unsafe fn draw_elements_instanced_base_vertex(
+        &self,
+        mode: Primitive,
+        count: GLsizei,
+        kind: IndexKind,
+        indices: *const std::ffi::c_void,
+        instancecount: GLsizei,
+        basevertex: GLint,
+    ) {
+        let function = self
+            .draw_elements_instanced_base_vertex_ptr
+            .unwrap_or_else(|| not_loaded("glDrawElementsInstancedBaseVertex"));
+        #[cfg(feature = "trace")]
+        log::trace!("glDrawElementsInstancedBaseVertex(mode: {mode:?}, count: {count:?}, kind: {kind:?}, indices: {indices:?}, instancecount: {instancecount:?}, basevertex: {basevertex:?})");
+        unsafe { function(mode, count, kind, indices, instancecount, basevertex) };
+        #[cfg(feature = "trace")]
+        crate::trace::check("glDrawElementsInstancedBaseVertex", || {
+            unsafe { self.get_error() }.error()
+        });
+    }
+}
@@ -0,0 +1,165 @@
+use std::fmt::Display;
+
+/// A failed `check_compile_status` or `check_link_status`, with the info log of the object.
+#[derive(Debug, Clone)]
+pub struct ShaderCompileError {
+    /// The info log exactly as the driver returned it.
+    pub log: String,
+    /// One entry per non-empty line of `log`.
+    pub entries: Vec<LogEntry>,
+}
+
+/// One diagnostic of an info log. `line` and `column` are only set if the driver's format was
+/// recognized, `message` then holds the rest of the line, otherwise the whole line.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct LogEntry {
+    pub line: Option<u32>,
+    pub column: Option<u32>,
+    pub message: String,
+}
+
+impl ShaderCompileError {
+    #[must_use]
+    pub fn from_log(log: String) -> Self {
+        let entries = log
+            .lines()
+            .map(str::trim)
+            .filter(|line| !line.is_empty())
+            .map(LogEntry::parse)
+            .collect();
+
+        Self { log, entries }
+    }
+}
+
+impl LogEntry {
+    /// Understands the formats of the common drivers:
+    /// - Mesa: `0:12(5): error: message`
+    /// - NVIDIA: `0(12) : error C1008: message`
+    /// - AMD, Intel on Windows and Apple: `ERROR: 0:12: message`
+    #[must_use]
+    pub fn parse(line: &str) -> Self {
+        let (severity, rest) = [("ERROR", "error"), ("WARNING", "warning")]
+            .into_iter()
+            .find_map(|(prefix, severity)| {
+                let rest = line.strip_prefix(prefix)?.strip_prefix(':')?;
+                Some((Some(severity), rest.trim_start()))
+            })
+            .unwrap_or((None, line));
+
+        let Some((line_number, column, message)) = location(rest) else {
+            return Self {
+                line: None,
+                column: None,
+                message: line.to_string(),
+            };
+        };
+
+        let message = message.trim();
+        Self {
+            line: Some(line_number),
+            column,
+            // keep the severity in the message like Mesa and NVIDIA do
+            message: severity.map_or_else(
+                || message.to_string(),
+                |severity| format!("{severity}: {message}"),
+            ),
+        }
+    }
+}
+
+/// Splits `0:12(5):`, `0:12:` or `0(12) :` off the start of `s`, the leading source string
+/// index is dropped as it is `0` unless several strings were passed to `shader_source`.
+fn location(s: &str) -> Option<(u32, Option<u32>, &str)> {
+    let (_, rest) = number(s)?;
+
+    if let Some(rest) = rest.strip_prefix('(') {
+        // NVIDIA
+        let (line, rest) = number(rest)?;
+        let rest = rest.strip_prefix(')')?.trim_start().strip_prefix(':')?;
+        return Some((line, None, rest));
+    }
+
+    let (line, rest) = number(rest.strip_prefix(':')?)?;
+    let (column, rest) = match rest.strip_prefix('(') {
+        // Mesa
+        Some(rest) => {
+            let (column, rest) = number(rest)?;
+            (Some(column), rest.strip_prefix(')')?)
+        }
+        None => (None, rest),
+    };
+    Some((line, column, rest.strip_prefix(':')?))
+}
+
+fn number(s: &str) -> Option<(u32, &str)> {
+    let end = s.find(|c: char| !c.is_ascii_digit()).unwrap_or(s.len());
+    Some((s[..end].parse().ok()?, &s[end..]))
+}
+
+impl std::error::Error for ShaderCompileError {}
+
+impl Display for ShaderCompileError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for (i, entry) in self.entries.iter().enumerate() {
+            if i > 0 {
+                writeln!(f)?;
+            }
+            write!(f, "{entry}")?;
+        }
+        Ok(())
+    }
+}
+
+impl Display for LogEntry {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match (self.line, self.column) {
+            (Some(line), Some(column)) => write!(f, "{line}:{column}: {}", self.message),
+            (Some(line), None) => write!(f, "{line}: {}", self.message),
+            _ => write!(f, "{}", self.message),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(line: Option<u32>, column: Option<u32>, message: &str) -> LogEntry {
+        LogEntry {
+            line,
+            column,
+            message: message.to_string(),
+        }
+    }
+
+    #[test]
+    fn parses_mesa() {
+        assert_eq!(
+            LogEntry::parse("0:12(5): error: `x' undeclared"),
+            entry(Some(12), Some(5), "error: `x' undeclared")
+        );
+    }
+
+    #[test]
+    fn parses_nvidia() {
+        assert_eq!(
+            LogEntry::parse("0(12) : error C1008: undefined variable \"x\""),
+            entry(Some(12), None, "error C1008: undefined variable \"x\"")
+        );
+    }
+
+    #[test]
+    fn parses_amd_and_angle() {
+        assert_eq!(
+            LogEntry::parse("ERROR: 0:12: 'x' : undeclared identifier"),
+            entry(Some(12), None, "error: 'x' : undeclared identifier")
+        );
+    }
+
+    #[test]
+    fn keeps_unrecognized_lines() {
+        let line = "ERROR: 1 compilation errors.  No code generated.";
+        assert_eq!(LogEntry::parse(line), entry(None, None, line));
+    }
+}
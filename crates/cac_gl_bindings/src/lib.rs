@@ -6,6 +6,7 @@ pub mod types {
     pub type GLboolean = std::ffi::c_uchar;
     pub type GLbyte = std::ffi::c_char;
     pub type GLchar = std::ffi::c_char;
+    pub type GLdouble = std::ffi::c_double;
     pub type GLenum = std::ffi::c_uint;
     pub type GLfixed = GLint;
     pub type GLfloat = std::ffi::c_float;
@@ -17,11 +18,158 @@ pub mod types {
     pub type GLuint = std::ffi::c_uint;
 }
 
+mod info_log;
+pub use info_log::{LogEntry, ShaderCompileError};
+
+mod gl;
+pub mod gl33;
 pub mod gl43;
+pub mod gl45;
+pub mod gl46;
+
+mod gles;
+pub mod gles30;
+pub mod gles31;
+pub mod gles32;
+
+pub mod safe;
+
+#[cfg(feature = "testing")]
+pub mod testing;
+
+#[cfg(feature = "trace")]
+pub mod trace;
+
+// An `Api` may be loaded on one thread and used on another that the context is made current on,
+// state of the hand-written code has to keep it `Send` and `Sync`.
+const _: () = {
+    const fn assert_send_sync<T: Send + Sync>() {}
+    assert_send_sync::<gl33::Api>();
+    assert_send_sync::<gl43::Api>();
+    assert_send_sync::<gl45::Api>();
+    assert_send_sync::<gl46::Api>();
+    assert_send_sync::<gles30::Api>();
+    assert_send_sync::<gles31::Api>();
+    assert_send_sync::<gles32::Api>();
+};
+
+/// The highest desktop `Api` a context supports, for code that picks its tier at runtime.
+/// Every tier dereferences to the ones below it, so `gl33` is always available.
+pub enum Tier {
+    Gl33(gl33::Api),
+    Gl43(gl43::Api),
+    Gl45(gl45::Api),
+    Gl46(gl46::Api),
+}
+
+impl Tier {
+    /// Loads the highest tier a context of version `major.minor` supports, e.g. the one reported
+    /// by the windowing library after context creation.
+    ///
+    /// # Errors
+    /// `Error::UnsupportedVersion` for contexts older than 3.3, otherwise see `gl33::Api::with_loader`.
+    ///
+    /// # Safety
+    /// See the safety note in `gl33::Api::with_loader`
+    pub unsafe fn with_loader(
+        major: u32,
+        minor: u32,
+        loader: &impl Fn(&str) -> *const std::ffi::c_void,
+    ) -> Result<Self, Error> {
+        unsafe {
+            Ok(match (major, minor) {
+                (5.., _) | (4, 6..) => Self::Gl46(gl46::Api::with_loader(loader)?),
+                (4, 5) => Self::Gl45(gl45::Api::with_loader(loader)?),
+                (4, 3..) => Self::Gl43(gl43::Api::with_loader(loader)?),
+                (4, _) | (3, 3..) => Self::Gl33(gl33::Api::with_loader(loader)?),
+                _ => return Err(Error::UnsupportedVersion(major, minor)),
+            })
+        }
+    }
+
+    #[must_use]
+    pub fn gl33(&self) -> &gl33::Api {
+        match self {
+            Self::Gl33(api) => api,
+            Self::Gl43(api) => api,
+            Self::Gl45(api) => api,
+            Self::Gl46(api) => api,
+        }
+    }
+
+    #[must_use]
+    pub fn gl43(&self) -> Option<&gl43::Api> {
+        match self {
+            Self::Gl33(_) => None,
+            Self::Gl43(api) => Some(api),
+            Self::Gl45(api) => Some(api),
+            Self::Gl46(api) => Some(api),
+        }
+    }
+
+    #[must_use]
+    pub fn gl45(&self) -> Option<&gl45::Api> {
+        match self {
+            Self::Gl33(_) | Self::Gl43(_) => None,
+            Self::Gl45(api) => Some(api),
+            Self::Gl46(api) => Some(api),
+        }
+    }
+
+    #[must_use]
+    pub const fn gl46(&self) -> Option<&gl46::Api> {
+        match self {
+            Self::Gl46(api) => Some(api),
+            _ => None,
+        }
+    }
+}
 
 #[derive(Debug)]
 pub enum Error {
     FailedToLoad(String),
+    UnsupportedVersion(u32, u32),
+    /// The flags `check_errors` cleared, in the order `get_error` returned them.
+    Gl(Vec<GlError>),
+    Compile(ShaderCompileError),
+    Link(ShaderCompileError),
+    /// The raw `FramebufferStatus` of a framebuffer that can't be rendered to, see
+    /// `check_framebuffer_complete`.
+    IncompleteFramebuffer(types::GLenum),
+}
+
+/// An error flag of `get_error`, the same for every family. See `ErrorCode::error`.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum GlError {
+    InvalidEnum,
+    InvalidValue,
+    InvalidOperation,
+    StackOverflow,
+    StackUnderflow,
+    OutOfMemory,
+    InvalidFramebufferOperation,
+    /// The context was lost, e.g. by a GPU reset, every call after this fails.
+    ContextLost,
+    /// A code this crate doesn't know.
+    Other(types::GLenum),
+}
+
+impl GlError {
+    /// The error of a `glGetError` code, `None` for `GL_NO_ERROR`.
+    const fn from_code(code: types::GLenum) -> Option<Self> {
+        Some(match code {
+            0 => return None,
+            0x0500 => Self::InvalidEnum,
+            0x0501 => Self::InvalidValue,
+            0x0502 => Self::InvalidOperation,
+            0x0503 => Self::StackOverflow,
+            0x0504 => Self::StackUnderflow,
+            0x0505 => Self::OutOfMemory,
+            0x0506 => Self::InvalidFramebufferOperation,
+            0x0507 => Self::ContextLost,
+            other => Self::Other(other),
+        })
+    }
 }
 
 trait Loader {
@@ -43,12 +191,69 @@ where
     }
 }
 
-impl std::error::Error for Error {}
+/// Called by wrappers whose function pointer was not found by `with_loader_partial`.
+#[cold]
+#[inline(never)]
+fn not_loaded(symbol: &str) -> ! {
+    panic!("{symbol} was not loaded, check `Api::missing_functions` before calling it")
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Compile(e) | Self::Link(e) => Some(e),
+            _ => None,
+        }
+    }
+}
 
 impl Display for Error {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
             Self::FailedToLoad(s) => write!(f, "function pointer for {s} not found"),
+            Self::UnsupportedVersion(major, minor) => {
+                write!(
+                    f,
+                    "OpenGL {major}.{minor} is not supported, 3.3 is the minimum"
+                )
+            }
+            Self::Gl(errors) => {
+                write!(f, "GL reported ")?;
+                for (i, error) in errors.iter().enumerate() {
+                    if i > 0 {
+                        write!(f, ", ")?;
+                    }
+                    write!(f, "{error}")?;
+                }
+                Ok(())
+            }
+            Self::Compile(e) => write!(f, "failed to compile shader:\n{e}"),
+            Self::Link(e) => write!(f, "failed to link program:\n{e}"),
+            Self::IncompleteFramebuffer(status) => {
+                // both families name a status the same, ES just knows a few more
+                let name = gl::FramebufferStatus::name_of(*status)
+                    .or_else(|| gles::FramebufferStatus::name_of(*status));
+                match name {
+                    Some(name) => write!(f, "framebuffer is incomplete: {name}"),
+                    None => write!(f, "framebuffer is incomplete: {status:#06X}"),
+                }
+            }
+        }
+    }
+}
+
+impl Display for GlError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::InvalidEnum => write!(f, "GL_INVALID_ENUM"),
+            Self::InvalidValue => write!(f, "GL_INVALID_VALUE"),
+            Self::InvalidOperation => write!(f, "GL_INVALID_OPERATION"),
+            Self::StackOverflow => write!(f, "GL_STACK_OVERFLOW"),
+            Self::StackUnderflow => write!(f, "GL_STACK_UNDERFLOW"),
+            Self::OutOfMemory => write!(f, "GL_OUT_OF_MEMORY"),
+            Self::InvalidFramebufferOperation => write!(f, "GL_INVALID_FRAMEBUFFER_OPERATION"),
+            Self::ContextLost => write!(f, "GL_CONTEXT_LOST"),
+            Self::Other(code) => write!(f, "{code:#06X}"),
         }
     }
 }
@@ -0,0 +1,434 @@
+use std::cell::Cell;
+use std::ops::{Deref, DerefMut, Range};
+use std::rc::Rc;
+
+use super::{gl, Context};
+use crate::types::{GLintptr, GLsizeiptr};
+
+pub struct Buffer {
+    context: Context,
+    raw: gl::Buffer,
+    /// Shared with the vertex arrays sourcing from this buffer, for their bounds checks.
+    size: Rc<Cell<usize>>,
+}
+
+/// Types that are valid for every bit pattern, so they can be read back from buffer memory.
+///
+/// # Safety
+/// Every bit pattern of `size_of::<Self>()` bytes must be a valid `Self`, so no `bool`, `char`,
+/// enums, references or `NonZero` types, not even as fields.
+pub unsafe trait Plain: Copy {}
+
+macro_rules! plain {
+    ($($ty:ty),* $(,)?) => {$(
+        unsafe impl Plain for $ty {}
+    )*};
+}
+
+plain!(u8, u16, u32, u64, i8, i16, i32, i64, f32, f64);
+
+unsafe impl<T: Plain, const N: usize> Plain for [T; N] {}
+
+/// A mapped range of a `Buffer`, see `Buffer::map`. Unmaps the buffer when dropped.
+#[must_use = "the buffer is unmapped as soon as the guard is dropped"]
+pub struct Mapping<'a, T: Plain> {
+    buffer: &'a Buffer,
+    target: gl::BufferTarget,
+    access: gl::MapAccess,
+    data: *mut T,
+    len: usize,
+}
+
+impl Buffer {
+    #[must_use]
+    pub fn new(context: &Context) -> Self {
+        let [buffer] = context.gen_buffers();
+        buffer
+    }
+
+    pub(super) fn from_raw(context: Context, raw: gl::Buffer) -> Self {
+        Self {
+            context,
+            raw,
+            size: Rc::default(),
+        }
+    }
+
+    #[must_use]
+    pub const fn raw(&self) -> gl::Buffer {
+        self.raw
+    }
+
+    /// The size of the storage in bytes, as of the last `data`.
+    #[must_use]
+    pub fn size(&self) -> usize {
+        self.size.get()
+    }
+
+    pub(super) fn shared_size(&self) -> Rc<Cell<usize>> {
+        Rc::clone(&self.size)
+    }
+
+    /// Names the buffer in debug messages and graphics debuggers.
+    pub fn set_label(&self, label: &str) {
+        // the copy target doesn't disturb any draw state
+        let bind = || self.bind(gl::BufferTarget::COPY_WRITE_BUFFER);
+        self.context.label_generated(self.raw, label, bind);
+    }
+
+    pub fn bind(&self, target: gl::BufferTarget) {
+        self.context.bind_buffer(target, self.raw);
+    }
+
+    /// Binds the buffer to `target` and replaces its storage with a copy of `data`.
+    pub fn data<T: Copy>(&self, target: gl::BufferTarget, data: &[T], usage: gl::BufferUsage) {
+        let bytes = std::mem::size_of_val(data);
+        // slices are at most isize::MAX bytes
+        #[allow(clippy::cast_possible_wrap)]
+        let size = bytes as GLsizeiptr;
+
+        self.bind(target);
+        unsafe {
+            self.context
+                .api()
+                .buffer_data(target, size, data.as_ptr().cast(), usage);
+        }
+        self.size.set(bytes);
+    }
+
+    /// Binds the buffer to `target` and overwrites the storage at `offset` bytes with `data`,
+    /// without reallocating it like `data` does.
+    ///
+    /// # Panics
+    /// If `data` doesn't fit into the storage at `offset`.
+    pub fn sub_data<T: Copy>(&self, target: gl::BufferTarget, offset: usize, data: &[T]) {
+        let (offset, size) = self.range(offset, std::mem::size_of_val(data));
+
+        self.bind(target);
+        unsafe {
+            self.context
+                .api()
+                .buffer_sub_data(target, offset, size, data.as_ptr().cast());
+        }
+    }
+
+    /// Binds the buffer to `target` and copies the storage at `offset` bytes into `data`. This
+    /// waits for every command writing the buffer to finish.
+    ///
+    /// # Panics
+    /// If the storage holds less than `data` at `offset`.
+    pub fn read<T: Plain>(&self, target: gl::BufferTarget, offset: usize, data: &mut [T]) {
+        let (offset, size) = self.range(offset, std::mem::size_of_val(data));
+
+        self.bind(target);
+        unsafe {
+            self.context
+                .api()
+                .get_buffer_sub_data(target, offset, size, data.as_mut_ptr().cast());
+        }
+    }
+
+    /// Copies `size` bytes of `source` at `read_offset` to `write_offset` of this buffer. Both
+    /// are bound to `COPY_READ_BUFFER` and `COPY_WRITE_BUFFER`, which draw calls don't use.
+    ///
+    /// # Panics
+    /// If either range exceeds its buffer, or they overlap within the same buffer.
+    pub fn copy_from(&self, source: &Self, read_offset: usize, write_offset: usize, size: usize) {
+        let (read_offset, _) = source.range(read_offset, size);
+        let (write_offset, size) = self.range(write_offset, size);
+        assert!(
+            self.raw != source.raw
+                || read_offset + size <= write_offset
+                || write_offset + size <= read_offset,
+            "the ranges of a copy within one buffer must not overlap"
+        );
+
+        source.bind(gl::BufferTarget::COPY_READ_BUFFER);
+        self.bind(gl::BufferTarget::COPY_WRITE_BUFFER);
+        unsafe {
+            self.context.api().copy_buffer_sub_data(
+                gl::BufferTarget::COPY_READ_BUFFER,
+                gl::BufferTarget::COPY_WRITE_BUFFER,
+                read_offset,
+                write_offset,
+                size,
+            );
+        }
+    }
+
+    /// Binds the buffer to `target` and maps `len` elements starting at `offset` bytes.
+    ///
+    /// `access` needs `READ` to read the elements and `WRITE` to change them, the guard panics
+    /// otherwise. With `FLUSH_EXPLICIT` only the ranges passed to `Mapping::flush` are written
+    /// back. The buffer stays mapped until the guard is dropped, which also keeps it from being
+    /// changed otherwise.
+    ///
+    /// Returns `None` if GL refused to map the range, e.g. for a `len` of 0.
+    ///
+    /// # Panics
+    /// If the range exceeds the storage, or `offset` isn't aligned for `T`. Also for
+    /// `PERSISTENT` and `UNSYNCHRONIZED`, which let GL change the elements behind the borrow.
+    pub fn map<T: Plain>(
+        &mut self,
+        target: gl::BufferTarget,
+        offset: usize,
+        len: usize,
+        access: gl::MapAccess,
+    ) -> Option<Mapping<'_, T>> {
+        assert!(
+            !access.contains(gl::MapAccess::PERSISTENT)
+                && !access.contains(gl::MapAccess::UNSYNCHRONIZED),
+            "PERSISTENT and UNSYNCHRONIZED mappings can't be borrowed safely"
+        );
+        // GL aligns the start of the storage to at least 64 bytes
+        assert!(
+            offset.is_multiple_of(std::mem::align_of::<T>()),
+            "offset {offset} isn't aligned for the mapped type"
+        );
+        let bytes = len
+            .checked_mul(std::mem::size_of::<T>())
+            .expect("the mapped range overflows usize");
+        let (raw_offset, length) = self.range(offset, bytes);
+
+        self.bind(target);
+        let data = unsafe {
+            self.context
+                .api()
+                .map_buffer_range(target, raw_offset, length, access)
+        };
+        if data.is_null() {
+            return None;
+        }
+
+        Some(Mapping {
+            buffer: self,
+            target,
+            access,
+            data: data.cast(),
+            len,
+        })
+    }
+
+    /// Checks that `bytes` bytes at `offset` lie within the storage, and converts them for GL.
+    fn range(&self, offset: usize, bytes: usize) -> (GLintptr, GLsizeiptr) {
+        let size = self.size();
+        assert!(
+            offset.checked_add(bytes).is_some_and(|end| end <= size),
+            "{bytes} bytes at offset {offset} exceed the buffer size of {size}"
+        );
+        // both lie within the storage, which `data` limits to isize::MAX bytes
+        #[allow(clippy::cast_possible_wrap)]
+        (offset as GLintptr, bytes as GLsizeiptr)
+    }
+}
+
+impl<T: Plain> Mapping<'_, T> {
+    /// The number of mapped elements, also for mappings without `READ`.
+    #[must_use]
+    pub const fn len(&self) -> usize {
+        self.len
+    }
+
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Writes the elements in `range` back to the buffer, for mappings with `FLUSH_EXPLICIT`.
+    ///
+    /// # Panics
+    /// If the buffer wasn't mapped with `MapAccess::FLUSH_EXPLICIT`, or `range` exceeds the
+    /// mapping.
+    pub fn flush(&self, range: Range<usize>) {
+        assert!(
+            self.access.contains(gl::MapAccess::FLUSH_EXPLICIT),
+            "the buffer was mapped without MapAccess::FLUSH_EXPLICIT"
+        );
+        let len = range
+            .end
+            .checked_sub(range.start)
+            .filter(|_| range.end <= self.len)
+            .unwrap_or_else(|| panic!("{range:?} exceeds the {} mapped elements", self.len));
+        let size = std::mem::size_of::<T>();
+        // within the mapping, which `Buffer::range` checked
+        #[allow(clippy::cast_possible_wrap)]
+        let (offset, length) = ((range.start * size) as GLintptr, (len * size) as GLsizeiptr);
+
+        self.buffer.bind(self.target);
+        unsafe {
+            self.buffer
+                .context
+                .api()
+                .flush_mapped_buffer_range(self.target, offset, length);
+        }
+    }
+
+    /// Unmaps the buffer, returning `false` if its contents got corrupted while mapped, e.g. by
+    /// a change of the display mode, and must be uploaded again.
+    #[must_use]
+    pub fn unmap(self) -> bool {
+        let intact = self.unmap_buffer();
+        std::mem::forget(self);
+        intact
+    }
+
+    fn unmap_buffer(&self) -> bool {
+        self.buffer.bind(self.target);
+        unsafe { self.buffer.context.api().unmap_buffer(self.target) != 0 }
+    }
+}
+
+impl<T: Plain> Deref for Mapping<'_, T> {
+    type Target = [T];
+
+    /// # Panics
+    /// If the buffer wasn't mapped with `MapAccess::READ`, GL leaves the contents undefined
+    /// then. `READ` can't be combined with the `INVALIDATE_*` flags.
+    fn deref(&self) -> &[T] {
+        assert!(
+            self.access.contains(gl::MapAccess::READ),
+            "the buffer was mapped without MapAccess::READ"
+        );
+        // mapped for `len` elements until the guard is dropped, and aligned as `Buffer::map` checked
+        unsafe { std::slice::from_raw_parts(self.data, self.len) }
+    }
+}
+
+impl<T: Plain> DerefMut for Mapping<'_, T> {
+    /// # Panics
+    /// If the buffer wasn't mapped with `MapAccess::WRITE`.
+    fn deref_mut(&mut self) -> &mut [T] {
+        assert!(
+            self.access.contains(gl::MapAccess::WRITE),
+            "the buffer was mapped without MapAccess::WRITE"
+        );
+        unsafe { std::slice::from_raw_parts_mut(self.data, self.len) }
+    }
+}
+
+impl<T: Plain> Drop for Mapping<'_, T> {
+    fn drop(&mut self) {
+        if !self.unmap_buffer() {
+            log::warn!(
+                "the contents of buffer {:?} got corrupted while mapped",
+                self.buffer.raw
+            );
+        }
+    }
+}
+
+impl Drop for Buffer {
+    fn drop(&mut self) {
+        self.context.buffer_deleted(self.raw);
+        unsafe { self.context.api().delete_buffers(1, &raw const self.raw) }
+    }
+}
+
+#[cfg(all(test, feature = "testing"))]
+mod tests {
+    use super::*;
+    use crate::safe::mock_context;
+    use crate::testing::{Mock, Value};
+
+    /// A buffer of 4 integers, whose mapping is `storage`.
+    fn mapped_buffer(mock: &Mock, context: &Context, storage: &mut [u32; 4]) -> Buffer {
+        let address = storage.as_mut_ptr().expose_provenance();
+        mock.on("glMapBufferRange", move |_| Some(Value::Pointer(address)));
+        mock.returns("glUnmapBuffer", 1);
+
+        let buffer = Buffer::new(context);
+        let target = gl::BufferTarget::ARRAY_BUFFER;
+        buffer.data(target, &[0u32; 4], gl::BufferUsage::STATIC_DRAW);
+        buffer
+    }
+
+    #[test]
+    fn mapping_reads_and_writes_the_storage() {
+        let mock = Mock::new();
+        let context = mock_context(&mock);
+        let mut storage = [1, 2, 3, 4];
+        let mut buffer = mapped_buffer(&mock, &context, &mut storage);
+
+        let access = gl::MapAccess::READ | gl::MapAccess::WRITE;
+        let mut mapping = buffer
+            .map::<u32>(gl::BufferTarget::ARRAY_BUFFER, 4, 2, access)
+            .unwrap();
+        assert_eq!(&*mapping, &[1, 2]);
+        mapping[1] = 5;
+        assert!(mapping.unmap());
+
+        let map = &mock.calls_to("glMapBufferRange")[0];
+        assert_eq!(map.arg("offset"), Some(Value::Int(4)));
+        assert_eq!(map.arg("length"), Some(Value::Int(8)));
+        assert_eq!(storage[1], 5);
+    }
+
+    #[test]
+    fn null_mapping_is_none() {
+        let mock = Mock::new();
+        let context = mock_context(&mock);
+        let mut buffer = Buffer::new(&context);
+        buffer.data(
+            gl::BufferTarget::ARRAY_BUFFER,
+            &[0u8; 16],
+            gl::BufferUsage::STATIC_DRAW,
+        );
+
+        let mapping = buffer.map::<u8>(gl::BufferTarget::ARRAY_BUFFER, 0, 16, gl::MapAccess::READ);
+        assert!(mapping.is_none());
+    }
+
+    #[test]
+    #[should_panic(expected = "without MapAccess::READ")]
+    fn reading_needs_read_access() {
+        let mock = Mock::new();
+        let context = mock_context(&mock);
+        let mut storage = [0; 4];
+        let mut buffer = mapped_buffer(&mock, &context, &mut storage);
+
+        let mapping = buffer
+            .map::<u32>(gl::BufferTarget::ARRAY_BUFFER, 0, 4, gl::MapAccess::WRITE)
+            .unwrap();
+        let _ = mapping[0];
+    }
+
+    #[test]
+    #[should_panic(expected = "without MapAccess::WRITE")]
+    fn writing_needs_write_access() {
+        let mock = Mock::new();
+        let context = mock_context(&mock);
+        let mut storage = [0; 4];
+        let mut buffer = mapped_buffer(&mock, &context, &mut storage);
+
+        let mut mapping = buffer
+            .map::<u32>(gl::BufferTarget::ARRAY_BUFFER, 0, 4, gl::MapAccess::READ)
+            .unwrap();
+        mapping[0] = 1;
+    }
+
+    #[test]
+    #[should_panic(expected = "without MapAccess::FLUSH_EXPLICIT")]
+    fn flushing_needs_flush_explicit() {
+        let mock = Mock::new();
+        let context = mock_context(&mock);
+        let mut storage = [0; 4];
+        let mut buffer = mapped_buffer(&mock, &context, &mut storage);
+
+        let mapping = buffer
+            .map::<u32>(gl::BufferTarget::ARRAY_BUFFER, 0, 4, gl::MapAccess::WRITE)
+            .unwrap();
+        mapping.flush(0..2);
+    }
+
+    #[test]
+    #[should_panic(expected = "can't be borrowed safely")]
+    fn persistent_mappings_are_rejected() {
+        let mock = Mock::new();
+        let context = mock_context(&mock);
+        let mut storage = [0; 4];
+        let mut buffer = mapped_buffer(&mock, &context, &mut storage);
+
+        let access = gl::MapAccess::WRITE | gl::MapAccess::PERSISTENT;
+        let _ = buffer.map::<u32>(gl::BufferTarget::ARRAY_BUFFER, 0, 4, access);
+    }
+}
@@ -0,0 +1,206 @@
+use std::cell::{Cell, RefCell};
+
+use super::{gl, Context};
+
+/// The bindings the `Context` last set, so binding the same object again can be skipped.
+/// `None` means unknown, which is what `invalidate` goes back to.
+#[derive(Default)]
+pub(super) struct Bindings {
+    buffers: RefCell<Vec<(gl::BufferTarget, gl::Buffer)>>,
+    vertex_array: Cell<Option<gl::VertexArray>>,
+    program: Cell<Option<gl::Program>>,
+    stats: Cell<CacheStats>,
+}
+
+/// How many binds the `Context` skipped and issued, see `Context::cache_stats`.
+#[derive(Copy, Clone, Eq, PartialEq, Debug, Default)]
+pub struct CacheStats {
+    /// Binds that were skipped because the object was already bound.
+    pub hits: u64,
+    /// Binds that reached GL.
+    pub misses: u64,
+}
+
+impl Context {
+    /// Forgets all bindings and pipeline state the context tracks. Call it after code that uses
+    /// the GL context directly, e.g. a UI library, so the next binds reach GL again.
+    pub fn invalidate(&self) {
+        let bindings = &self.0.bindings;
+        bindings.buffers.take();
+        bindings.vertex_array.take();
+        bindings.program.take();
+        self.invalidate_pipeline();
+    }
+
+    #[must_use]
+    pub fn cache_stats(&self) -> CacheStats {
+        self.0.bindings.stats.get()
+    }
+
+    pub fn reset_cache_stats(&self) {
+        self.0.bindings.stats.take();
+    }
+
+    pub(super) fn bind_buffer(&self, target: gl::BufferTarget, buffer: gl::Buffer) {
+        let mut buffers = self.0.bindings.buffers.borrow_mut();
+        let slot = buffers.iter_mut().find(|(bound, _)| *bound == target);
+        if self.record(slot.as_ref().is_some_and(|(_, bound)| *bound == buffer)) {
+            return;
+        }
+
+        unsafe { self.api().bind_buffer(target, buffer) };
+        match slot {
+            Some((_, bound)) => *bound = buffer,
+            None => buffers.push((target, buffer)),
+        }
+    }
+
+    pub(super) fn bind_vertex_array(&self, vertex_array: gl::VertexArray) {
+        let bindings = &self.0.bindings;
+        if self.record(bindings.vertex_array.get() == Some(vertex_array)) {
+            return;
+        }
+
+        unsafe { self.api().bind_vertex_array(vertex_array) };
+        bindings.vertex_array.set(Some(vertex_array));
+        self.invalidate_element_array_buffer();
+    }
+
+    pub(super) fn use_program(&self, program: gl::Program) {
+        let bindings = &self.0.bindings;
+        if self.record(bindings.program.get() == Some(program)) {
+            return;
+        }
+
+        unsafe { self.api().use_program(program) };
+        bindings.program.set(Some(program));
+    }
+
+    /// Deleting a bound buffer binds `NONE` in its place, and its name may be handed out again.
+    pub(super) fn buffer_deleted(&self, buffer: gl::Buffer) {
+        self.0
+            .bindings
+            .buffers
+            .borrow_mut()
+            .retain(|(_, bound)| *bound != buffer);
+    }
+
+    pub(super) fn vertex_array_deleted(&self, vertex_array: gl::VertexArray) {
+        let bindings = &self.0.bindings;
+        if bindings.vertex_array.get() == Some(vertex_array) {
+            bindings.vertex_array.take();
+            self.invalidate_element_array_buffer();
+        }
+    }
+
+    pub(super) fn program_deleted(&self, program: gl::Program) {
+        let bindings = &self.0.bindings;
+        if bindings.program.get() == Some(program) {
+            bindings.program.take();
+        }
+    }
+
+    /// The index buffer binding is part of the vertex array, so it changes along with it.
+    fn invalidate_element_array_buffer(&self) {
+        self.0
+            .bindings
+            .buffers
+            .borrow_mut()
+            .retain(|(target, _)| *target != gl::BufferTarget::ELEMENT_ARRAY_BUFFER);
+    }
+
+    /// Counts a bind and returns whether it was a hit.
+    fn record(&self, hit: bool) -> bool {
+        let stats = &self.0.bindings.stats;
+        let mut current = stats.get();
+        if hit {
+            current.hits += 1;
+        } else {
+            current.misses += 1;
+        }
+        stats.set(current);
+        hit
+    }
+}
+
+#[cfg(all(test, feature = "testing"))]
+mod tests {
+    use super::*;
+    use crate::safe::{mock_context, Buffer, Program, Shader, VertexArray};
+    use crate::testing::{Mock, Value};
+
+    #[test]
+    fn repeated_binds_are_skipped() {
+        let mock = Mock::new();
+        let context = mock_context(&mock);
+        let buffer = Buffer::new(&context);
+
+        buffer.bind(gl::BufferTarget::ARRAY_BUFFER);
+        buffer.bind(gl::BufferTarget::ARRAY_BUFFER);
+        buffer.bind(gl::BufferTarget::COPY_READ_BUFFER);
+        assert_eq!(mock.calls_to("glBindBuffer").len(), 2);
+        assert_eq!(context.cache_stats(), CacheStats { hits: 1, misses: 2 });
+
+        context.reset_cache_stats();
+        assert_eq!(context.cache_stats(), CacheStats::default());
+    }
+
+    #[test]
+    fn invalidate_forgets_all_bindings() {
+        let mock = Mock::new();
+        let context = mock_context(&mock);
+        let buffer = Buffer::new(&context);
+        let vertex_array = VertexArray::new(&context);
+
+        buffer.bind(gl::BufferTarget::ARRAY_BUFFER);
+        vertex_array.bind();
+        context.invalidate();
+        buffer.bind(gl::BufferTarget::ARRAY_BUFFER);
+        vertex_array.bind();
+
+        assert_eq!(mock.calls_to("glBindBuffer").len(), 2);
+        assert_eq!(mock.calls_to("glBindVertexArray").len(), 2);
+        assert_eq!(context.cache_stats().hits, 0);
+    }
+
+    #[test]
+    fn deleted_objects_are_unbound() {
+        let mock = Mock::new();
+        let context = mock_context(&mock);
+        let shader = Shader::new(&context, gl::ShaderKind::VERTEX, "void main() {}").unwrap();
+        let program = Program::new(&context, &[&shader]).unwrap();
+        let buffer = Buffer::new(&context);
+        let vertex_array = VertexArray::new(&context);
+
+        buffer.bind(gl::BufferTarget::ARRAY_BUFFER);
+        vertex_array.bind();
+        program.bind();
+        drop((buffer, vertex_array, program));
+
+        let bindings = &context.0.bindings;
+        assert!(bindings.buffers.borrow().is_empty());
+        assert_eq!(bindings.vertex_array.get(), None);
+        assert_eq!(bindings.program.get(), None);
+    }
+
+    #[test]
+    fn vertex_array_changes_forget_the_index_buffer() {
+        let mock = Mock::new();
+        let context = mock_context(&mock);
+        let [vertices, indices] = context.gen_buffers();
+        let [first, second] = context.gen_vertex_arrays();
+
+        first.bind();
+        vertices.bind(gl::BufferTarget::ARRAY_BUFFER);
+        indices.bind(gl::BufferTarget::ELEMENT_ARRAY_BUFFER);
+        second.bind();
+        let _ = mock.take_calls();
+
+        vertices.bind(gl::BufferTarget::ARRAY_BUFFER);
+        indices.bind(gl::BufferTarget::ELEMENT_ARRAY_BUFFER);
+        let binds = mock.calls_to("glBindBuffer");
+        assert_eq!(binds.len(), 1);
+        // GL_ELEMENT_ARRAY_BUFFER
+        assert_eq!(binds[0].arg("target"), Some(Value::Int(0x8893)));
+    }
+}
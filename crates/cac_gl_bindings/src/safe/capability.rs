@@ -0,0 +1,167 @@
+use super::{gl, Context};
+use crate::types::GLuint;
+
+/// Restores a capability to the state it had before `Context::enable_scoped` or
+/// `Context::disable_scoped` when dropped.
+#[must_use = "the capability is restored as soon as the guard is dropped"]
+pub struct CapabilityGuard<'a> {
+    context: &'a Context,
+    capability: gl::Capability,
+    index: Option<GLuint>,
+    was_enabled: bool,
+}
+
+impl Context {
+    /// # Panics
+    /// For `DEBUG_OUTPUT_SYNCHRONOUS` while a debug handler is set, see `Api::set_debug_handler`.
+    pub fn disable(&self, capability: gl::Capability) {
+        self.assert_can_disable(capability);
+        self.capability_changed(capability);
+        unsafe { self.api().disable(capability) }
+    }
+
+    #[must_use]
+    pub fn is_enabled(&self, capability: gl::Capability) -> bool {
+        unsafe { self.api().is_enabled(capability) != 0 }
+    }
+
+    /// Enables `capability` for draw buffer or viewport `index` only.
+    pub fn enable_indexed(&self, capability: gl::Capability, index: GLuint) {
+        self.capability_changed(capability);
+        unsafe { self.api().enablei(capability, index) }
+    }
+
+    /// # Panics
+    /// See `disable`.
+    pub fn disable_indexed(&self, capability: gl::Capability, index: GLuint) {
+        self.assert_can_disable(capability);
+        self.capability_changed(capability);
+        unsafe { self.api().disablei(capability, index) }
+    }
+
+    #[must_use]
+    pub fn is_enabled_indexed(&self, capability: gl::Capability, index: GLuint) -> bool {
+        unsafe { self.api().is_enabledi(capability, index) != 0 }
+    }
+
+    /// Enables `capability` until the returned guard is dropped.
+    pub fn enable_scoped(&self, capability: gl::Capability) -> CapabilityGuard<'_> {
+        CapabilityGuard::new(self, capability, None, true)
+    }
+
+    /// Disables `capability` until the returned guard is dropped.
+    ///
+    /// # Panics
+    /// See `disable`.
+    pub fn disable_scoped(&self, capability: gl::Capability) -> CapabilityGuard<'_> {
+        CapabilityGuard::new(self, capability, None, false)
+    }
+
+    /// Enables `capability` for `index` until the returned guard is dropped.
+    pub fn enable_indexed_scoped(
+        &self,
+        capability: gl::Capability,
+        index: GLuint,
+    ) -> CapabilityGuard<'_> {
+        CapabilityGuard::new(self, capability, Some(index), true)
+    }
+
+    /// Disables `capability` for `index` until the returned guard is dropped.
+    ///
+    /// # Panics
+    /// See `disable`.
+    pub fn disable_indexed_scoped(
+        &self,
+        capability: gl::Capability,
+        index: GLuint,
+    ) -> CapabilityGuard<'_> {
+        CapabilityGuard::new(self, capability, Some(index), false)
+    }
+
+    /// The debug handler is only called on this thread while the output is synchronous.
+    fn can_disable(&self, capability: gl::Capability) -> bool {
+        capability != gl::Capability::DEBUG_OUTPUT_SYNCHRONOUS || !self.api().has_debug_handler()
+    }
+
+    fn assert_can_disable(&self, capability: gl::Capability) {
+        assert!(
+            self.can_disable(capability),
+            "DEBUG_OUTPUT_SYNCHRONOUS can't be disabled while a debug handler is set"
+        );
+    }
+
+    fn set_enabled(&self, capability: gl::Capability, index: Option<GLuint>, enabled: bool) {
+        match (index, enabled) {
+            (None, true) => self.enable(capability),
+            (None, false) => self.disable(capability),
+            (Some(index), true) => self.enable_indexed(capability, index),
+            (Some(index), false) => self.disable_indexed(capability, index),
+        }
+    }
+}
+
+impl<'a> CapabilityGuard<'a> {
+    fn new(
+        context: &'a Context,
+        capability: gl::Capability,
+        index: Option<GLuint>,
+        enabled: bool,
+    ) -> Self {
+        let was_enabled = index.map_or_else(
+            || context.is_enabled(capability),
+            |index| context.is_enabled_indexed(capability, index),
+        );
+        context.set_enabled(capability, index, enabled);
+
+        Self {
+            context,
+            capability,
+            index,
+            was_enabled,
+        }
+    }
+}
+
+impl Drop for CapabilityGuard<'_> {
+    /// Leaves the capability enabled where disabling it would panic, e.g. for a debug handler
+    /// set within the scope.
+    fn drop(&mut self) {
+        if !self.was_enabled && !self.context.can_disable(self.capability) {
+            log::warn!(
+                "not restoring {:?}, it can't be disabled while a debug handler is set",
+                self.capability
+            );
+            return;
+        }
+        self.context
+            .set_enabled(self.capability, self.index, self.was_enabled);
+    }
+}
+
+#[cfg(all(test, feature = "testing"))]
+mod tests {
+    use super::*;
+    use crate::safe::mock_context;
+    use crate::testing::Mock;
+
+    #[test]
+    fn guards_restore_the_previous_state() {
+        let mock = Mock::new();
+        let context = mock_context(&mock);
+
+        drop(context.enable_scoped(gl::Capability::SCISSOR_TEST));
+        assert_eq!(mock.calls_to("glEnable").len(), 1);
+        assert_eq!(mock.calls_to("glDisable").len(), 1);
+    }
+
+    #[test]
+    fn restoring_skips_what_the_debug_handler_needs() {
+        let mock = Mock::new();
+        let context = mock_context(&mock);
+
+        let guard = context.enable_scoped(gl::Capability::DEBUG_OUTPUT_SYNCHRONOUS);
+        let _debug = unsafe { context.api().set_debug_handler(Box::new(|_| {})) };
+        drop(guard);
+        assert!(mock.calls_to("glDisable").is_empty());
+    }
+}
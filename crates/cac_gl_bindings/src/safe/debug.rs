@@ -0,0 +1,124 @@
+use super::{count, gl, Context};
+
+/// Ends the group of `Context::debug_group` when dropped.
+#[must_use = "the group ends as soon as the guard is dropped"]
+pub struct DebugGroup<'a> {
+    context: &'a Context,
+}
+
+impl Context {
+    /// Groups the following commands under `name` until the returned guard is dropped, e.g. one
+    /// per render pass. Groups nest up to `MAX_DEBUG_GROUP_STACK_DEPTH` deep.
+    pub fn debug_group(&self, name: &str) -> DebugGroup<'_> {
+        unsafe {
+            self.api().push_debug_group(
+                gl::DebugSource::APPLICATION,
+                0,
+                count(name.len()),
+                name.as_ptr().cast(),
+            );
+        }
+        DebugGroup { context: self }
+    }
+
+    /// Drains the messages logged while no debug handler is set, see `Api::debug_message_log`.
+    #[must_use]
+    pub fn debug_message_log(&self) -> gl::DebugMessageLog<'_> {
+        unsafe { self.api().debug_message_log() }
+    }
+
+    /// Names `object` in debug messages and graphics debuggers.
+    pub fn label(&self, object: impl gl::Object, label: &str) {
+        unsafe { self.api().label_object(object, label) }
+    }
+
+    /// `label` for a name from `glGen*`, which only becomes an object once it is bound, GL
+    /// rejects the label before that. `bind` binds it.
+    pub(super) fn label_generated(
+        &self,
+        object: impl gl::Object,
+        label: &str,
+        bind: impl FnOnce(),
+    ) {
+        bind();
+        self.label(object, label);
+    }
+}
+
+impl Drop for DebugGroup<'_> {
+    fn drop(&mut self) {
+        unsafe { self.context.api().pop_debug_group() }
+    }
+}
+
+#[cfg(all(test, feature = "testing"))]
+mod tests {
+    use std::cell::RefCell;
+    use std::collections::VecDeque;
+    use std::rc::Rc;
+
+    use crate::safe::mock_context;
+    use crate::testing::{write, Mock, Value};
+    use crate::types::{GLint, GLsizei};
+
+    #[test]
+    fn message_log_is_drained_in_order() {
+        let mock = Mock::new();
+        let context = mock_context(&mock);
+        let log = Rc::new(RefCell::new(VecDeque::from(["first", "second"])));
+
+        let pending = Rc::clone(&log);
+        mock.on("glGetIntegerv", move |call| {
+            // GL_DEBUG_NEXT_LOGGED_MESSAGE_LENGTH, which includes the terminating nul
+            (call.arg("pname") == Some(Value::Int(0x8243))).then(|| {
+                let length = pending.borrow().front().map_or(0, |text| text.len() + 1);
+                let length = GLint::try_from(length).unwrap();
+                unsafe { write(call.arg("data").unwrap(), 0, length) };
+                Value::Int(0)
+            })
+        });
+        mock.on("glGetDebugMessageLog", move |call| {
+            let text = log.borrow_mut().pop_front()?;
+            let length = GLsizei::try_from(text.len() + 1).unwrap();
+            unsafe {
+                write(call.arg("lengths").unwrap(), 0, length);
+                for (i, &byte) in text.as_bytes().iter().chain(&[0]).enumerate() {
+                    write(call.arg("message_log").unwrap(), i, byte);
+                }
+            }
+            Some(Value::Int(1))
+        });
+
+        let texts: Vec<_> = context.debug_message_log().map(|m| m.text).collect();
+        assert_eq!(texts, ["first", "second"]);
+        assert_eq!(mock.calls_to("glGetDebugMessageLog").len(), 2);
+        assert_eq!(context.debug_message_log().count(), 0);
+    }
+
+    #[test]
+    fn groups_are_popped_when_dropped() {
+        let mock = Mock::new();
+        let context = mock_context(&mock);
+
+        {
+            let _frame = context.debug_group("frame");
+            let _pass = context.debug_group("shadow pass");
+        }
+        let _ = context.debug_group("ui");
+
+        let calls = mock.take_calls();
+        let symbols: Vec<_> = calls.iter().map(|call| call.symbol).collect();
+        assert_eq!(
+            symbols,
+            [
+                "glPushDebugGroup",
+                "glPushDebugGroup",
+                "glPopDebugGroup",
+                "glPopDebugGroup",
+                "glPushDebugGroup",
+                "glPopDebugGroup",
+            ]
+        );
+        assert_eq!(calls[1].arg("length"), Some(Value::Int(11)));
+    }
+}
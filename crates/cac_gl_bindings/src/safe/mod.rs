@@ -0,0 +1,128 @@
+//! Owned objects on top of `gl43::Api` that delete themselves on `Drop`.
+//!
+//! The only `unsafe` left is `Context::new`, which is where the caller promises that the context
+//! stays current, and the draw calls, which read whatever the bound objects point at.
+
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use crate::gl43 as gl;
+use crate::types::{GLfloat, GLint, GLsizei};
+
+mod buffer;
+mod cache;
+mod capability;
+mod debug;
+mod pipeline;
+mod program;
+mod vertex_array;
+mod vertex_layout;
+
+pub use buffer::{Buffer, Mapping, Plain};
+pub use cache::CacheStats;
+pub use capability::CapabilityGuard;
+pub use debug::DebugGroup;
+pub use pipeline::{
+    BlendState, DepthState, PipelineState, PolygonOffset, RasterState, StencilFace, StencilState,
+};
+pub use program::{Program, Shader};
+pub use vertex_array::{OutOfBounds, VertexArray};
+pub use vertex_layout::VertexLayout;
+
+/// A shared handle to the loaded `Api`. Every object keeps a clone, so the `Api` outlives them.
+/// It is neither `Send` nor `Sync`, as a context can only be current on one thread.
+#[derive(Clone)]
+pub struct Context(Rc<Shared>);
+
+struct Shared {
+    api: gl::Api,
+    bindings: cache::Bindings,
+    /// The state of the last `bind_pipeline`, `None` until then or after raw state changes.
+    pipeline: RefCell<Option<PipelineState>>,
+}
+
+impl Context {
+    /// # Safety
+    /// The context `api` was loaded from must be current on this thread whenever this `Context`
+    /// or an object created from it is used or dropped.
+    #[must_use]
+    pub unsafe fn new(api: gl::Api) -> Self {
+        Self(Rc::new(Shared {
+            api,
+            bindings: cache::Bindings::default(),
+            pipeline: RefCell::default(),
+        }))
+    }
+
+    /// The raw bindings, for everything this module doesn't wrap yet.
+    /// Changing bindings or pipeline state through them has to be followed by `invalidate`.
+    #[must_use]
+    pub fn api(&self) -> &gl::Api {
+        &self.0.api
+    }
+
+    #[must_use]
+    pub fn gen_buffers<const N: usize>(&self) -> [Buffer; N] {
+        let mut raw = [gl::Buffer::NONE; N];
+        unsafe { self.api().gen_buffers(count(N), raw.as_mut_ptr()) };
+        raw.map(|raw| Buffer::from_raw(self.clone(), raw))
+    }
+
+    #[must_use]
+    pub fn gen_vertex_arrays<const N: usize>(&self) -> [VertexArray; N] {
+        let mut raw = [gl::VertexArray::NONE; N];
+        unsafe { self.api().gen_vertex_arrays(count(N), raw.as_mut_ptr()) };
+        raw.map(|raw| VertexArray::from_raw(self.clone(), raw))
+    }
+
+    pub fn enable(&self, capability: gl::Capability) {
+        self.capability_changed(capability);
+        unsafe { self.api().enable(capability) }
+    }
+
+    pub fn clear_color(&self, red: GLfloat, green: GLfloat, blue: GLfloat, alpha: GLfloat) {
+        unsafe { self.api().clear_color(red, green, blue, alpha) }
+    }
+
+    pub fn clear(&self, mask: gl::ClearMask) {
+        unsafe { self.api().clear(mask) }
+    }
+
+    /// # Safety
+    /// The enabled attributes of the bound `VertexArray` must have at least `first + count`
+    /// vertices in their buffers.
+    pub unsafe fn draw_arrays(&self, mode: gl::Primitive, first: GLint, count: GLsizei) {
+        unsafe { self.api().draw_arrays(mode, first, count) }
+    }
+
+    /// Draws `count` indices of `kind`, starting `offset` bytes into the `ELEMENT_ARRAY_BUFFER` of
+    /// the bound `VertexArray`.
+    ///
+    /// # Safety
+    /// The index buffer must hold `count` indices after `offset`, and the enabled attributes of
+    /// the bound `VertexArray` must have a vertex for each of them.
+    pub unsafe fn draw_elements(
+        &self,
+        mode: gl::Primitive,
+        count: GLsizei,
+        kind: gl::IndexKind,
+        offset: usize,
+    ) {
+        unsafe {
+            self.api()
+                .draw_elements(mode, count, kind, std::ptr::without_provenance(offset));
+        }
+    }
+}
+
+/// Converts a slice length or const generic into the `count` of a GL call.
+/// A `Context` on top of `mock`, which has to outlive it.
+#[cfg(all(test, feature = "testing"))]
+fn mock_context(_mock: &crate::testing::Mock) -> Context {
+    let api = unsafe { gl::Api::with_loader(&crate::testing::Mock::loader) };
+    unsafe { Context::new(api.unwrap()) }
+}
+
+fn count(n: usize) -> GLsizei {
+    n.try_into().expect("count doesn't fit into a GLsizei")
+}
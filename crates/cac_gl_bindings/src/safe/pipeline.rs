@@ -0,0 +1,411 @@
+use super::{gl, Context};
+use crate::types::{GLfloat, GLint, GLuint};
+
+/// The blend, depth, stencil and raster state of a draw, applied as a whole with
+/// `Context::bind_pipeline`.
+///
+/// `None` disables the corresponding test and resets its write masks, which `clear` respects too.
+/// `Default` is the initial state of a new context.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct PipelineState {
+    pub blend: Option<BlendState>,
+    pub depth: Option<DepthState>,
+    pub stencil: Option<StencilState>,
+    pub raster: RasterState,
+    /// Whether red, green, blue and alpha are written.
+    pub color_mask: [bool; 4],
+}
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct BlendState {
+    pub src_color: gl::BlendFactor,
+    pub dst_color: gl::BlendFactor,
+    pub color_equation: gl::BlendEquation,
+    pub src_alpha: gl::BlendFactor,
+    pub dst_alpha: gl::BlendFactor,
+    pub alpha_equation: gl::BlendEquation,
+    /// The color of the `CONSTANT_*` factors.
+    pub constant: [GLfloat; 4],
+}
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct DepthState {
+    pub func: gl::CompareFunc,
+    pub write: bool,
+}
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct StencilState {
+    pub front: StencilFace,
+    pub back: StencilFace,
+}
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct StencilFace {
+    pub func: gl::CompareFunc,
+    pub reference: GLint,
+    /// Applied to the reference and the stored value before comparing them.
+    pub read_mask: GLuint,
+    pub write_mask: GLuint,
+    pub fail: gl::StencilOp,
+    pub depth_fail: gl::StencilOp,
+    pub pass: gl::StencilOp,
+}
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct RasterState {
+    /// The faces that are culled, `None` disables culling.
+    pub cull_face: Option<gl::Face>,
+    pub front_face: gl::FrontFace,
+    pub polygon_mode: gl::PolygonMode,
+    /// Offsets the depth of filled polygons, `None` disables it.
+    pub polygon_offset: Option<PolygonOffset>,
+}
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct PolygonOffset {
+    pub factor: GLfloat,
+    pub units: GLfloat,
+}
+
+impl Default for PipelineState {
+    fn default() -> Self {
+        Self {
+            blend: None,
+            depth: None,
+            stencil: None,
+            raster: RasterState::default(),
+            color_mask: [true; 4],
+        }
+    }
+}
+
+impl Default for BlendState {
+    /// Regular alpha blending.
+    fn default() -> Self {
+        Self {
+            src_color: gl::BlendFactor::SRC_ALPHA,
+            dst_color: gl::BlendFactor::ONE_MINUS_SRC_ALPHA,
+            color_equation: gl::BlendEquation::ADD,
+            src_alpha: gl::BlendFactor::ONE,
+            dst_alpha: gl::BlendFactor::ONE_MINUS_SRC_ALPHA,
+            alpha_equation: gl::BlendEquation::ADD,
+            constant: [0.0; 4],
+        }
+    }
+}
+
+impl Default for DepthState {
+    fn default() -> Self {
+        Self {
+            func: gl::CompareFunc::LESS,
+            write: true,
+        }
+    }
+}
+
+impl Default for StencilFace {
+    fn default() -> Self {
+        Self {
+            func: gl::CompareFunc::ALWAYS,
+            reference: 0,
+            read_mask: GLuint::MAX,
+            write_mask: GLuint::MAX,
+            fail: gl::StencilOp::KEEP,
+            depth_fail: gl::StencilOp::KEEP,
+            pass: gl::StencilOp::KEEP,
+        }
+    }
+}
+
+impl Default for RasterState {
+    fn default() -> Self {
+        Self {
+            cull_face: None,
+            front_face: gl::FrontFace::CCW,
+            polygon_mode: gl::PolygonMode::FILL,
+            polygon_offset: None,
+        }
+    }
+}
+
+/// The capabilities `bind_pipeline` tracks, changing them elsewhere invalidates the tracking.
+const TRACKED: [gl::Capability; 5] = [
+    gl::Capability::BLEND,
+    gl::Capability::DEPTH_TEST,
+    gl::Capability::STENCIL_TEST,
+    gl::Capability::CULL_FACE,
+    gl::Capability::POLYGON_OFFSET_FILL,
+];
+
+impl Context {
+    /// Switches to `state`, only issuing the calls for what differs from the last bound state.
+    /// The first call after `invalidate_pipeline` sets everything.
+    pub fn bind_pipeline(&self, state: &PipelineState) {
+        let mut tracked = self.0.pipeline.borrow_mut();
+        if tracked.as_ref() != Some(state) {
+            unsafe { apply(self.api(), tracked.as_ref(), state) };
+            *tracked = Some(*state);
+        }
+    }
+
+    /// Forgets the tracked state, call it after changing any of it through `api`.
+    pub fn invalidate_pipeline(&self) {
+        self.0.pipeline.take();
+    }
+
+    /// Keeps the tracking correct when a capability is toggled outside of `bind_pipeline`.
+    pub(super) fn capability_changed(&self, capability: gl::Capability) {
+        if TRACKED.contains(&capability) {
+            self.invalidate_pipeline();
+        }
+    }
+}
+
+/// Whether a part of the state has to be set, either because it differs or the old state is
+/// unknown.
+fn changed<T: Copy + PartialEq>(old: Option<T>, new: T) -> bool {
+    old.is_none_or(|old| old != new)
+}
+
+unsafe fn toggle(api: &gl::Api, capability: gl::Capability, old: Option<bool>, new: bool) {
+    if changed(old, new) {
+        unsafe {
+            if new {
+                api.enable(capability);
+            } else {
+                api.disable(capability);
+            }
+        }
+    }
+}
+
+/// The depth mask, which stays in effect for `clear` when the test is disabled.
+fn depth_write(state: &PipelineState) -> bool {
+    state.depth.is_none_or(|depth| depth.write)
+}
+
+/// The front and back stencil masks, like `depth_write`.
+fn stencil_write_masks(state: &PipelineState) -> (GLuint, GLuint) {
+    state.stencil.map_or((GLuint::MAX, GLuint::MAX), |stencil| {
+        (stencil.front.write_mask, stencil.back.write_mask)
+    })
+}
+
+unsafe fn apply(api: &gl::Api, old: Option<&PipelineState>, new: &PipelineState) {
+    unsafe {
+        toggle(
+            api,
+            gl::Capability::BLEND,
+            old.map(|o| o.blend.is_some()),
+            new.blend.is_some(),
+        );
+        if let Some(blend) = new.blend {
+            apply_blend(api, old.and_then(|o| o.blend), blend);
+        }
+
+        toggle(
+            api,
+            gl::Capability::DEPTH_TEST,
+            old.map(|o| o.depth.is_some()),
+            new.depth.is_some(),
+        );
+        if let Some(depth) = new.depth {
+            let old = old.and_then(|o| o.depth);
+            if changed(old.map(|o| o.func), depth.func) {
+                api.depth_func(depth.func);
+            }
+        }
+        if changed(old.map(depth_write), depth_write(new)) {
+            api.depth_mask(depth_write(new).into());
+        }
+
+        toggle(
+            api,
+            gl::Capability::STENCIL_TEST,
+            old.map(|o| o.stencil.is_some()),
+            new.stencil.is_some(),
+        );
+        if let Some(stencil) = new.stencil {
+            let old = old.and_then(|o| o.stencil);
+            apply_stencil(api, gl::Face::FRONT, old.map(|o| o.front), stencil.front);
+            apply_stencil(api, gl::Face::BACK, old.map(|o| o.back), stencil.back);
+        }
+        let (front, back) = stencil_write_masks(new);
+        let old_masks = old.map(stencil_write_masks);
+        if changed(old_masks.map(|(front, _)| front), front) {
+            api.stencil_mask_separate(gl::Face::FRONT, front);
+        }
+        if changed(old_masks.map(|(_, back)| back), back) {
+            api.stencil_mask_separate(gl::Face::BACK, back);
+        }
+
+        apply_raster(api, old.map(|o| o.raster), new.raster);
+
+        if changed(old.map(|o| o.color_mask), new.color_mask) {
+            let [red, green, blue, alpha] = new.color_mask;
+            api.color_mask(red.into(), green.into(), blue.into(), alpha.into());
+        }
+    }
+}
+
+unsafe fn apply_blend(api: &gl::Api, old: Option<BlendState>, new: BlendState) {
+    let factors = |b: BlendState| (b.src_color, b.dst_color, b.src_alpha, b.dst_alpha);
+    let equations = |b: BlendState| (b.color_equation, b.alpha_equation);
+
+    unsafe {
+        if changed(old.map(factors), factors(new)) {
+            api.blend_func_separate(new.src_color, new.dst_color, new.src_alpha, new.dst_alpha);
+        }
+        if changed(old.map(equations), equations(new)) {
+            api.blend_equation_separate(new.color_equation, new.alpha_equation);
+        }
+        if changed(old.map(|o| o.constant), new.constant) {
+            let [red, green, blue, alpha] = new.constant;
+            api.blend_color(red, green, blue, alpha);
+        }
+    }
+}
+
+unsafe fn apply_stencil(api: &gl::Api, face: gl::Face, old: Option<StencilFace>, new: StencilFace) {
+    let func = |s: StencilFace| (s.func, s.reference, s.read_mask);
+    let ops = |s: StencilFace| (s.fail, s.depth_fail, s.pass);
+
+    unsafe {
+        if changed(old.map(func), func(new)) {
+            api.stencil_func_separate(face, new.func, new.reference, new.read_mask);
+        }
+        if changed(old.map(ops), ops(new)) {
+            api.stencil_op_separate(face, new.fail, new.depth_fail, new.pass);
+        }
+    }
+}
+
+unsafe fn apply_raster(api: &gl::Api, old: Option<RasterState>, new: RasterState) {
+    unsafe {
+        toggle(
+            api,
+            gl::Capability::CULL_FACE,
+            old.map(|o| o.cull_face.is_some()),
+            new.cull_face.is_some(),
+        );
+        if let Some(face) = new.cull_face {
+            if changed(old.and_then(|o| o.cull_face), face) {
+                api.cull_face(face);
+            }
+        }
+        if changed(old.map(|o| o.front_face), new.front_face) {
+            api.front_face(new.front_face);
+        }
+        if changed(old.map(|o| o.polygon_mode), new.polygon_mode) {
+            api.polygon_mode(gl::Face::FRONT_AND_BACK, new.polygon_mode);
+        }
+
+        toggle(
+            api,
+            gl::Capability::POLYGON_OFFSET_FILL,
+            old.map(|o| o.polygon_offset.is_some()),
+            new.polygon_offset.is_some(),
+        );
+        if let Some(offset) = new.polygon_offset {
+            if changed(old.and_then(|o| o.polygon_offset), offset) {
+                api.polygon_offset(offset.factor, offset.units);
+            }
+        }
+    }
+}
+
+#[cfg(all(test, feature = "testing"))]
+mod tests {
+    use super::*;
+    use crate::safe::mock_context;
+    use crate::testing::{Mock, Value};
+
+    fn symbols(mock: &Mock) -> Vec<&'static str> {
+        mock.take_calls().iter().map(|call| call.symbol).collect()
+    }
+
+    #[test]
+    fn only_changed_state_is_set() {
+        let mock = Mock::new();
+        let context = mock_context(&mock);
+        context.bind_pipeline(&PipelineState::default());
+        let _ = mock.take_calls();
+
+        context.bind_pipeline(&PipelineState::default());
+        assert!(symbols(&mock).is_empty());
+
+        let state = PipelineState {
+            depth: Some(DepthState::default()),
+            ..PipelineState::default()
+        };
+        context.bind_pipeline(&state);
+        assert_eq!(symbols(&mock), ["glEnable", "glDepthFunc"]);
+    }
+
+    #[test]
+    fn changed_capabilities_reapply_everything() {
+        let mock = Mock::new();
+        let context = mock_context(&mock);
+        context.bind_pipeline(&PipelineState::default());
+
+        context.enable(gl::Capability::SCISSOR_TEST);
+        let _ = mock.take_calls();
+        context.bind_pipeline(&PipelineState::default());
+        assert!(symbols(&mock).is_empty());
+
+        context.enable(gl::Capability::BLEND);
+        let _ = mock.take_calls();
+        context.bind_pipeline(&PipelineState::default());
+        // GL_BLEND
+        let blend = Some(Value::Int(0x0BE2));
+        assert!(mock
+            .calls_to("glDisable")
+            .iter()
+            .any(|call| call.arg("cap") == blend));
+        assert_eq!(mock.calls_to("glColorMask").len(), 1);
+    }
+
+    #[test]
+    fn depth_and_stencil_masks_are_tracked_apart() {
+        let mock = Mock::new();
+        let context = mock_context(&mock);
+        let stencil = StencilState {
+            front: StencilFace {
+                write_mask: 0xFF,
+                ..StencilFace::default()
+            },
+            back: StencilFace::default(),
+        };
+        let mut state = PipelineState {
+            stencil: Some(stencil),
+            ..PipelineState::default()
+        };
+        context.bind_pipeline(&state);
+        let _ = mock.take_calls();
+
+        state.depth = Some(DepthState {
+            write: false,
+            ..DepthState::default()
+        });
+        context.bind_pipeline(&state);
+        assert_eq!(mock.calls_to("glDepthMask").len(), 1);
+        assert!(mock.calls_to("glStencilMaskSeparate").is_empty());
+        let _ = mock.take_calls();
+
+        state.stencil = Some(StencilState {
+            back: StencilFace {
+                write_mask: 0x0F,
+                ..StencilFace::default()
+            },
+            ..stencil
+        });
+        context.bind_pipeline(&state);
+        let masks = mock.calls_to("glStencilMaskSeparate");
+        assert_eq!(masks.len(), 1);
+        // GL_BACK
+        assert_eq!(masks[0].arg("face"), Some(Value::Int(0x0405)));
+        assert_eq!(masks[0].arg("mask"), Some(Value::Int(0x0F)));
+        assert!(mock.calls_to("glDepthMask").is_empty());
+    }
+}
@@ -0,0 +1,116 @@
+use super::{gl, Context};
+use crate::types::GLint;
+use crate::Error;
+
+pub struct Shader {
+    context: Context,
+    raw: gl::Shader,
+}
+
+impl Shader {
+    /// Creates and compiles a shader, `source` doesn't need to be nul terminated.
+    ///
+    /// # Errors
+    /// `Error::Compile` with the parsed info log if the compilation failed.
+    ///
+    /// # Panics
+    /// If `source` is longer than `GLint::MAX` bytes.
+    pub fn new(context: &Context, kind: gl::ShaderKind, source: &str) -> Result<Self, Error> {
+        let shader = Self {
+            context: context.clone(),
+            raw: unsafe { context.api().create_shader(kind) },
+        };
+
+        let string = source.as_ptr().cast();
+        let length: GLint = source
+            .len()
+            .try_into()
+            .expect("shader source exceeds GLint::MAX bytes");
+
+        unsafe {
+            context
+                .api()
+                .shader_source(shader.raw, 1, &raw const string, &raw const length);
+            context.api().compile_shader(shader.raw);
+            context
+                .api()
+                .check_compile_status(shader.raw)
+                .map_err(Error::Compile)?;
+        }
+
+        Ok(shader)
+    }
+
+    #[must_use]
+    pub const fn raw(&self) -> gl::Shader {
+        self.raw
+    }
+
+    /// Names the shader in debug messages and graphics debuggers.
+    pub fn set_label(&self, label: &str) {
+        self.context.label(self.raw, label);
+    }
+}
+
+impl Drop for Shader {
+    fn drop(&mut self) {
+        unsafe { self.context.api().delete_shader(self.raw) }
+    }
+}
+
+pub struct Program {
+    context: Context,
+    raw: gl::Program,
+}
+
+impl Program {
+    /// Links `shaders` into a new program. They are detached again afterwards, so they can be
+    /// dropped right away.
+    ///
+    /// # Errors
+    /// `Error::Link` with the parsed info log if linking failed.
+    pub fn new(context: &Context, shaders: &[&Shader]) -> Result<Self, Error> {
+        let program = Self {
+            context: context.clone(),
+            raw: unsafe { context.api().create_program() },
+        };
+
+        unsafe {
+            for shader in shaders {
+                context.api().attach_shader(program.raw, shader.raw);
+            }
+            context.api().link_program(program.raw);
+            for shader in shaders {
+                context.api().detach_shader(program.raw, shader.raw);
+            }
+            context
+                .api()
+                .check_link_status(program.raw)
+                .map_err(Error::Link)?;
+        }
+
+        Ok(program)
+    }
+
+    #[must_use]
+    pub const fn raw(&self) -> gl::Program {
+        self.raw
+    }
+
+    /// Names the program in debug messages and graphics debuggers.
+    pub fn set_label(&self, label: &str) {
+        self.context.label(self.raw, label);
+    }
+
+    /// Makes this the program used by draw calls.
+    pub fn bind(&self) {
+        self.context.use_program(self.raw);
+    }
+}
+
+impl Drop for Program {
+    fn drop(&mut self) {
+        self.context.program_deleted(self.raw);
+        unsafe { self.context.api().delete_program(self.raw) }
+    }
+}
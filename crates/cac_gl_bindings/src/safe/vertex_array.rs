@@ -0,0 +1,265 @@
+use std::cell::{Cell, RefCell};
+use std::collections::BTreeMap;
+use std::fmt::Display;
+use std::rc::Rc;
+
+use super::vertex_layout::{Attribute, Conversion};
+use super::{gl, Buffer, Context, VertexLayout};
+use crate::types::{GLint, GLsizei, GLuint};
+
+pub struct VertexArray {
+    context: Context,
+    raw: gl::VertexArray,
+    /// Where each attached attribute reads from, for the bounds checks of the draw calls.
+    sources: RefCell<BTreeMap<GLuint, Source>>,
+}
+
+struct Source {
+    buffer_size: Rc<Cell<usize>>,
+    stride: usize,
+    end: usize,
+    divisor: GLuint,
+}
+
+/// A draw call that would read attribute `index` past the end of its buffer.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub struct OutOfBounds {
+    pub index: GLuint,
+    /// Vertices or instances the draw call needs.
+    pub required: usize,
+    /// Vertices or instances the buffer holds.
+    pub available: usize,
+}
+
+impl VertexArray {
+    #[must_use]
+    pub fn new(context: &Context) -> Self {
+        let [vertex_array] = context.gen_vertex_arrays();
+        vertex_array
+    }
+
+    pub(super) fn from_raw(context: Context, raw: gl::VertexArray) -> Self {
+        Self {
+            context,
+            raw,
+            sources: RefCell::default(),
+        }
+    }
+
+    #[must_use]
+    pub const fn raw(&self) -> gl::VertexArray {
+        self.raw
+    }
+
+    /// Binds the vertex array and names it in debug messages and graphics debuggers.
+    pub fn set_label(&self, label: &str) {
+        self.context
+            .label_generated(self.raw, label, || self.bind());
+    }
+
+    pub fn bind(&self) {
+        self.context.bind_vertex_array(self.raw);
+    }
+
+    /// Sources every attribute of `layout` from `buffer` and enables it. Call it once per buffer
+    /// for vertex data split across several buffers.
+    ///
+    /// # Panics
+    /// If the stride of `layout` doesn't fit into a `GLsizei`.
+    pub fn attach(&self, buffer: &Buffer, layout: &VertexLayout) {
+        let stride = layout.resolved_stride();
+        let gl_stride = stride
+            .try_into()
+            .expect("vertex stride doesn't fit into a GLsizei");
+        let api = self.context.api();
+
+        self.bind();
+        buffer.bind(gl::BufferTarget::ARRAY_BUFFER);
+        for attribute in &layout.attributes {
+            let Attribute {
+                index,
+                components,
+                kind,
+                conversion,
+                offset,
+            } = *attribute;
+            let pointer = std::ptr::without_provenance(offset);
+
+            unsafe {
+                api.enable_vertex_attrib_array(index);
+                match conversion {
+                    Conversion::Integer => {
+                        api.vertex_attrib_i_pointer(index, components, kind, gl_stride, pointer);
+                    }
+                    Conversion::Float | Conversion::Normalized => {
+                        let normalized = conversion == Conversion::Normalized;
+                        api.vertex_attrib_pointer(
+                            index,
+                            components,
+                            kind,
+                            normalized.into(),
+                            gl_stride,
+                            pointer,
+                        );
+                    }
+                }
+                api.vertex_attrib_divisor(index, layout.divisor);
+            }
+
+            self.sources.borrow_mut().insert(
+                index,
+                Source {
+                    buffer_size: buffer.shared_size(),
+                    stride,
+                    end: attribute.end(),
+                    divisor: layout.divisor,
+                },
+            );
+        }
+    }
+
+    /// Makes `buffer` the `ELEMENT_ARRAY_BUFFER` of this vertex array, which is part of its state
+    /// unlike the `ARRAY_BUFFER` binding.
+    pub fn attach_indices(&self, buffer: &Buffer) {
+        self.bind();
+        buffer.bind(gl::BufferTarget::ELEMENT_ARRAY_BUFFER);
+    }
+
+    /// Binds the vertex array and draws vertices `first..first + count` `instances` times, after
+    /// checking that every attached buffer holds enough vertices or instances for that.
+    ///
+    /// # Errors
+    /// `OutOfBounds` for the first attribute that would be read past the end of its buffer,
+    /// nothing is drawn then.
+    pub fn draw_arrays_instanced(
+        &self,
+        mode: gl::Primitive,
+        first: GLint,
+        count: GLsizei,
+        instances: GLsizei,
+    ) -> Result<(), OutOfBounds> {
+        // negative values are rejected by GL without drawing anything
+        let vertices =
+            usize::try_from(first).unwrap_or_default() + usize::try_from(count).unwrap_or_default();
+        let instances_usize = usize::try_from(instances).unwrap_or_default();
+
+        for (&index, source) in self.sources.borrow().iter() {
+            let required = match source.divisor {
+                0 => vertices,
+                divisor => instances_usize.div_ceil(divisor as usize),
+            };
+            let available = source.available();
+            if required > available {
+                return Err(OutOfBounds {
+                    index,
+                    required,
+                    available,
+                });
+            }
+        }
+
+        self.bind();
+        unsafe {
+            self.context
+                .api()
+                .draw_arrays_instanced(mode, first, count, instances);
+        }
+        Ok(())
+    }
+}
+
+impl Source {
+    /// How many vertices, or instances for a divisor, the buffer holds for this attribute.
+    fn available(&self) -> usize {
+        let size = self.buffer_size.get();
+        if size < self.end {
+            return 0;
+        }
+        // a stride of 0 reads the same vertex over and over
+        (size - self.end)
+            .checked_div(self.stride)
+            .map_or(usize::MAX, |n| n + 1)
+    }
+}
+
+impl Drop for VertexArray {
+    fn drop(&mut self) {
+        self.context.vertex_array_deleted(self.raw);
+        unsafe {
+            self.context
+                .api()
+                .delete_vertex_arrays(1, &raw const self.raw);
+        }
+    }
+}
+
+impl std::error::Error for OutOfBounds {}
+
+impl Display for OutOfBounds {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "attribute {} needs {} elements, but its buffer only holds {}",
+            self.index, self.required, self.available
+        )
+    }
+}
+
+#[cfg(all(test, feature = "testing"))]
+mod tests {
+    use super::*;
+    use crate::safe::mock_context;
+    use crate::testing::Mock;
+
+    /// A vertex array with 4 `vec2` positions at 0 and 3 `vec4` colors at 1, one per 2 instances.
+    fn vertex_array(context: &Context) -> (VertexArray, [Buffer; 2]) {
+        let [positions, colors] = context.gen_buffers();
+        let target = gl::BufferTarget::ARRAY_BUFFER;
+        positions.data(target, &[[0.0f32; 2]; 4], gl::BufferUsage::STATIC_DRAW);
+        colors.data(target, &[[0.0f32; 4]; 3], gl::BufferUsage::STATIC_DRAW);
+
+        let vertex_array = VertexArray::new(context);
+        let float = gl::VertexAttributeKind::FLOAT;
+        vertex_array.attach(&positions, &VertexLayout::new().float(0, 2, float));
+        vertex_array.attach(&colors, &VertexLayout::new().divisor(2).float(1, 4, float));
+        (vertex_array, [positions, colors])
+    }
+
+    #[test]
+    fn vertices_are_checked_up_to_the_last_one() {
+        let mock = Mock::new();
+        let context = mock_context(&mock);
+        let (vertex_array, _buffers) = vertex_array(&context);
+        let mode = gl::Primitive::TRIANGLES;
+
+        assert_eq!(vertex_array.draw_arrays_instanced(mode, 1, 3, 1), Ok(()));
+        assert_eq!(
+            vertex_array.draw_arrays_instanced(mode, 1, 4, 1),
+            Err(OutOfBounds {
+                index: 0,
+                required: 5,
+                available: 4,
+            })
+        );
+        assert_eq!(mock.calls_to("glDrawArraysInstanced").len(), 1);
+    }
+
+    #[test]
+    fn instances_are_checked_up_to_the_last_one() {
+        let mock = Mock::new();
+        let context = mock_context(&mock);
+        let (vertex_array, _buffers) = vertex_array(&context);
+        let mode = gl::Primitive::TRIANGLES;
+
+        assert_eq!(vertex_array.draw_arrays_instanced(mode, 0, 4, 6), Ok(()));
+        assert_eq!(
+            vertex_array.draw_arrays_instanced(mode, 0, 4, 7),
+            Err(OutOfBounds {
+                index: 1,
+                required: 4,
+                available: 3,
+            })
+        );
+        assert_eq!(mock.calls_to("glDrawArraysInstanced").len(), 1);
+    }
+}
@@ -0,0 +1,159 @@
+use super::gl;
+use crate::types::{GLint, GLuint};
+
+/// How the attributes sourced from one buffer are laid out, see `VertexArray::attach`.
+///
+/// Attributes are placed one after another unless `at` moves the next one, and the stride
+/// defaults to the end of the last attribute.
+///
+/// ```
+/// use cac_gl_bindings::gl43 as gl;
+/// use cac_gl_bindings::safe::VertexLayout;
+///
+/// #[repr(C)]
+/// struct Vertex {
+///     position: [f32; 2],
+///     color: [u8; 4],
+/// }
+///
+/// let layout = VertexLayout::of::<Vertex>()
+///     .float(0, 2, gl::VertexAttributeKind::FLOAT)
+///     .at(std::mem::offset_of!(Vertex, color))
+///     .normalized(1, 4, gl::VertexAttributeKind::UNSIGNED_BYTE);
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct VertexLayout {
+    pub(super) attributes: Vec<Attribute>,
+    offset: usize,
+    stride: Option<usize>,
+    pub(super) divisor: GLuint,
+}
+
+#[derive(Copy, Clone, Debug)]
+pub(super) struct Attribute {
+    pub index: GLuint,
+    pub components: GLint,
+    pub kind: gl::VertexAttributeKind,
+    pub conversion: Conversion,
+    pub offset: usize,
+}
+
+/// What the shader sees of the stored values.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub(super) enum Conversion {
+    /// Converted to floats as they are.
+    Float,
+    /// Integers mapped to `[0, 1]` or `[-1, 1]`.
+    Normalized,
+    /// Integers kept as integers, see `vertex_attrib_i_pointer`.
+    Integer,
+}
+
+impl VertexLayout {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// A layout with the stride of `T`, for buffers of `#[repr(C)]` vertex structs.
+    #[must_use]
+    pub fn of<T>() -> Self {
+        Self::new().stride(std::mem::size_of::<T>())
+    }
+
+    /// Places the next attribute at `offset` bytes, e.g. from `std::mem::offset_of!`.
+    #[must_use]
+    pub const fn at(mut self, offset: usize) -> Self {
+        self.offset = offset;
+        self
+    }
+
+    /// Overrides the distance between two vertices in bytes.
+    #[must_use]
+    pub const fn stride(mut self, stride: usize) -> Self {
+        self.stride = Some(stride);
+        self
+    }
+
+    /// Advances all attributes of this layout once per `divisor` instances, `0` means per vertex.
+    #[must_use]
+    pub const fn divisor(mut self, divisor: GLuint) -> Self {
+        self.divisor = divisor;
+        self
+    }
+
+    /// An attribute the shader reads as floats, e.g. a `vec3` from three `FLOAT`s.
+    #[must_use]
+    pub fn float(self, index: GLuint, components: GLint, kind: gl::VertexAttributeKind) -> Self {
+        self.push(index, components, kind, Conversion::Float)
+    }
+
+    /// An integer attribute the shader reads as floats in `[0, 1]`, or `[-1, 1]` if signed.
+    #[must_use]
+    pub fn normalized(
+        self,
+        index: GLuint,
+        components: GLint,
+        kind: gl::VertexAttributeKind,
+    ) -> Self {
+        self.push(index, components, kind, Conversion::Normalized)
+    }
+
+    /// An integer attribute the shader reads as `int`, `uint` or one of their vectors.
+    #[must_use]
+    pub fn integer(self, index: GLuint, components: GLint, kind: gl::VertexAttributeKind) -> Self {
+        self.push(index, components, kind, Conversion::Integer)
+    }
+
+    fn push(
+        mut self,
+        index: GLuint,
+        components: GLint,
+        kind: gl::VertexAttributeKind,
+        conversion: Conversion,
+    ) -> Self {
+        self.attributes.push(Attribute {
+            index,
+            components,
+            kind,
+            conversion,
+            offset: self.offset,
+        });
+        self.offset += byte_size(kind, components);
+        self
+    }
+
+    /// The distance between two vertices in bytes.
+    pub(super) fn resolved_stride(&self) -> usize {
+        self.stride.unwrap_or_else(|| {
+            self.attributes
+                .iter()
+                .map(Attribute::end)
+                .max()
+                .unwrap_or_default()
+        })
+    }
+}
+
+impl Attribute {
+    /// The offset of the first byte after this attribute in the first vertex.
+    pub(super) fn end(&self) -> usize {
+        self.offset + byte_size(self.kind, self.components)
+    }
+}
+
+/// The size of one attribute in bytes, the packed kinds hold all components in 4 bytes.
+fn byte_size(kind: gl::VertexAttributeKind, components: GLint) -> usize {
+    let components = usize::try_from(components).unwrap_or_default();
+    match kind {
+        gl::VertexAttributeKind::INT_2_10_10_10_REV
+        | gl::VertexAttributeKind::UNSIGNED_INT_2_10_10_10_REV
+        | gl::VertexAttributeKind::UNSIGNED_INT_10F_11F_11F_REV => 4,
+        gl::VertexAttributeKind::BYTE | gl::VertexAttributeKind::UNSIGNED_BYTE => components,
+        gl::VertexAttributeKind::SHORT
+        | gl::VertexAttributeKind::UNSIGNED_SHORT
+        | gl::VertexAttributeKind::HALF_FLOAT => components * 2,
+        gl::VertexAttributeKind::DOUBLE => components * 8,
+        _ => components * 4,
+    }
+}
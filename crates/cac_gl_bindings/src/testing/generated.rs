@@ -0,0 +1,2193 @@
+// This file is generated by `cac_gl_generator` from the Khronos registry, do not edit it by hand.
+// Change `crates/cac_gl_generator/allowlist.toml` and run `cargo run -p cac_gl_generator` instead.
+
+use std::ffi::c_void;
+
+use super::{call, call_with_data};
+use crate::types::{
+    GLbitField, GLboolean, GLdouble, GLenum, GLfloat, GLint, GLintptr, GLsizei, GLsizeiptr, GLuint,
+};
+
+/// The stub for `symbol`, or null for symbols that aren't part of any generated `Api`.
+#[allow(clippy::too_many_lines)]
+pub fn stub(symbol: &str) -> *const c_void {
+    match symbol {
+        "glDebugMessageCallback" => glDebugMessageCallback as *const c_void,
+        "glDebugMessageControl" => glDebugMessageControl as *const c_void,
+        "glDebugMessageInsert" => glDebugMessageInsert as *const c_void,
+        "glGetDebugMessageLog" => glGetDebugMessageLog as *const c_void,
+        "glPushDebugGroup" => glPushDebugGroup as *const c_void,
+        "glPopDebugGroup" => glPopDebugGroup as *const c_void,
+        "glObjectLabel" => glObjectLabel as *const c_void,
+        "glObjectPtrLabel" => glObjectPtrLabel as *const c_void,
+        "glGetError" => glGetError as *const c_void,
+        "glEnable" => glEnable as *const c_void,
+        "glDisable" => glDisable as *const c_void,
+        "glIsEnabled" => glIsEnabled as *const c_void,
+        "glEnablei" => glEnablei as *const c_void,
+        "glDisablei" => glDisablei as *const c_void,
+        "glIsEnabledi" => glIsEnabledi as *const c_void,
+        "glViewport" => glViewport as *const c_void,
+        "glScissor" => glScissor as *const c_void,
+        "glBlendFunc" => glBlendFunc as *const c_void,
+        "glBlendFuncSeparate" => glBlendFuncSeparate as *const c_void,
+        "glBlendEquation" => glBlendEquation as *const c_void,
+        "glBlendEquationSeparate" => glBlendEquationSeparate as *const c_void,
+        "glBlendColor" => glBlendColor as *const c_void,
+        "glDepthFunc" => glDepthFunc as *const c_void,
+        "glDepthMask" => glDepthMask as *const c_void,
+        "glDepthRange" => glDepthRange as *const c_void,
+        "glDepthRangef" => glDepthRangef as *const c_void,
+        "glStencilFunc" => glStencilFunc as *const c_void,
+        "glStencilFuncSeparate" => glStencilFuncSeparate as *const c_void,
+        "glStencilOp" => glStencilOp as *const c_void,
+        "glStencilOpSeparate" => glStencilOpSeparate as *const c_void,
+        "glStencilMask" => glStencilMask as *const c_void,
+        "glStencilMaskSeparate" => glStencilMaskSeparate as *const c_void,
+        "glCullFace" => glCullFace as *const c_void,
+        "glFrontFace" => glFrontFace as *const c_void,
+        "glPolygonMode" => glPolygonMode as *const c_void,
+        "glPolygonOffset" => glPolygonOffset as *const c_void,
+        "glColorMask" => glColorMask as *const c_void,
+        "glLineWidth" => glLineWidth as *const c_void,
+        "glPointSize" => glPointSize as *const c_void,
+        "glClear" => glClear as *const c_void,
+        "glClearColor" => glClearColor as *const c_void,
+        "glDrawArrays" => glDrawArrays as *const c_void,
+        "glDrawElements" => glDrawElements as *const c_void,
+        "glDrawRangeElements" => glDrawRangeElements as *const c_void,
+        "glDrawElementsBaseVertex" => glDrawElementsBaseVertex as *const c_void,
+        "glMultiDrawElements" => glMultiDrawElements as *const c_void,
+        "glMultiDrawElementsBaseVertex" => glMultiDrawElementsBaseVertex as *const c_void,
+        "glDrawArraysInstanced" => glDrawArraysInstanced as *const c_void,
+        "glDrawElementsInstanced" => glDrawElementsInstanced as *const c_void,
+        "glDrawElementsInstancedBaseVertex" => glDrawElementsInstancedBaseVertex as *const c_void,
+        "glDrawArraysInstancedBaseInstance" => glDrawArraysInstancedBaseInstance as *const c_void,
+        "glDrawElementsInstancedBaseInstance" => {
+            glDrawElementsInstancedBaseInstance as *const c_void
+        }
+        "glDrawElementsInstancedBaseVertexBaseInstance" => {
+            glDrawElementsInstancedBaseVertexBaseInstance as *const c_void
+        }
+        "glDrawArraysIndirect" => glDrawArraysIndirect as *const c_void,
+        "glDrawElementsIndirect" => glDrawElementsIndirect as *const c_void,
+        "glMultiDrawArraysIndirect" => glMultiDrawArraysIndirect as *const c_void,
+        "glMultiDrawElementsIndirect" => glMultiDrawElementsIndirect as *const c_void,
+        "glPrimitiveRestartIndex" => glPrimitiveRestartIndex as *const c_void,
+        "glGenVertexArrays" => glGenVertexArrays as *const c_void,
+        "glBindVertexArray" => glBindVertexArray as *const c_void,
+        "glEnableVertexAttribArray" => glEnableVertexAttribArray as *const c_void,
+        "glVertexAttribPointer" => glVertexAttribPointer as *const c_void,
+        "glVertexAttribIPointer" => glVertexAttribIPointer as *const c_void,
+        "glVertexAttribDivisor" => glVertexAttribDivisor as *const c_void,
+        "glDeleteVertexArrays" => glDeleteVertexArrays as *const c_void,
+        "glCreateVertexArrays" => glCreateVertexArrays as *const c_void,
+        "glBindVertexBuffer" => glBindVertexBuffer as *const c_void,
+        "glVertexAttribFormat" => glVertexAttribFormat as *const c_void,
+        "glVertexAttribBinding" => glVertexAttribBinding as *const c_void,
+        "glGenBuffers" => glGenBuffers as *const c_void,
+        "glBindBuffer" => glBindBuffer as *const c_void,
+        "glBindBufferBase" => glBindBufferBase as *const c_void,
+        "glBindBufferRange" => glBindBufferRange as *const c_void,
+        "glBufferData" => glBufferData as *const c_void,
+        "glBufferSubData" => glBufferSubData as *const c_void,
+        "glGetBufferSubData" => glGetBufferSubData as *const c_void,
+        "glCopyBufferSubData" => glCopyBufferSubData as *const c_void,
+        "glMapBufferRange" => glMapBufferRange as *const c_void,
+        "glFlushMappedBufferRange" => glFlushMappedBufferRange as *const c_void,
+        "glUnmapBuffer" => glUnmapBuffer as *const c_void,
+        "glDeleteBuffers" => glDeleteBuffers as *const c_void,
+        "glCreateBuffers" => glCreateBuffers as *const c_void,
+        "glNamedBufferData" => glNamedBufferData as *const c_void,
+        "glCreateShader" => glCreateShader as *const c_void,
+        "glShaderSource" => glShaderSource as *const c_void,
+        "glCompileShader" => glCompileShader as *const c_void,
+        "glDeleteShader" => glDeleteShader as *const c_void,
+        "glSpecializeShader" => glSpecializeShader as *const c_void,
+        "glGetShaderiv" => glGetShaderiv as *const c_void,
+        "glGetShaderInfoLog" => glGetShaderInfoLog as *const c_void,
+        "glCreateProgram" => glCreateProgram as *const c_void,
+        "glAttachShader" => glAttachShader as *const c_void,
+        "glLinkProgram" => glLinkProgram as *const c_void,
+        "glDetachShader" => glDetachShader as *const c_void,
+        "glGetProgramiv" => glGetProgramiv as *const c_void,
+        "glGetProgramInfoLog" => glGetProgramInfoLog as *const c_void,
+        "glUseProgram" => glUseProgram as *const c_void,
+        "glDeleteProgram" => glDeleteProgram as *const c_void,
+        "glGenTextures" => glGenTextures as *const c_void,
+        "glBindTexture" => glBindTexture as *const c_void,
+        "glActiveTexture" => glActiveTexture as *const c_void,
+        "glTexImage2D" => glTexImage2D as *const c_void,
+        "glTexImage3D" => glTexImage3D as *const c_void,
+        "glTexStorage2D" => glTexStorage2D as *const c_void,
+        "glTexStorage3D" => glTexStorage3D as *const c_void,
+        "glTexSubImage2D" => glTexSubImage2D as *const c_void,
+        "glTexSubImage3D" => glTexSubImage3D as *const c_void,
+        "glTexParameteri" => glTexParameteri as *const c_void,
+        "glTexParameterf" => glTexParameterf as *const c_void,
+        "glGenerateMipmap" => glGenerateMipmap as *const c_void,
+        "glDeleteTextures" => glDeleteTextures as *const c_void,
+        "glGenFramebuffers" => glGenFramebuffers as *const c_void,
+        "glBindFramebuffer" => glBindFramebuffer as *const c_void,
+        "glFramebufferTexture2D" => glFramebufferTexture2D as *const c_void,
+        "glFramebufferRenderbuffer" => glFramebufferRenderbuffer as *const c_void,
+        "glCheckFramebufferStatus" => glCheckFramebufferStatus as *const c_void,
+        "glDrawBuffers" => glDrawBuffers as *const c_void,
+        "glReadBuffer" => glReadBuffer as *const c_void,
+        "glBlitFramebuffer" => glBlitFramebuffer as *const c_void,
+        "glDeleteFramebuffers" => glDeleteFramebuffers as *const c_void,
+        "glGenRenderbuffers" => glGenRenderbuffers as *const c_void,
+        "glBindRenderbuffer" => glBindRenderbuffer as *const c_void,
+        "glRenderbufferStorage" => glRenderbufferStorage as *const c_void,
+        "glRenderbufferStorageMultisample" => glRenderbufferStorageMultisample as *const c_void,
+        "glDeleteRenderbuffers" => glDeleteRenderbuffers as *const c_void,
+        "glGetUniformLocation" => glGetUniformLocation as *const c_void,
+        "glUniform1f" => glUniform1f as *const c_void,
+        "glUniform1fv" => glUniform1fv as *const c_void,
+        "glUniform1i" => glUniform1i as *const c_void,
+        "glUniform1iv" => glUniform1iv as *const c_void,
+        "glUniform1ui" => glUniform1ui as *const c_void,
+        "glUniform1uiv" => glUniform1uiv as *const c_void,
+        "glUniform2f" => glUniform2f as *const c_void,
+        "glUniform2fv" => glUniform2fv as *const c_void,
+        "glUniform2i" => glUniform2i as *const c_void,
+        "glUniform2iv" => glUniform2iv as *const c_void,
+        "glUniform2ui" => glUniform2ui as *const c_void,
+        "glUniform2uiv" => glUniform2uiv as *const c_void,
+        "glUniform3f" => glUniform3f as *const c_void,
+        "glUniform3fv" => glUniform3fv as *const c_void,
+        "glUniform3i" => glUniform3i as *const c_void,
+        "glUniform3iv" => glUniform3iv as *const c_void,
+        "glUniform3ui" => glUniform3ui as *const c_void,
+        "glUniform3uiv" => glUniform3uiv as *const c_void,
+        "glUniform4f" => glUniform4f as *const c_void,
+        "glUniform4fv" => glUniform4fv as *const c_void,
+        "glUniform4i" => glUniform4i as *const c_void,
+        "glUniform4iv" => glUniform4iv as *const c_void,
+        "glUniform4ui" => glUniform4ui as *const c_void,
+        "glUniform4uiv" => glUniform4uiv as *const c_void,
+        "glUniformMatrix2fv" => glUniformMatrix2fv as *const c_void,
+        "glUniformMatrix3fv" => glUniformMatrix3fv as *const c_void,
+        "glUniformMatrix4fv" => glUniformMatrix4fv as *const c_void,
+        "glUniformMatrix2x3fv" => glUniformMatrix2x3fv as *const c_void,
+        "glUniformMatrix3x2fv" => glUniformMatrix3x2fv as *const c_void,
+        "glUniformMatrix2x4fv" => glUniformMatrix2x4fv as *const c_void,
+        "glUniformMatrix4x2fv" => glUniformMatrix4x2fv as *const c_void,
+        "glUniformMatrix3x4fv" => glUniformMatrix3x4fv as *const c_void,
+        "glUniformMatrix4x3fv" => glUniformMatrix4x3fv as *const c_void,
+        "glGetActiveUniform" => glGetActiveUniform as *const c_void,
+        "glGetProgramInterfaceiv" => glGetProgramInterfaceiv as *const c_void,
+        "glDispatchCompute" => glDispatchCompute as *const c_void,
+        "glDispatchComputeIndirect" => glDispatchComputeIndirect as *const c_void,
+        "glMemoryBarrier" => glMemoryBarrier as *const c_void,
+        "glGetIntegerv" => glGetIntegerv as *const c_void,
+        "glGetIntegeri_v" => glGetIntegeri_v as *const c_void,
+        _ => std::ptr::null(),
+    }
+}
+
+/// Commands that write `n` fresh object names to their second parameter, like `glGenBuffers`.
+pub const WRITES_NAMES: &[&str] = &[
+    "glGenVertexArrays",
+    "glCreateVertexArrays",
+    "glGenBuffers",
+    "glCreateBuffers",
+    "glGenTextures",
+    "glGenFramebuffers",
+    "glGenRenderbuffers",
+];
+
+/// Commands that return a fresh object name, like `glCreateShader`.
+pub const RETURNS_NAME: &[&str] = &["glCreateShader", "glCreateProgram"];
+
+extern "system" fn glDebugMessageCallback(callback: *const c_void, user_param: *const c_void) {
+    call(
+        "glDebugMessageCallback",
+        &[
+            ("callback", callback.into()),
+            ("user_param", user_param.into()),
+        ],
+    );
+}
+
+extern "system" fn glDebugMessageControl(
+    source: GLenum,
+    kind: GLenum,
+    severity: GLenum,
+    count: GLsizei,
+    ids: *const c_void,
+    enabled: GLboolean,
+) {
+    call(
+        "glDebugMessageControl",
+        &[
+            ("source", source.into()),
+            ("kind", kind.into()),
+            ("severity", severity.into()),
+            ("count", count.into()),
+            ("ids", ids.into()),
+            ("enabled", enabled.into()),
+        ],
+    );
+}
+
+extern "system" fn glDebugMessageInsert(
+    source: GLenum,
+    kind: GLenum,
+    id: GLuint,
+    severity: GLenum,
+    length: GLsizei,
+    buf: *const c_void,
+) {
+    call(
+        "glDebugMessageInsert",
+        &[
+            ("source", source.into()),
+            ("kind", kind.into()),
+            ("id", id.into()),
+            ("severity", severity.into()),
+            ("length", length.into()),
+            ("buf", buf.into()),
+        ],
+    );
+}
+
+extern "system" fn glGetDebugMessageLog(
+    count: GLuint,
+    buf_size: GLsizei,
+    sources: *const c_void,
+    types: *const c_void,
+    ids: *const c_void,
+    severities: *const c_void,
+    lengths: *const c_void,
+    message_log: *const c_void,
+) -> GLuint {
+    call(
+        "glGetDebugMessageLog",
+        &[
+            ("count", count.into()),
+            ("buf_size", buf_size.into()),
+            ("sources", sources.into()),
+            ("types", types.into()),
+            ("ids", ids.into()),
+            ("severities", severities.into()),
+            ("lengths", lengths.into()),
+            ("message_log", message_log.into()),
+        ],
+    )
+    .into()
+}
+
+extern "system" fn glPushDebugGroup(
+    source: GLenum,
+    id: GLuint,
+    length: GLsizei,
+    message: *const c_void,
+) {
+    call(
+        "glPushDebugGroup",
+        &[
+            ("source", source.into()),
+            ("id", id.into()),
+            ("length", length.into()),
+            ("message", message.into()),
+        ],
+    );
+}
+
+extern "system" fn glPopDebugGroup() {
+    call("glPopDebugGroup", &[]);
+}
+
+extern "system" fn glObjectLabel(
+    identifier: GLenum,
+    name: GLuint,
+    length: GLsizei,
+    label: *const c_void,
+) {
+    call(
+        "glObjectLabel",
+        &[
+            ("identifier", identifier.into()),
+            ("name", name.into()),
+            ("length", length.into()),
+            ("label", label.into()),
+        ],
+    );
+}
+
+extern "system" fn glObjectPtrLabel(ptr: *const c_void, length: GLsizei, label: *const c_void) {
+    call(
+        "glObjectPtrLabel",
+        &[
+            ("ptr", ptr.into()),
+            ("length", length.into()),
+            ("label", label.into()),
+        ],
+    );
+}
+
+extern "system" fn glGetError() -> GLenum {
+    call("glGetError", &[]).into()
+}
+
+extern "system" fn glEnable(cap: GLenum) {
+    call("glEnable", &[("cap", cap.into())]);
+}
+
+extern "system" fn glDisable(cap: GLenum) {
+    call("glDisable", &[("cap", cap.into())]);
+}
+
+extern "system" fn glIsEnabled(cap: GLenum) -> GLboolean {
+    call("glIsEnabled", &[("cap", cap.into())]).into()
+}
+
+extern "system" fn glEnablei(target: GLenum, index: GLuint) {
+    call(
+        "glEnablei",
+        &[("target", target.into()), ("index", index.into())],
+    );
+}
+
+extern "system" fn glDisablei(target: GLenum, index: GLuint) {
+    call(
+        "glDisablei",
+        &[("target", target.into()), ("index", index.into())],
+    );
+}
+
+extern "system" fn glIsEnabledi(target: GLenum, index: GLuint) -> GLboolean {
+    call(
+        "glIsEnabledi",
+        &[("target", target.into()), ("index", index.into())],
+    )
+    .into()
+}
+
+extern "system" fn glViewport(x: GLint, y: GLint, width: GLsizei, height: GLsizei) {
+    call(
+        "glViewport",
+        &[
+            ("x", x.into()),
+            ("y", y.into()),
+            ("width", width.into()),
+            ("height", height.into()),
+        ],
+    );
+}
+
+extern "system" fn glScissor(x: GLint, y: GLint, width: GLsizei, height: GLsizei) {
+    call(
+        "glScissor",
+        &[
+            ("x", x.into()),
+            ("y", y.into()),
+            ("width", width.into()),
+            ("height", height.into()),
+        ],
+    );
+}
+
+extern "system" fn glBlendFunc(sfactor: GLenum, dfactor: GLenum) {
+    call(
+        "glBlendFunc",
+        &[("sfactor", sfactor.into()), ("dfactor", dfactor.into())],
+    );
+}
+
+extern "system" fn glBlendFuncSeparate(
+    sfactor_rgb: GLenum,
+    dfactor_rgb: GLenum,
+    sfactor_alpha: GLenum,
+    dfactor_alpha: GLenum,
+) {
+    call(
+        "glBlendFuncSeparate",
+        &[
+            ("sfactor_rgb", sfactor_rgb.into()),
+            ("dfactor_rgb", dfactor_rgb.into()),
+            ("sfactor_alpha", sfactor_alpha.into()),
+            ("dfactor_alpha", dfactor_alpha.into()),
+        ],
+    );
+}
+
+extern "system" fn glBlendEquation(mode: GLenum) {
+    call("glBlendEquation", &[("mode", mode.into())]);
+}
+
+extern "system" fn glBlendEquationSeparate(mode_rgb: GLenum, mode_alpha: GLenum) {
+    call(
+        "glBlendEquationSeparate",
+        &[
+            ("mode_rgb", mode_rgb.into()),
+            ("mode_alpha", mode_alpha.into()),
+        ],
+    );
+}
+
+extern "system" fn glBlendColor(red: GLfloat, green: GLfloat, blue: GLfloat, alpha: GLfloat) {
+    call(
+        "glBlendColor",
+        &[
+            ("red", red.into()),
+            ("green", green.into()),
+            ("blue", blue.into()),
+            ("alpha", alpha.into()),
+        ],
+    );
+}
+
+extern "system" fn glDepthFunc(func: GLenum) {
+    call("glDepthFunc", &[("func", func.into())]);
+}
+
+extern "system" fn glDepthMask(flag: GLboolean) {
+    call("glDepthMask", &[("flag", flag.into())]);
+}
+
+extern "system" fn glDepthRange(n: GLdouble, f: GLdouble) {
+    call("glDepthRange", &[("n", n.into()), ("f", f.into())]);
+}
+
+extern "system" fn glDepthRangef(n: GLfloat, f: GLfloat) {
+    call("glDepthRangef", &[("n", n.into()), ("f", f.into())]);
+}
+
+extern "system" fn glStencilFunc(func: GLenum, reference: GLint, mask: GLuint) {
+    call(
+        "glStencilFunc",
+        &[
+            ("func", func.into()),
+            ("reference", reference.into()),
+            ("mask", mask.into()),
+        ],
+    );
+}
+
+extern "system" fn glStencilFuncSeparate(
+    face: GLenum,
+    func: GLenum,
+    reference: GLint,
+    mask: GLuint,
+) {
+    call(
+        "glStencilFuncSeparate",
+        &[
+            ("face", face.into()),
+            ("func", func.into()),
+            ("reference", reference.into()),
+            ("mask", mask.into()),
+        ],
+    );
+}
+
+extern "system" fn glStencilOp(fail: GLenum, zfail: GLenum, zpass: GLenum) {
+    call(
+        "glStencilOp",
+        &[
+            ("fail", fail.into()),
+            ("zfail", zfail.into()),
+            ("zpass", zpass.into()),
+        ],
+    );
+}
+
+extern "system" fn glStencilOpSeparate(
+    face: GLenum,
+    sfail: GLenum,
+    dpfail: GLenum,
+    dppass: GLenum,
+) {
+    call(
+        "glStencilOpSeparate",
+        &[
+            ("face", face.into()),
+            ("sfail", sfail.into()),
+            ("dpfail", dpfail.into()),
+            ("dppass", dppass.into()),
+        ],
+    );
+}
+
+extern "system" fn glStencilMask(mask: GLuint) {
+    call("glStencilMask", &[("mask", mask.into())]);
+}
+
+extern "system" fn glStencilMaskSeparate(face: GLenum, mask: GLuint) {
+    call(
+        "glStencilMaskSeparate",
+        &[("face", face.into()), ("mask", mask.into())],
+    );
+}
+
+extern "system" fn glCullFace(mode: GLenum) {
+    call("glCullFace", &[("mode", mode.into())]);
+}
+
+extern "system" fn glFrontFace(mode: GLenum) {
+    call("glFrontFace", &[("mode", mode.into())]);
+}
+
+extern "system" fn glPolygonMode(face: GLenum, mode: GLenum) {
+    call(
+        "glPolygonMode",
+        &[("face", face.into()), ("mode", mode.into())],
+    );
+}
+
+extern "system" fn glPolygonOffset(factor: GLfloat, units: GLfloat) {
+    call(
+        "glPolygonOffset",
+        &[("factor", factor.into()), ("units", units.into())],
+    );
+}
+
+extern "system" fn glColorMask(
+    red: GLboolean,
+    green: GLboolean,
+    blue: GLboolean,
+    alpha: GLboolean,
+) {
+    call(
+        "glColorMask",
+        &[
+            ("red", red.into()),
+            ("green", green.into()),
+            ("blue", blue.into()),
+            ("alpha", alpha.into()),
+        ],
+    );
+}
+
+extern "system" fn glLineWidth(width: GLfloat) {
+    call("glLineWidth", &[("width", width.into())]);
+}
+
+extern "system" fn glPointSize(size: GLfloat) {
+    call("glPointSize", &[("size", size.into())]);
+}
+
+extern "system" fn glClear(mask: GLbitField) {
+    call("glClear", &[("mask", mask.into())]);
+}
+
+extern "system" fn glClearColor(red: GLfloat, green: GLfloat, blue: GLfloat, alpha: GLfloat) {
+    call(
+        "glClearColor",
+        &[
+            ("red", red.into()),
+            ("green", green.into()),
+            ("blue", blue.into()),
+            ("alpha", alpha.into()),
+        ],
+    );
+}
+
+extern "system" fn glDrawArrays(mode: GLenum, first: GLint, count: GLsizei) {
+    call(
+        "glDrawArrays",
+        &[
+            ("mode", mode.into()),
+            ("first", first.into()),
+            ("count", count.into()),
+        ],
+    );
+}
+
+extern "system" fn glDrawElements(
+    mode: GLenum,
+    count: GLsizei,
+    kind: GLenum,
+    indices: *const c_void,
+) {
+    call(
+        "glDrawElements",
+        &[
+            ("mode", mode.into()),
+            ("count", count.into()),
+            ("kind", kind.into()),
+            ("indices", indices.into()),
+        ],
+    );
+}
+
+extern "system" fn glDrawRangeElements(
+    mode: GLenum,
+    start: GLuint,
+    end: GLuint,
+    count: GLsizei,
+    kind: GLenum,
+    indices: *const c_void,
+) {
+    call(
+        "glDrawRangeElements",
+        &[
+            ("mode", mode.into()),
+            ("start", start.into()),
+            ("end", end.into()),
+            ("count", count.into()),
+            ("kind", kind.into()),
+            ("indices", indices.into()),
+        ],
+    );
+}
+
+extern "system" fn glDrawElementsBaseVertex(
+    mode: GLenum,
+    count: GLsizei,
+    kind: GLenum,
+    indices: *const c_void,
+    basevertex: GLint,
+) {
+    call(
+        "glDrawElementsBaseVertex",
+        &[
+            ("mode", mode.into()),
+            ("count", count.into()),
+            ("kind", kind.into()),
+            ("indices", indices.into()),
+            ("basevertex", basevertex.into()),
+        ],
+    );
+}
+
+extern "system" fn glMultiDrawElements(
+    mode: GLenum,
+    count: *const c_void,
+    kind: GLenum,
+    indices: *const c_void,
+    drawcount: GLsizei,
+) {
+    call(
+        "glMultiDrawElements",
+        &[
+            ("mode", mode.into()),
+            ("count", count.into()),
+            ("kind", kind.into()),
+            ("indices", indices.into()),
+            ("drawcount", drawcount.into()),
+        ],
+    );
+}
+
+extern "system" fn glMultiDrawElementsBaseVertex(
+    mode: GLenum,
+    count: *const c_void,
+    kind: GLenum,
+    indices: *const c_void,
+    drawcount: GLsizei,
+    basevertex: *const c_void,
+) {
+    call(
+        "glMultiDrawElementsBaseVertex",
+        &[
+            ("mode", mode.into()),
+            ("count", count.into()),
+            ("kind", kind.into()),
+            ("indices", indices.into()),
+            ("drawcount", drawcount.into()),
+            ("basevertex", basevertex.into()),
+        ],
+    );
+}
+
+extern "system" fn glDrawArraysInstanced(
+    mode: GLenum,
+    first: GLint,
+    count: GLsizei,
+    instancecount: GLsizei,
+) {
+    call(
+        "glDrawArraysInstanced",
+        &[
+            ("mode", mode.into()),
+            ("first", first.into()),
+            ("count", count.into()),
+            ("instancecount", instancecount.into()),
+        ],
+    );
+}
+
+extern "system" fn glDrawElementsInstanced(
+    mode: GLenum,
+    count: GLsizei,
+    kind: GLenum,
+    indices: *const c_void,
+    instancecount: GLsizei,
+) {
+    call(
+        "glDrawElementsInstanced",
+        &[
+            ("mode", mode.into()),
+            ("count", count.into()),
+            ("kind", kind.into()),
+            ("indices", indices.into()),
+            ("instancecount", instancecount.into()),
+        ],
+    );
+}
+
+extern "system" fn glDrawElementsInstancedBaseVertex(
+    mode: GLenum,
+    count: GLsizei,
+    kind: GLenum,
+    indices: *const c_void,
+    instancecount: GLsizei,
+    basevertex: GLint,
+) {
+    call(
+        "glDrawElementsInstancedBaseVertex",
+        &[
+            ("mode", mode.into()),
+            ("count", count.into()),
+            ("kind", kind.into()),
+            ("indices", indices.into()),
+            ("instancecount", instancecount.into()),
+            ("basevertex", basevertex.into()),
+        ],
+    );
+}
+
+extern "system" fn glDrawArraysInstancedBaseInstance(
+    mode: GLenum,
+    first: GLint,
+    count: GLsizei,
+    instancecount: GLsizei,
+    baseinstance: GLuint,
+) {
+    call(
+        "glDrawArraysInstancedBaseInstance",
+        &[
+            ("mode", mode.into()),
+            ("first", first.into()),
+            ("count", count.into()),
+            ("instancecount", instancecount.into()),
+            ("baseinstance", baseinstance.into()),
+        ],
+    );
+}
+
+extern "system" fn glDrawElementsInstancedBaseInstance(
+    mode: GLenum,
+    count: GLsizei,
+    kind: GLenum,
+    indices: *const c_void,
+    instancecount: GLsizei,
+    baseinstance: GLuint,
+) {
+    call(
+        "glDrawElementsInstancedBaseInstance",
+        &[
+            ("mode", mode.into()),
+            ("count", count.into()),
+            ("kind", kind.into()),
+            ("indices", indices.into()),
+            ("instancecount", instancecount.into()),
+            ("baseinstance", baseinstance.into()),
+        ],
+    );
+}
+
+extern "system" fn glDrawElementsInstancedBaseVertexBaseInstance(
+    mode: GLenum,
+    count: GLsizei,
+    kind: GLenum,
+    indices: *const c_void,
+    instancecount: GLsizei,
+    basevertex: GLint,
+    baseinstance: GLuint,
+) {
+    call(
+        "glDrawElementsInstancedBaseVertexBaseInstance",
+        &[
+            ("mode", mode.into()),
+            ("count", count.into()),
+            ("kind", kind.into()),
+            ("indices", indices.into()),
+            ("instancecount", instancecount.into()),
+            ("basevertex", basevertex.into()),
+            ("baseinstance", baseinstance.into()),
+        ],
+    );
+}
+
+extern "system" fn glDrawArraysIndirect(mode: GLenum, indirect: *const c_void) {
+    call(
+        "glDrawArraysIndirect",
+        &[("mode", mode.into()), ("indirect", indirect.into())],
+    );
+}
+
+extern "system" fn glDrawElementsIndirect(mode: GLenum, kind: GLenum, indirect: *const c_void) {
+    call(
+        "glDrawElementsIndirect",
+        &[
+            ("mode", mode.into()),
+            ("kind", kind.into()),
+            ("indirect", indirect.into()),
+        ],
+    );
+}
+
+extern "system" fn glMultiDrawArraysIndirect(
+    mode: GLenum,
+    indirect: *const c_void,
+    drawcount: GLsizei,
+    stride: GLsizei,
+) {
+    call(
+        "glMultiDrawArraysIndirect",
+        &[
+            ("mode", mode.into()),
+            ("indirect", indirect.into()),
+            ("drawcount", drawcount.into()),
+            ("stride", stride.into()),
+        ],
+    );
+}
+
+extern "system" fn glMultiDrawElementsIndirect(
+    mode: GLenum,
+    kind: GLenum,
+    indirect: *const c_void,
+    drawcount: GLsizei,
+    stride: GLsizei,
+) {
+    call(
+        "glMultiDrawElementsIndirect",
+        &[
+            ("mode", mode.into()),
+            ("kind", kind.into()),
+            ("indirect", indirect.into()),
+            ("drawcount", drawcount.into()),
+            ("stride", stride.into()),
+        ],
+    );
+}
+
+extern "system" fn glPrimitiveRestartIndex(index: GLuint) {
+    call("glPrimitiveRestartIndex", &[("index", index.into())]);
+}
+
+extern "system" fn glGenVertexArrays(n: GLsizei, arrays: *const c_void) {
+    call(
+        "glGenVertexArrays",
+        &[("n", n.into()), ("arrays", arrays.into())],
+    );
+}
+
+extern "system" fn glBindVertexArray(array: GLuint) {
+    call("glBindVertexArray", &[("array", array.into())]);
+}
+
+extern "system" fn glEnableVertexAttribArray(index: GLuint) {
+    call("glEnableVertexAttribArray", &[("index", index.into())]);
+}
+
+extern "system" fn glVertexAttribPointer(
+    index: GLuint,
+    size: GLint,
+    kind: GLenum,
+    normalized: GLboolean,
+    stride: GLsizei,
+    pointer: *const c_void,
+) {
+    call(
+        "glVertexAttribPointer",
+        &[
+            ("index", index.into()),
+            ("size", size.into()),
+            ("kind", kind.into()),
+            ("normalized", normalized.into()),
+            ("stride", stride.into()),
+            ("pointer", pointer.into()),
+        ],
+    );
+}
+
+extern "system" fn glVertexAttribIPointer(
+    index: GLuint,
+    size: GLint,
+    kind: GLenum,
+    stride: GLsizei,
+    pointer: *const c_void,
+) {
+    call(
+        "glVertexAttribIPointer",
+        &[
+            ("index", index.into()),
+            ("size", size.into()),
+            ("kind", kind.into()),
+            ("stride", stride.into()),
+            ("pointer", pointer.into()),
+        ],
+    );
+}
+
+extern "system" fn glVertexAttribDivisor(index: GLuint, divisor: GLuint) {
+    call(
+        "glVertexAttribDivisor",
+        &[("index", index.into()), ("divisor", divisor.into())],
+    );
+}
+
+extern "system" fn glDeleteVertexArrays(n: GLsizei, arrays: *const c_void) {
+    call(
+        "glDeleteVertexArrays",
+        &[("n", n.into()), ("arrays", arrays.into())],
+    );
+}
+
+extern "system" fn glCreateVertexArrays(n: GLsizei, arrays: *const c_void) {
+    call(
+        "glCreateVertexArrays",
+        &[("n", n.into()), ("arrays", arrays.into())],
+    );
+}
+
+extern "system" fn glBindVertexBuffer(
+    bindingindex: GLuint,
+    buffer: GLuint,
+    offset: GLintptr,
+    stride: GLsizei,
+) {
+    call(
+        "glBindVertexBuffer",
+        &[
+            ("bindingindex", bindingindex.into()),
+            ("buffer", buffer.into()),
+            ("offset", offset.into()),
+            ("stride", stride.into()),
+        ],
+    );
+}
+
+extern "system" fn glVertexAttribFormat(
+    attribindex: GLuint,
+    size: GLint,
+    kind: GLenum,
+    normalized: GLboolean,
+    relativeoffset: GLuint,
+) {
+    call(
+        "glVertexAttribFormat",
+        &[
+            ("attribindex", attribindex.into()),
+            ("size", size.into()),
+            ("kind", kind.into()),
+            ("normalized", normalized.into()),
+            ("relativeoffset", relativeoffset.into()),
+        ],
+    );
+}
+
+extern "system" fn glVertexAttribBinding(attribindex: GLuint, bindingindex: GLuint) {
+    call(
+        "glVertexAttribBinding",
+        &[
+            ("attribindex", attribindex.into()),
+            ("bindingindex", bindingindex.into()),
+        ],
+    );
+}
+
+extern "system" fn glGenBuffers(n: GLsizei, buffers: *const c_void) {
+    call(
+        "glGenBuffers",
+        &[("n", n.into()), ("buffers", buffers.into())],
+    );
+}
+
+extern "system" fn glBindBuffer(target: GLenum, buffer: GLuint) {
+    call(
+        "glBindBuffer",
+        &[("target", target.into()), ("buffer", buffer.into())],
+    );
+}
+
+extern "system" fn glBindBufferBase(target: GLenum, index: GLuint, buffer: GLuint) {
+    call(
+        "glBindBufferBase",
+        &[
+            ("target", target.into()),
+            ("index", index.into()),
+            ("buffer", buffer.into()),
+        ],
+    );
+}
+
+extern "system" fn glBindBufferRange(
+    target: GLenum,
+    index: GLuint,
+    buffer: GLuint,
+    offset: GLintptr,
+    size: GLsizeiptr,
+) {
+    call(
+        "glBindBufferRange",
+        &[
+            ("target", target.into()),
+            ("index", index.into()),
+            ("buffer", buffer.into()),
+            ("offset", offset.into()),
+            ("size", size.into()),
+        ],
+    );
+}
+
+extern "system" fn glBufferData(
+    target: GLenum,
+    size: GLsizeiptr,
+    data: *const c_void,
+    usage: GLenum,
+) {
+    call_with_data(
+        "glBufferData",
+        &[
+            ("target", target.into()),
+            ("size", size.into()),
+            ("data", data.into()),
+            ("usage", usage.into()),
+        ],
+        data,
+        size,
+    );
+}
+
+extern "system" fn glBufferSubData(
+    target: GLenum,
+    offset: GLintptr,
+    size: GLsizeiptr,
+    data: *const c_void,
+) {
+    call_with_data(
+        "glBufferSubData",
+        &[
+            ("target", target.into()),
+            ("offset", offset.into()),
+            ("size", size.into()),
+            ("data", data.into()),
+        ],
+        data,
+        size,
+    );
+}
+
+extern "system" fn glGetBufferSubData(
+    target: GLenum,
+    offset: GLintptr,
+    size: GLsizeiptr,
+    data: *const c_void,
+) {
+    call(
+        "glGetBufferSubData",
+        &[
+            ("target", target.into()),
+            ("offset", offset.into()),
+            ("size", size.into()),
+            ("data", data.into()),
+        ],
+    );
+}
+
+extern "system" fn glCopyBufferSubData(
+    read_target: GLenum,
+    write_target: GLenum,
+    read_offset: GLintptr,
+    write_offset: GLintptr,
+    size: GLsizeiptr,
+) {
+    call(
+        "glCopyBufferSubData",
+        &[
+            ("read_target", read_target.into()),
+            ("write_target", write_target.into()),
+            ("read_offset", read_offset.into()),
+            ("write_offset", write_offset.into()),
+            ("size", size.into()),
+        ],
+    );
+}
+
+extern "system" fn glMapBufferRange(
+    target: GLenum,
+    offset: GLintptr,
+    length: GLsizeiptr,
+    access: GLbitField,
+) -> *const c_void {
+    call(
+        "glMapBufferRange",
+        &[
+            ("target", target.into()),
+            ("offset", offset.into()),
+            ("length", length.into()),
+            ("access", access.into()),
+        ],
+    )
+    .into()
+}
+
+extern "system" fn glFlushMappedBufferRange(target: GLenum, offset: GLintptr, length: GLsizeiptr) {
+    call(
+        "glFlushMappedBufferRange",
+        &[
+            ("target", target.into()),
+            ("offset", offset.into()),
+            ("length", length.into()),
+        ],
+    );
+}
+
+extern "system" fn glUnmapBuffer(target: GLenum) -> GLboolean {
+    call("glUnmapBuffer", &[("target", target.into())]).into()
+}
+
+extern "system" fn glDeleteBuffers(n: GLsizei, buffers: *const c_void) {
+    call(
+        "glDeleteBuffers",
+        &[("n", n.into()), ("buffers", buffers.into())],
+    );
+}
+
+extern "system" fn glCreateBuffers(n: GLsizei, buffers: *const c_void) {
+    call(
+        "glCreateBuffers",
+        &[("n", n.into()), ("buffers", buffers.into())],
+    );
+}
+
+extern "system" fn glNamedBufferData(
+    buffer: GLuint,
+    size: GLsizeiptr,
+    data: *const c_void,
+    usage: GLenum,
+) {
+    call_with_data(
+        "glNamedBufferData",
+        &[
+            ("buffer", buffer.into()),
+            ("size", size.into()),
+            ("data", data.into()),
+            ("usage", usage.into()),
+        ],
+        data,
+        size,
+    );
+}
+
+extern "system" fn glCreateShader(kind: GLenum) -> GLuint {
+    call("glCreateShader", &[("kind", kind.into())]).into()
+}
+
+extern "system" fn glShaderSource(
+    shader: GLuint,
+    count: GLsizei,
+    string: *const c_void,
+    length: *const c_void,
+) {
+    call(
+        "glShaderSource",
+        &[
+            ("shader", shader.into()),
+            ("count", count.into()),
+            ("string", string.into()),
+            ("length", length.into()),
+        ],
+    );
+}
+
+extern "system" fn glCompileShader(shader: GLuint) {
+    call("glCompileShader", &[("shader", shader.into())]);
+}
+
+extern "system" fn glDeleteShader(shader: GLuint) {
+    call("glDeleteShader", &[("shader", shader.into())]);
+}
+
+extern "system" fn glSpecializeShader(
+    shader: GLuint,
+    p_entry_point: *const c_void,
+    num_specialization_constants: GLuint,
+    p_constant_index: *const c_void,
+    p_constant_value: *const c_void,
+) {
+    call(
+        "glSpecializeShader",
+        &[
+            ("shader", shader.into()),
+            ("p_entry_point", p_entry_point.into()),
+            (
+                "num_specialization_constants",
+                num_specialization_constants.into(),
+            ),
+            ("p_constant_index", p_constant_index.into()),
+            ("p_constant_value", p_constant_value.into()),
+        ],
+    );
+}
+
+extern "system" fn glGetShaderiv(shader: GLuint, pname: GLenum, params: *const c_void) {
+    call(
+        "glGetShaderiv",
+        &[
+            ("shader", shader.into()),
+            ("pname", pname.into()),
+            ("params", params.into()),
+        ],
+    );
+}
+
+extern "system" fn glGetShaderInfoLog(
+    shader: GLuint,
+    buf_size: GLsizei,
+    length: *const c_void,
+    info_log: *const c_void,
+) {
+    call(
+        "glGetShaderInfoLog",
+        &[
+            ("shader", shader.into()),
+            ("buf_size", buf_size.into()),
+            ("length", length.into()),
+            ("info_log", info_log.into()),
+        ],
+    );
+}
+
+extern "system" fn glCreateProgram() -> GLuint {
+    call("glCreateProgram", &[]).into()
+}
+
+extern "system" fn glAttachShader(program: GLuint, shader: GLuint) {
+    call(
+        "glAttachShader",
+        &[("program", program.into()), ("shader", shader.into())],
+    );
+}
+
+extern "system" fn glLinkProgram(program: GLuint) {
+    call("glLinkProgram", &[("program", program.into())]);
+}
+
+extern "system" fn glDetachShader(program: GLuint, shader: GLuint) {
+    call(
+        "glDetachShader",
+        &[("program", program.into()), ("shader", shader.into())],
+    );
+}
+
+extern "system" fn glGetProgramiv(program: GLuint, pname: GLenum, params: *const c_void) {
+    call(
+        "glGetProgramiv",
+        &[
+            ("program", program.into()),
+            ("pname", pname.into()),
+            ("params", params.into()),
+        ],
+    );
+}
+
+extern "system" fn glGetProgramInfoLog(
+    program: GLuint,
+    buf_size: GLsizei,
+    length: *const c_void,
+    info_log: *const c_void,
+) {
+    call(
+        "glGetProgramInfoLog",
+        &[
+            ("program", program.into()),
+            ("buf_size", buf_size.into()),
+            ("length", length.into()),
+            ("info_log", info_log.into()),
+        ],
+    );
+}
+
+extern "system" fn glUseProgram(program: GLuint) {
+    call("glUseProgram", &[("program", program.into())]);
+}
+
+extern "system" fn glDeleteProgram(program: GLuint) {
+    call("glDeleteProgram", &[("program", program.into())]);
+}
+
+extern "system" fn glGenTextures(n: GLsizei, textures: *const c_void) {
+    call(
+        "glGenTextures",
+        &[("n", n.into()), ("textures", textures.into())],
+    );
+}
+
+extern "system" fn glBindTexture(target: GLenum, texture: GLuint) {
+    call(
+        "glBindTexture",
+        &[("target", target.into()), ("texture", texture.into())],
+    );
+}
+
+extern "system" fn glActiveTexture(texture: GLenum) {
+    call("glActiveTexture", &[("texture", texture.into())]);
+}
+
+extern "system" fn glTexImage2D(
+    target: GLenum,
+    level: GLint,
+    internalformat: GLint,
+    width: GLsizei,
+    height: GLsizei,
+    border: GLint,
+    format: GLenum,
+    kind: GLenum,
+    pixels: *const c_void,
+) {
+    call(
+        "glTexImage2D",
+        &[
+            ("target", target.into()),
+            ("level", level.into()),
+            ("internalformat", internalformat.into()),
+            ("width", width.into()),
+            ("height", height.into()),
+            ("border", border.into()),
+            ("format", format.into()),
+            ("kind", kind.into()),
+            ("pixels", pixels.into()),
+        ],
+    );
+}
+
+extern "system" fn glTexImage3D(
+    target: GLenum,
+    level: GLint,
+    internalformat: GLint,
+    width: GLsizei,
+    height: GLsizei,
+    depth: GLsizei,
+    border: GLint,
+    format: GLenum,
+    kind: GLenum,
+    pixels: *const c_void,
+) {
+    call(
+        "glTexImage3D",
+        &[
+            ("target", target.into()),
+            ("level", level.into()),
+            ("internalformat", internalformat.into()),
+            ("width", width.into()),
+            ("height", height.into()),
+            ("depth", depth.into()),
+            ("border", border.into()),
+            ("format", format.into()),
+            ("kind", kind.into()),
+            ("pixels", pixels.into()),
+        ],
+    );
+}
+
+extern "system" fn glTexStorage2D(
+    target: GLenum,
+    levels: GLsizei,
+    internalformat: GLenum,
+    width: GLsizei,
+    height: GLsizei,
+) {
+    call(
+        "glTexStorage2D",
+        &[
+            ("target", target.into()),
+            ("levels", levels.into()),
+            ("internalformat", internalformat.into()),
+            ("width", width.into()),
+            ("height", height.into()),
+        ],
+    );
+}
+
+extern "system" fn glTexStorage3D(
+    target: GLenum,
+    levels: GLsizei,
+    internalformat: GLenum,
+    width: GLsizei,
+    height: GLsizei,
+    depth: GLsizei,
+) {
+    call(
+        "glTexStorage3D",
+        &[
+            ("target", target.into()),
+            ("levels", levels.into()),
+            ("internalformat", internalformat.into()),
+            ("width", width.into()),
+            ("height", height.into()),
+            ("depth", depth.into()),
+        ],
+    );
+}
+
+extern "system" fn glTexSubImage2D(
+    target: GLenum,
+    level: GLint,
+    xoffset: GLint,
+    yoffset: GLint,
+    width: GLsizei,
+    height: GLsizei,
+    format: GLenum,
+    kind: GLenum,
+    pixels: *const c_void,
+) {
+    call(
+        "glTexSubImage2D",
+        &[
+            ("target", target.into()),
+            ("level", level.into()),
+            ("xoffset", xoffset.into()),
+            ("yoffset", yoffset.into()),
+            ("width", width.into()),
+            ("height", height.into()),
+            ("format", format.into()),
+            ("kind", kind.into()),
+            ("pixels", pixels.into()),
+        ],
+    );
+}
+
+extern "system" fn glTexSubImage3D(
+    target: GLenum,
+    level: GLint,
+    xoffset: GLint,
+    yoffset: GLint,
+    zoffset: GLint,
+    width: GLsizei,
+    height: GLsizei,
+    depth: GLsizei,
+    format: GLenum,
+    kind: GLenum,
+    pixels: *const c_void,
+) {
+    call(
+        "glTexSubImage3D",
+        &[
+            ("target", target.into()),
+            ("level", level.into()),
+            ("xoffset", xoffset.into()),
+            ("yoffset", yoffset.into()),
+            ("zoffset", zoffset.into()),
+            ("width", width.into()),
+            ("height", height.into()),
+            ("depth", depth.into()),
+            ("format", format.into()),
+            ("kind", kind.into()),
+            ("pixels", pixels.into()),
+        ],
+    );
+}
+
+extern "system" fn glTexParameteri(target: GLenum, pname: GLenum, param: GLint) {
+    call(
+        "glTexParameteri",
+        &[
+            ("target", target.into()),
+            ("pname", pname.into()),
+            ("param", param.into()),
+        ],
+    );
+}
+
+extern "system" fn glTexParameterf(target: GLenum, pname: GLenum, param: GLfloat) {
+    call(
+        "glTexParameterf",
+        &[
+            ("target", target.into()),
+            ("pname", pname.into()),
+            ("param", param.into()),
+        ],
+    );
+}
+
+extern "system" fn glGenerateMipmap(target: GLenum) {
+    call("glGenerateMipmap", &[("target", target.into())]);
+}
+
+extern "system" fn glDeleteTextures(n: GLsizei, textures: *const c_void) {
+    call(
+        "glDeleteTextures",
+        &[("n", n.into()), ("textures", textures.into())],
+    );
+}
+
+extern "system" fn glGenFramebuffers(n: GLsizei, framebuffers: *const c_void) {
+    call(
+        "glGenFramebuffers",
+        &[("n", n.into()), ("framebuffers", framebuffers.into())],
+    );
+}
+
+extern "system" fn glBindFramebuffer(target: GLenum, framebuffer: GLuint) {
+    call(
+        "glBindFramebuffer",
+        &[
+            ("target", target.into()),
+            ("framebuffer", framebuffer.into()),
+        ],
+    );
+}
+
+extern "system" fn glFramebufferTexture2D(
+    target: GLenum,
+    attachment: GLenum,
+    textarget: GLenum,
+    texture: GLuint,
+    level: GLint,
+) {
+    call(
+        "glFramebufferTexture2D",
+        &[
+            ("target", target.into()),
+            ("attachment", attachment.into()),
+            ("textarget", textarget.into()),
+            ("texture", texture.into()),
+            ("level", level.into()),
+        ],
+    );
+}
+
+extern "system" fn glFramebufferRenderbuffer(
+    target: GLenum,
+    attachment: GLenum,
+    renderbuffertarget: GLenum,
+    renderbuffer: GLuint,
+) {
+    call(
+        "glFramebufferRenderbuffer",
+        &[
+            ("target", target.into()),
+            ("attachment", attachment.into()),
+            ("renderbuffertarget", renderbuffertarget.into()),
+            ("renderbuffer", renderbuffer.into()),
+        ],
+    );
+}
+
+extern "system" fn glCheckFramebufferStatus(target: GLenum) -> GLenum {
+    call("glCheckFramebufferStatus", &[("target", target.into())]).into()
+}
+
+extern "system" fn glDrawBuffers(n: GLsizei, bufs: *const c_void) {
+    call("glDrawBuffers", &[("n", n.into()), ("bufs", bufs.into())]);
+}
+
+extern "system" fn glReadBuffer(src: GLenum) {
+    call("glReadBuffer", &[("src", src.into())]);
+}
+
+extern "system" fn glBlitFramebuffer(
+    src_x_0: GLint,
+    src_y_0: GLint,
+    src_x_1: GLint,
+    src_y_1: GLint,
+    dst_x_0: GLint,
+    dst_y_0: GLint,
+    dst_x_1: GLint,
+    dst_y_1: GLint,
+    mask: GLbitField,
+    filter: GLenum,
+) {
+    call(
+        "glBlitFramebuffer",
+        &[
+            ("src_x_0", src_x_0.into()),
+            ("src_y_0", src_y_0.into()),
+            ("src_x_1", src_x_1.into()),
+            ("src_y_1", src_y_1.into()),
+            ("dst_x_0", dst_x_0.into()),
+            ("dst_y_0", dst_y_0.into()),
+            ("dst_x_1", dst_x_1.into()),
+            ("dst_y_1", dst_y_1.into()),
+            ("mask", mask.into()),
+            ("filter", filter.into()),
+        ],
+    );
+}
+
+extern "system" fn glDeleteFramebuffers(n: GLsizei, framebuffers: *const c_void) {
+    call(
+        "glDeleteFramebuffers",
+        &[("n", n.into()), ("framebuffers", framebuffers.into())],
+    );
+}
+
+extern "system" fn glGenRenderbuffers(n: GLsizei, renderbuffers: *const c_void) {
+    call(
+        "glGenRenderbuffers",
+        &[("n", n.into()), ("renderbuffers", renderbuffers.into())],
+    );
+}
+
+extern "system" fn glBindRenderbuffer(target: GLenum, renderbuffer: GLuint) {
+    call(
+        "glBindRenderbuffer",
+        &[
+            ("target", target.into()),
+            ("renderbuffer", renderbuffer.into()),
+        ],
+    );
+}
+
+extern "system" fn glRenderbufferStorage(
+    target: GLenum,
+    internalformat: GLenum,
+    width: GLsizei,
+    height: GLsizei,
+) {
+    call(
+        "glRenderbufferStorage",
+        &[
+            ("target", target.into()),
+            ("internalformat", internalformat.into()),
+            ("width", width.into()),
+            ("height", height.into()),
+        ],
+    );
+}
+
+extern "system" fn glRenderbufferStorageMultisample(
+    target: GLenum,
+    samples: GLsizei,
+    internalformat: GLenum,
+    width: GLsizei,
+    height: GLsizei,
+) {
+    call(
+        "glRenderbufferStorageMultisample",
+        &[
+            ("target", target.into()),
+            ("samples", samples.into()),
+            ("internalformat", internalformat.into()),
+            ("width", width.into()),
+            ("height", height.into()),
+        ],
+    );
+}
+
+extern "system" fn glDeleteRenderbuffers(n: GLsizei, renderbuffers: *const c_void) {
+    call(
+        "glDeleteRenderbuffers",
+        &[("n", n.into()), ("renderbuffers", renderbuffers.into())],
+    );
+}
+
+extern "system" fn glGetUniformLocation(program: GLuint, name: *const c_void) -> GLint {
+    call(
+        "glGetUniformLocation",
+        &[("program", program.into()), ("name", name.into())],
+    )
+    .into()
+}
+
+extern "system" fn glUniform1f(location: GLint, v0: GLfloat) {
+    call(
+        "glUniform1f",
+        &[("location", location.into()), ("v0", v0.into())],
+    );
+}
+
+extern "system" fn glUniform1fv(location: GLint, count: GLsizei, value: *const c_void) {
+    call(
+        "glUniform1fv",
+        &[
+            ("location", location.into()),
+            ("count", count.into()),
+            ("value", value.into()),
+        ],
+    );
+}
+
+extern "system" fn glUniform1i(location: GLint, v0: GLint) {
+    call(
+        "glUniform1i",
+        &[("location", location.into()), ("v0", v0.into())],
+    );
+}
+
+extern "system" fn glUniform1iv(location: GLint, count: GLsizei, value: *const c_void) {
+    call(
+        "glUniform1iv",
+        &[
+            ("location", location.into()),
+            ("count", count.into()),
+            ("value", value.into()),
+        ],
+    );
+}
+
+extern "system" fn glUniform1ui(location: GLint, v0: GLuint) {
+    call(
+        "glUniform1ui",
+        &[("location", location.into()), ("v0", v0.into())],
+    );
+}
+
+extern "system" fn glUniform1uiv(location: GLint, count: GLsizei, value: *const c_void) {
+    call(
+        "glUniform1uiv",
+        &[
+            ("location", location.into()),
+            ("count", count.into()),
+            ("value", value.into()),
+        ],
+    );
+}
+
+extern "system" fn glUniform2f(location: GLint, v0: GLfloat, v1: GLfloat) {
+    call(
+        "glUniform2f",
+        &[
+            ("location", location.into()),
+            ("v0", v0.into()),
+            ("v1", v1.into()),
+        ],
+    );
+}
+
+extern "system" fn glUniform2fv(location: GLint, count: GLsizei, value: *const c_void) {
+    call(
+        "glUniform2fv",
+        &[
+            ("location", location.into()),
+            ("count", count.into()),
+            ("value", value.into()),
+        ],
+    );
+}
+
+extern "system" fn glUniform2i(location: GLint, v0: GLint, v1: GLint) {
+    call(
+        "glUniform2i",
+        &[
+            ("location", location.into()),
+            ("v0", v0.into()),
+            ("v1", v1.into()),
+        ],
+    );
+}
+
+extern "system" fn glUniform2iv(location: GLint, count: GLsizei, value: *const c_void) {
+    call(
+        "glUniform2iv",
+        &[
+            ("location", location.into()),
+            ("count", count.into()),
+            ("value", value.into()),
+        ],
+    );
+}
+
+extern "system" fn glUniform2ui(location: GLint, v0: GLuint, v1: GLuint) {
+    call(
+        "glUniform2ui",
+        &[
+            ("location", location.into()),
+            ("v0", v0.into()),
+            ("v1", v1.into()),
+        ],
+    );
+}
+
+extern "system" fn glUniform2uiv(location: GLint, count: GLsizei, value: *const c_void) {
+    call(
+        "glUniform2uiv",
+        &[
+            ("location", location.into()),
+            ("count", count.into()),
+            ("value", value.into()),
+        ],
+    );
+}
+
+extern "system" fn glUniform3f(location: GLint, v0: GLfloat, v1: GLfloat, v2: GLfloat) {
+    call(
+        "glUniform3f",
+        &[
+            ("location", location.into()),
+            ("v0", v0.into()),
+            ("v1", v1.into()),
+            ("v2", v2.into()),
+        ],
+    );
+}
+
+extern "system" fn glUniform3fv(location: GLint, count: GLsizei, value: *const c_void) {
+    call(
+        "glUniform3fv",
+        &[
+            ("location", location.into()),
+            ("count", count.into()),
+            ("value", value.into()),
+        ],
+    );
+}
+
+extern "system" fn glUniform3i(location: GLint, v0: GLint, v1: GLint, v2: GLint) {
+    call(
+        "glUniform3i",
+        &[
+            ("location", location.into()),
+            ("v0", v0.into()),
+            ("v1", v1.into()),
+            ("v2", v2.into()),
+        ],
+    );
+}
+
+extern "system" fn glUniform3iv(location: GLint, count: GLsizei, value: *const c_void) {
+    call(
+        "glUniform3iv",
+        &[
+            ("location", location.into()),
+            ("count", count.into()),
+            ("value", value.into()),
+        ],
+    );
+}
+
+extern "system" fn glUniform3ui(location: GLint, v0: GLuint, v1: GLuint, v2: GLuint) {
+    call(
+        "glUniform3ui",
+        &[
+            ("location", location.into()),
+            ("v0", v0.into()),
+            ("v1", v1.into()),
+            ("v2", v2.into()),
+        ],
+    );
+}
+
+extern "system" fn glUniform3uiv(location: GLint, count: GLsizei, value: *const c_void) {
+    call(
+        "glUniform3uiv",
+        &[
+            ("location", location.into()),
+            ("count", count.into()),
+            ("value", value.into()),
+        ],
+    );
+}
+
+extern "system" fn glUniform4f(
+    location: GLint,
+    v0: GLfloat,
+    v1: GLfloat,
+    v2: GLfloat,
+    v3: GLfloat,
+) {
+    call(
+        "glUniform4f",
+        &[
+            ("location", location.into()),
+            ("v0", v0.into()),
+            ("v1", v1.into()),
+            ("v2", v2.into()),
+            ("v3", v3.into()),
+        ],
+    );
+}
+
+extern "system" fn glUniform4fv(location: GLint, count: GLsizei, value: *const c_void) {
+    call(
+        "glUniform4fv",
+        &[
+            ("location", location.into()),
+            ("count", count.into()),
+            ("value", value.into()),
+        ],
+    );
+}
+
+extern "system" fn glUniform4i(location: GLint, v0: GLint, v1: GLint, v2: GLint, v3: GLint) {
+    call(
+        "glUniform4i",
+        &[
+            ("location", location.into()),
+            ("v0", v0.into()),
+            ("v1", v1.into()),
+            ("v2", v2.into()),
+            ("v3", v3.into()),
+        ],
+    );
+}
+
+extern "system" fn glUniform4iv(location: GLint, count: GLsizei, value: *const c_void) {
+    call(
+        "glUniform4iv",
+        &[
+            ("location", location.into()),
+            ("count", count.into()),
+            ("value", value.into()),
+        ],
+    );
+}
+
+extern "system" fn glUniform4ui(location: GLint, v0: GLuint, v1: GLuint, v2: GLuint, v3: GLuint) {
+    call(
+        "glUniform4ui",
+        &[
+            ("location", location.into()),
+            ("v0", v0.into()),
+            ("v1", v1.into()),
+            ("v2", v2.into()),
+            ("v3", v3.into()),
+        ],
+    );
+}
+
+extern "system" fn glUniform4uiv(location: GLint, count: GLsizei, value: *const c_void) {
+    call(
+        "glUniform4uiv",
+        &[
+            ("location", location.into()),
+            ("count", count.into()),
+            ("value", value.into()),
+        ],
+    );
+}
+
+extern "system" fn glUniformMatrix2fv(
+    location: GLint,
+    count: GLsizei,
+    transpose: GLboolean,
+    value: *const c_void,
+) {
+    call(
+        "glUniformMatrix2fv",
+        &[
+            ("location", location.into()),
+            ("count", count.into()),
+            ("transpose", transpose.into()),
+            ("value", value.into()),
+        ],
+    );
+}
+
+extern "system" fn glUniformMatrix3fv(
+    location: GLint,
+    count: GLsizei,
+    transpose: GLboolean,
+    value: *const c_void,
+) {
+    call(
+        "glUniformMatrix3fv",
+        &[
+            ("location", location.into()),
+            ("count", count.into()),
+            ("transpose", transpose.into()),
+            ("value", value.into()),
+        ],
+    );
+}
+
+extern "system" fn glUniformMatrix4fv(
+    location: GLint,
+    count: GLsizei,
+    transpose: GLboolean,
+    value: *const c_void,
+) {
+    call(
+        "glUniformMatrix4fv",
+        &[
+            ("location", location.into()),
+            ("count", count.into()),
+            ("transpose", transpose.into()),
+            ("value", value.into()),
+        ],
+    );
+}
+
+extern "system" fn glUniformMatrix2x3fv(
+    location: GLint,
+    count: GLsizei,
+    transpose: GLboolean,
+    value: *const c_void,
+) {
+    call(
+        "glUniformMatrix2x3fv",
+        &[
+            ("location", location.into()),
+            ("count", count.into()),
+            ("transpose", transpose.into()),
+            ("value", value.into()),
+        ],
+    );
+}
+
+extern "system" fn glUniformMatrix3x2fv(
+    location: GLint,
+    count: GLsizei,
+    transpose: GLboolean,
+    value: *const c_void,
+) {
+    call(
+        "glUniformMatrix3x2fv",
+        &[
+            ("location", location.into()),
+            ("count", count.into()),
+            ("transpose", transpose.into()),
+            ("value", value.into()),
+        ],
+    );
+}
+
+extern "system" fn glUniformMatrix2x4fv(
+    location: GLint,
+    count: GLsizei,
+    transpose: GLboolean,
+    value: *const c_void,
+) {
+    call(
+        "glUniformMatrix2x4fv",
+        &[
+            ("location", location.into()),
+            ("count", count.into()),
+            ("transpose", transpose.into()),
+            ("value", value.into()),
+        ],
+    );
+}
+
+extern "system" fn glUniformMatrix4x2fv(
+    location: GLint,
+    count: GLsizei,
+    transpose: GLboolean,
+    value: *const c_void,
+) {
+    call(
+        "glUniformMatrix4x2fv",
+        &[
+            ("location", location.into()),
+            ("count", count.into()),
+            ("transpose", transpose.into()),
+            ("value", value.into()),
+        ],
+    );
+}
+
+extern "system" fn glUniformMatrix3x4fv(
+    location: GLint,
+    count: GLsizei,
+    transpose: GLboolean,
+    value: *const c_void,
+) {
+    call(
+        "glUniformMatrix3x4fv",
+        &[
+            ("location", location.into()),
+            ("count", count.into()),
+            ("transpose", transpose.into()),
+            ("value", value.into()),
+        ],
+    );
+}
+
+extern "system" fn glUniformMatrix4x3fv(
+    location: GLint,
+    count: GLsizei,
+    transpose: GLboolean,
+    value: *const c_void,
+) {
+    call(
+        "glUniformMatrix4x3fv",
+        &[
+            ("location", location.into()),
+            ("count", count.into()),
+            ("transpose", transpose.into()),
+            ("value", value.into()),
+        ],
+    );
+}
+
+extern "system" fn glGetActiveUniform(
+    program: GLuint,
+    index: GLuint,
+    buf_size: GLsizei,
+    length: *const c_void,
+    size: *const c_void,
+    kind: *const c_void,
+    name: *const c_void,
+) {
+    call(
+        "glGetActiveUniform",
+        &[
+            ("program", program.into()),
+            ("index", index.into()),
+            ("buf_size", buf_size.into()),
+            ("length", length.into()),
+            ("size", size.into()),
+            ("kind", kind.into()),
+            ("name", name.into()),
+        ],
+    );
+}
+
+extern "system" fn glGetProgramInterfaceiv(
+    program: GLuint,
+    program_interface: GLenum,
+    pname: GLenum,
+    params: *const c_void,
+) {
+    call(
+        "glGetProgramInterfaceiv",
+        &[
+            ("program", program.into()),
+            ("program_interface", program_interface.into()),
+            ("pname", pname.into()),
+            ("params", params.into()),
+        ],
+    );
+}
+
+extern "system" fn glDispatchCompute(
+    num_groups_x: GLuint,
+    num_groups_y: GLuint,
+    num_groups_z: GLuint,
+) {
+    call(
+        "glDispatchCompute",
+        &[
+            ("num_groups_x", num_groups_x.into()),
+            ("num_groups_y", num_groups_y.into()),
+            ("num_groups_z", num_groups_z.into()),
+        ],
+    );
+}
+
+extern "system" fn glDispatchComputeIndirect(indirect: GLintptr) {
+    call(
+        "glDispatchComputeIndirect",
+        &[("indirect", indirect.into())],
+    );
+}
+
+extern "system" fn glMemoryBarrier(barriers: GLbitField) {
+    call("glMemoryBarrier", &[("barriers", barriers.into())]);
+}
+
+extern "system" fn glGetIntegerv(pname: GLenum, data: *const c_void) {
+    call(
+        "glGetIntegerv",
+        &[("pname", pname.into()), ("data", data.into())],
+    );
+}
+
+extern "system" fn glGetIntegeri_v(target: GLenum, index: GLuint, data: *const c_void) {
+    call(
+        "glGetIntegeri_v",
+        &[
+            ("target", target.into()),
+            ("index", index.into()),
+            ("data", data.into()),
+        ],
+    );
+}
@@ -0,0 +1,506 @@
+//! A stand-in for a GL context, so code built on these bindings can be tested without a GPU.
+//!
+//! `Mock::loader` hands out an `extern "system"` stub for every symbol of every generated `Api`.
+//! The stubs record each call into the `Mock` of the current thread and answer it with a
+//! scripted value, or with a plausible default:
+//! - `glGen*` and `glCreate*` hand out fresh object names, starting at 1
+//! - `glGetIntegerv`, `glGetIntegeri_v`, `glGetShaderiv` and `glGetProgramiv` write the value set
+//!   with `set_integer`, where compile and link status default to `GL_TRUE`
+//! - `glGetShaderInfoLog` and `glGetProgramInfoLog` write the log set with `set_info_log`
+//! - everything else returns 0
+//!
+//! Uploads like `glBufferData` also record the bytes they read, see `Call::data`.
+//!
+//! A panic can't unwind out of an `extern "system"` stub, e.g. a failed assertion in an `on`
+//! handler. The stub catches it and returns 0, and the next `Mock` method, or dropping the
+//! `Mock`, raises it again on the test's thread.
+//!
+//! ```
+//! use cac_gl_bindings::gl33;
+//! use cac_gl_bindings::testing::Mock;
+//!
+//! let mock = Mock::new();
+//! let api = unsafe { gl33::Api::with_loader(&Mock::loader)? };
+//! mock.fail_compile("0:3(1): error: syntax error");
+//!
+//! let shader = unsafe { api.create_shader(gl33::ShaderKind::VERTEX) };
+//! unsafe { api.compile_shader(shader) };
+//! let error = unsafe { api.check_compile_status(shader) }.unwrap_err();
+//! assert_eq!(error.log, "0:3(1): error: syntax error");
+//! assert_eq!(mock.calls_to("glCompileShader").len(), 1);
+//! # Ok::<(), cac_gl_bindings::Error>(())
+//! ```
+
+// the stubs are named and typed like the registry commands
+#[allow(non_snake_case, clippy::similar_names, clippy::too_many_arguments)]
+mod generated;
+
+use std::any::Any;
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::ffi::c_void;
+use std::fmt::Display;
+use std::marker::PhantomData;
+use std::panic::{catch_unwind, resume_unwind, AssertUnwindSafe};
+
+use crate::types::{GLboolean, GLdouble, GLenum, GLfloat, GLint, GLsizei, GLsizeiptr, GLuint};
+
+const TRUE: GLint = 1;
+const COMPILE_STATUS: GLenum = 0x8B81;
+const LINK_STATUS: GLenum = 0x8B82;
+const INFO_LOG_LENGTH: GLenum = 0x8B84;
+
+/// A decoded argument or return value of a GL call.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum Value {
+    /// Any integer, including enums, bitfields, booleans and object names.
+    Int(i64),
+    Float(f64),
+    /// The address of a pointer argument, what it points at is only recorded in `Call::data`.
+    Pointer(usize),
+}
+
+/// One call that reached a stub.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Call {
+    /// The registry name, e.g. `"glBindBuffer"`.
+    pub symbol: &'static str,
+    /// The arguments with their names, as they appear in the `Api` wrappers.
+    pub args: Vec<(&'static str, Value)>,
+    /// The bytes uploaded by commands with a `data` and `size` parameter, like `glBufferData`.
+    /// `None` for other commands and for null `data`.
+    pub data: Option<Vec<u8>>,
+}
+
+type Handler = Box<dyn FnMut(&Call) -> Option<Value>>;
+
+/// Records the GL calls made on the current thread while it is alive, see the module docs.
+///
+/// Only one `Mock` can exist per thread, and `loader` panics without one.
+pub struct Mock {
+    // the state is thread local
+    _not_send: PhantomData<*const ()>,
+}
+
+#[derive(Default)]
+struct State {
+    calls: Vec<Call>,
+    last_name: GLuint,
+    integers: HashMap<GLenum, GLint>,
+    info_log: String,
+    handlers: HashMap<&'static str, Handler>,
+}
+
+thread_local! {
+    static STATE: RefCell<Option<State>> = const { RefCell::new(None) };
+    /// The first panic caught in a stub, kept apart from `STATE` as it may be missing.
+    static FAILURE: RefCell<Option<Box<dyn Any + Send>>> = const { RefCell::new(None) };
+}
+
+impl Mock {
+    /// Installs a fresh mock on the current thread.
+    ///
+    /// # Panics
+    /// If the thread already has one, or with a failure of a stub called after the last `Mock`
+    /// was dropped.
+    #[must_use]
+    #[allow(clippy::new_without_default)]
+    pub fn new() -> Self {
+        raise_failure();
+        STATE.with_borrow_mut(|state| {
+            assert!(state.is_none(), "this thread already has a Mock");
+            *state = Some(State {
+                integers: HashMap::from([(COMPILE_STATUS, TRUE), (LINK_STATUS, TRUE)]),
+                ..State::default()
+            });
+        });
+        Self {
+            _not_send: PhantomData,
+        }
+    }
+
+    /// The loader to pass to `with_loader`, it never returns null for symbols of an `Api`.
+    ///
+    /// # Panics
+    /// If the thread has no `Mock`.
+    #[must_use]
+    pub fn loader(symbol: &str) -> *const c_void {
+        assert!(
+            STATE.with_borrow(Option::is_some),
+            "no Mock on this thread, create one before loading"
+        );
+        generated::stub(symbol)
+    }
+
+    /// Every call so far, oldest first.
+    #[must_use]
+    pub fn calls(&self) -> Vec<Call> {
+        mock_state(|state| state.calls.clone())
+    }
+
+    /// Like `calls`, but also clears the log.
+    #[must_use]
+    pub fn take_calls(&self) -> Vec<Call> {
+        mock_state(|state| std::mem::take(&mut state.calls))
+    }
+
+    #[must_use]
+    pub fn calls_to(&self, symbol: &str) -> Vec<Call> {
+        mock_state(|state| {
+            state
+                .calls
+                .iter()
+                .filter(|call| call.symbol == symbol)
+                .cloned()
+                .collect()
+        })
+    }
+
+    /// Answers calls to `symbol` with `handler`, falling back to the default for `None`.
+    /// Replaces any earlier handler for the same symbol.
+    pub fn on(&self, symbol: &'static str, handler: impl FnMut(&Call) -> Option<Value> + 'static) {
+        mock_state(|state| state.handlers.insert(symbol, Box::new(handler)));
+    }
+
+    /// Makes every call to `symbol` return `value`.
+    pub fn returns(&self, symbol: &'static str, value: impl Into<Value>) {
+        let value = value.into();
+        self.on(symbol, move |_| Some(value));
+    }
+
+    /// The value the `glGet*iv` queries write for `pname`, the raw value of e.g.
+    /// `gl::IntegerParameter::MAX_VERTEX_ATTRIBS`.
+    pub fn set_integer(&self, pname: GLenum, value: GLint) {
+        mock_state(|state| state.integers.insert(pname, value));
+    }
+
+    /// The log returned by the info log queries, `INFO_LOG_LENGTH` follows it.
+    pub fn set_info_log(&self, log: &str) {
+        mock_state(|state| log.clone_into(&mut state.info_log));
+    }
+
+    /// Makes shader compilation fail with `log`.
+    pub fn fail_compile(&self, log: &str) {
+        self.set_integer(COMPILE_STATUS, 0);
+        self.set_info_log(log);
+    }
+
+    /// Makes program linking fail with `log`.
+    pub fn fail_link(&self, log: &str) {
+        self.set_integer(LINK_STATUS, 0);
+        self.set_info_log(log);
+    }
+}
+
+impl Drop for Mock {
+    fn drop(&mut self) {
+        STATE.with_borrow_mut(Option::take);
+        // a second panic would abort
+        if std::thread::panicking() {
+            FAILURE.take();
+        } else {
+            raise_failure();
+        }
+    }
+}
+
+fn raise_failure() {
+    if let Some(panic) = FAILURE.take() {
+        resume_unwind(panic);
+    }
+}
+
+/// `with_state` for the `Mock` methods, which first raise the failure of a stub, so the test
+/// fails where it looks at the mock next.
+fn mock_state<T>(f: impl FnOnce(&mut State) -> T) -> T {
+    raise_failure();
+    with_state(f)
+}
+
+fn with_state<T>(f: impl FnOnce(&mut State) -> T) -> T {
+    STATE.with_borrow_mut(|state| f(state.as_mut().expect("no Mock on this thread")))
+}
+
+/// Records a call and works out what it returns, called by every stub. Panics are kept for
+/// `raise_failure`, as they can't unwind through the `extern "system"` stubs.
+fn call(symbol: &'static str, args: &[(&'static str, Value)]) -> Value {
+    stub_call(symbol, args, None)
+}
+
+/// `call` for the stubs of commands that read `size` bytes from `data`.
+fn call_with_data(
+    symbol: &'static str,
+    args: &[(&'static str, Value)],
+    data: *const c_void,
+    size: GLsizeiptr,
+) -> Value {
+    let bytes = usize::try_from(size)
+        .ok()
+        .filter(|_| !data.is_null())
+        .map(|size| {
+            // the code under test passed `size` readable bytes, as GL requires
+            unsafe { std::slice::from_raw_parts(data.cast::<u8>(), size) }.to_vec()
+        });
+    stub_call(symbol, args, bytes)
+}
+
+fn stub_call(symbol: &'static str, args: &[(&'static str, Value)], data: Option<Vec<u8>>) -> Value {
+    catch_unwind(AssertUnwindSafe(|| record(symbol, args, data))).unwrap_or_else(|panic| {
+        FAILURE.with_borrow_mut(|failure| {
+            failure.get_or_insert(panic);
+        });
+        Value::Int(0)
+    })
+}
+
+fn record(symbol: &'static str, args: &[(&'static str, Value)], data: Option<Vec<u8>>) -> Value {
+    let call = Call {
+        symbol,
+        args: args.to_vec(),
+        data,
+    };
+
+    // the handler runs without the state borrowed, so it can use the `Mock` itself
+    let handler = with_state(|state| {
+        state.calls.push(call.clone());
+        state.handlers.remove(symbol)
+    });
+    if let Some(mut handler) = handler {
+        let value = handler(&call);
+        with_state(|state| {
+            // unless the handler replaced itself
+            state.handlers.entry(symbol).or_insert(handler);
+        });
+        if let Some(value) = value {
+            return value;
+        }
+    }
+
+    with_state(|state| state.answer(&call))
+}
+
+impl State {
+    /// What a call returns when no handler answered it.
+    fn answer(&mut self, call: &Call) -> Value {
+        let arg = |index: usize| call.args[index].1;
+
+        match call.symbol {
+            symbol if generated::WRITES_NAMES.contains(&symbol) => {
+                let n = usize::try_from(arg(0).int()).unwrap_or_default();
+                for i in 0..n {
+                    let name = self.next_name();
+                    unsafe { write(arg(1), i, name) };
+                }
+                Value::Int(0)
+            }
+            symbol if generated::RETURNS_NAME.contains(&symbol) => self.next_name().into(),
+            "glGetIntegerv" => self.write_integer(arg(0), arg(1)),
+            "glGetIntegeri_v" => self.write_integer(arg(0), arg(2)),
+            "glGetShaderiv" | "glGetProgramiv" => self.write_integer(arg(1), arg(2)),
+            "glGetShaderInfoLog" | "glGetProgramInfoLog" => {
+                self.write_info_log(arg(1), arg(2), arg(3));
+                Value::Int(0)
+            }
+            _ => Value::Int(0),
+        }
+    }
+
+    const fn next_name(&mut self) -> GLuint {
+        self.last_name += 1;
+        self.last_name
+    }
+
+    fn write_integer(&self, pname: Value, params: Value) -> Value {
+        let pname = GLenum::try_from(pname.int()).unwrap_or_default();
+        let value = match pname {
+            // the log length includes the terminating nul
+            INFO_LOG_LENGTH if self.info_log.is_empty() => 0,
+            INFO_LOG_LENGTH => len(self.info_log.len() + 1),
+            _ => self.integers.get(&pname).copied().unwrap_or_default(),
+        };
+        unsafe { write(params, 0, value) };
+        Value::Int(0)
+    }
+
+    fn write_info_log(&self, buf_size: Value, length: Value, info_log: Value) {
+        let capacity = usize::try_from(buf_size.int()).unwrap_or_default();
+        if capacity == 0 {
+            return;
+        }
+        let bytes = &self.info_log.as_bytes()[..self.info_log.len().min(capacity - 1)];
+
+        for (i, &byte) in bytes.iter().chain(&[0]).enumerate() {
+            unsafe { write(info_log, i, byte) };
+        }
+        if length != Value::Pointer(0) {
+            unsafe { write(length, 0, len(bytes.len())) };
+        }
+    }
+}
+
+/// Writes `value` to element `index` of the array an argument points at.
+///
+/// # Safety
+/// The code under test passed a pointer to at least `index + 1` elements of `T`, as GL requires.
+pub(crate) unsafe fn write<T>(pointer: Value, index: usize, value: T) {
+    let Value::Pointer(address) = pointer else {
+        panic!("expected a pointer argument, got {pointer:?}");
+    };
+    let pointer = std::ptr::with_exposed_provenance_mut::<T>(address);
+    unsafe { pointer.add(index).write_unaligned(value) };
+}
+
+fn len(n: usize) -> GLsizei {
+    n.try_into().expect("length doesn't fit into a GLsizei")
+}
+
+impl Value {
+    /// The integer, or 0 for other kinds of values.
+    #[must_use]
+    pub const fn int(self) -> i64 {
+        match self {
+            Self::Int(value) => value,
+            Self::Float(_) | Self::Pointer(_) => 0,
+        }
+    }
+}
+
+impl Call {
+    /// The argument named `name`, e.g. `"target"` of `glBindBuffer`.
+    #[must_use]
+    pub fn arg(&self, name: &str) -> Option<Value> {
+        self.args
+            .iter()
+            .find_map(|&(arg, value)| (arg == name).then_some(value))
+    }
+}
+
+macro_rules! int_conversions {
+    ($($ty:ty),*) => {$(
+        impl From<$ty> for Value {
+            fn from(value: $ty) -> Self {
+                Self::Int(value as i64)
+            }
+        }
+
+        impl From<Value> for $ty {
+            /// Truncates to the return type, so scripted values don't need the exact C type.
+            #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+            fn from(value: Value) -> Self {
+                value.int() as Self
+            }
+        }
+    )*};
+}
+
+// GLenum, GLbitField and GLuint are the same type, as are GLint and GLsizei
+#[allow(clippy::cast_lossless, clippy::cast_possible_wrap)]
+mod conversions {
+    use super::{GLboolean, GLint, GLuint, Value};
+
+    int_conversions!(GLboolean, GLint, GLuint, isize);
+}
+
+impl From<GLfloat> for Value {
+    fn from(value: GLfloat) -> Self {
+        Self::Float(value.into())
+    }
+}
+
+impl From<GLdouble> for Value {
+    fn from(value: GLdouble) -> Self {
+        Self::Float(value)
+    }
+}
+
+impl From<bool> for Value {
+    fn from(value: bool) -> Self {
+        Self::Int(value.into())
+    }
+}
+
+impl From<*const c_void> for Value {
+    fn from(pointer: *const c_void) -> Self {
+        Self::Pointer(pointer.expose_provenance())
+    }
+}
+
+impl From<Value> for *const c_void {
+    fn from(value: Value) -> Self {
+        match value {
+            Value::Pointer(address) => std::ptr::with_exposed_provenance(address),
+            Value::Int(_) | Value::Float(_) => std::ptr::null(),
+        }
+    }
+}
+
+impl Display for Value {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Int(value) => write!(f, "{value}"),
+            Self::Float(value) => write!(f, "{value}"),
+            Self::Pointer(address) => write!(f, "{address:#x}"),
+        }
+    }
+}
+
+impl Display for Call {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}(", self.symbol)?;
+        for (i, (name, value)) in self.args.iter().enumerate() {
+            if i > 0 {
+                write!(f, ", ")?;
+            }
+            write!(f, "{name}: {value}")?;
+        }
+        write!(f, ")")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::gl33;
+
+    #[test]
+    fn failed_compile_returns_the_info_log() {
+        let mock = Mock::new();
+        let api = unsafe { gl33::Api::with_loader(&Mock::loader) }.unwrap();
+        mock.fail_compile("0:3(1): error: syntax error");
+
+        let shader = unsafe { api.create_shader(gl33::ShaderKind::VERTEX) };
+        unsafe { api.compile_shader(shader) };
+        let error = unsafe { api.check_compile_status(shader) }.unwrap_err();
+
+        assert_eq!(error.log, "0:3(1): error: syntax error");
+        assert_eq!(error.entries[0].line, Some(3));
+        assert_eq!(mock.calls_to("glCompileShader").len(), 1);
+    }
+
+    #[test]
+    #[should_panic(expected = "handler failed")]
+    fn panics_in_stubs_reach_the_test() {
+        let mock = Mock::new();
+        let api = unsafe { gl33::Api::with_loader(&Mock::loader) }.unwrap();
+        mock.on("glClear", |_| panic!("handler failed"));
+
+        unsafe { api.clear(gl33::ClearMask::COLOR) };
+        let _ = mock.calls();
+    }
+
+    #[test]
+    fn uploads_record_their_bytes() {
+        let mock = Mock::new();
+        let api = unsafe { gl33::Api::with_loader(&Mock::loader) }.unwrap();
+
+        let data = [1u8, 2, 3];
+        let target = gl33::BufferTarget::ARRAY_BUFFER;
+        let usage = gl33::BufferUsage::STATIC_DRAW;
+        unsafe { api.buffer_data(target, 3, data.as_ptr().cast(), usage) };
+        unsafe { api.buffer_data(target, 3, std::ptr::null(), usage) };
+
+        let calls = mock.calls_to("glBufferData");
+        assert_eq!(calls[0].data.as_deref(), Some(&data[..]));
+        assert_eq!(calls[1].data, None);
+    }
+}
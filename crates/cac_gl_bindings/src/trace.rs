@@ -0,0 +1,40 @@
+//! Call tracing for the `trace` feature.
+//!
+//! Every wrapper of every `Api` logs its call with the `Debug` of its arguments at `trace`
+//! level, under the target of its module, e.g. `cac_gl_bindings::gl33`. With `set_check_errors`
+//! it also calls `glGetError` afterwards and logs failures at `error` level, together with the
+//! location of the call. `get_error` itself is neither logged nor checked.
+
+use std::panic::Location;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+use crate::GlError;
+
+static CHECK_ERRORS: AtomicBool = AtomicBool::new(false);
+
+/// Calls `glGetError` after every traced call, off by default. This synchronizes with the
+/// driver, so it is slow and meant for debugging.
+pub fn set_check_errors(enabled: bool) {
+    CHECK_ERRORS.store(enabled, Ordering::Relaxed);
+}
+
+#[must_use]
+pub fn check_errors() -> bool {
+    CHECK_ERRORS.load(Ordering::Relaxed)
+}
+
+/// Logs the error `get_error` returns after `symbol`, if checks are enabled. The location is the
+/// caller of the wrapper, as the wrappers are `#[track_caller]` with this feature.
+#[track_caller]
+pub(crate) fn check(symbol: &str, get_error: impl FnOnce() -> Option<GlError>) {
+    if !check_errors() {
+        return;
+    }
+
+    if let Some(error) = get_error() {
+        log::error!(
+            "{symbol} failed with {error}, called at {}",
+            Location::caller()
+        );
+    }
+}
@@ -0,0 +1,149 @@
+use std::collections::{BTreeMap, HashMap};
+
+use serde::Deserialize;
+
+use crate::registry::Version;
+use crate::Error;
+
+/// The curated subset of the registry that ends up in the bindings, see `allowlist.toml`.
+#[derive(Deserialize, Debug)]
+#[serde(deny_unknown_fields)]
+pub struct Allowlist {
+    pub registry: String,
+    /// Path of the `extern "system"` stubs of the `testing` feature, relative to the workspace
+    /// root. They cover every allow-listed command, whichever family it belongs to.
+    pub stubs: String,
+    #[serde(rename = "family")]
+    pub families: Vec<Family>,
+    #[serde(rename = "module")]
+    pub modules: Vec<Module>,
+    /// Registry types that need a Rust spelling other than the one in `crate::types`.
+    #[serde(default)]
+    pub types: HashMap<String, String>,
+    #[serde(default, rename = "handle")]
+    pub handles: Vec<Handle>,
+    #[serde(default, rename = "enum")]
+    pub enums: Vec<Enum>,
+    #[serde(default, rename = "command")]
+    pub commands: Vec<Command>,
+}
+
+/// One API from the registry. Its handles and enums are generated once into `output` and shared
+/// by all of its modules.
+#[derive(Deserialize, Debug)]
+#[serde(deny_unknown_fields)]
+pub struct Family {
+    pub name: String,
+    /// Human readable name used in doc comments, e.g. `OpenGL`.
+    pub title: String,
+    /// The `api` attribute of the registry's `<feature>` blocks, e.g. `gl` or `gles2`.
+    pub api: String,
+    pub profile: String,
+    /// Constants introduced up to this version are grouped together without a `since` comment.
+    pub baseline: String,
+    /// Path of the generated file, relative to the workspace root.
+    pub output: String,
+}
+
+/// One generated `Api`, e.g. `gl43` for the OpenGL 4.3 core profile.
+#[derive(Deserialize, Debug)]
+#[serde(deny_unknown_fields)]
+pub struct Module {
+    pub name: String,
+    pub family: String,
+    pub version: String,
+    /// The next lower module of the same family. Its `Api` is embedded and dereferenced to, so
+    /// this module only contains the commands introduced after it.
+    #[serde(default)]
+    pub base: Option<String>,
+    /// Field name to type of hand-written state in `Api`, initialized with `Type::default()`.
+    #[serde(default)]
+    pub fields: BTreeMap<String, String>,
+    /// Path of the generated file, relative to the workspace root.
+    pub output: String,
+}
+
+/// An object name newtype such as `Buffer`, always backed by a `GLuint`.
+#[derive(Deserialize, Debug)]
+#[serde(deny_unknown_fields)]
+pub struct Handle {
+    pub name: String,
+    /// `GLuint` parameters with one of these names are typed as this handle.
+    #[serde(default)]
+    pub params: Vec<String>,
+}
+
+/// A newtype over `GLenum` or `GLbitfield` with a curated set of constants.
+#[derive(Deserialize, Debug)]
+#[serde(deny_unknown_fields)]
+pub struct Enum {
+    pub name: String,
+    #[serde(default)]
+    pub bitmask: bool,
+    /// Prepended to every constant to get its registry name, after the implicit `GL_`.
+    #[serde(default)]
+    pub prefix: String,
+    /// Appended to every constant to get its registry name.
+    #[serde(default)]
+    pub suffix: String,
+    /// Either `NAME`, or `NAME = GL_REGISTRY_NAME` when the Rust name can't be derived.
+    pub values: Vec<String>,
+}
+
+impl Allowlist {
+    pub fn family(&self, name: &str) -> Result<&Family, Error> {
+        self.families
+            .iter()
+            .find(|f| f.name == name)
+            .ok_or_else(|| Error::Allowlist(format!("unknown family {name}")))
+    }
+
+    pub fn module(&self, name: &str) -> Result<&Module, Error> {
+        self.modules
+            .iter()
+            .find(|m| m.name == name)
+            .ok_or_else(|| Error::Allowlist(format!("unknown module {name}")))
+    }
+
+    /// The highest version generated for `family`, which bounds its enum constants.
+    pub fn family_version(&self, family: &Family) -> Result<Version, Error> {
+        let mut max = None;
+        for module in self.modules.iter().filter(|m| m.family == family.name) {
+            max = max.max(Some(Version::parse(&module.version)?));
+        }
+        max.ok_or_else(|| Error::Allowlist(format!("family {} has no modules", family.name)))
+    }
+}
+
+impl Enum {
+    /// Pairs of (Rust constant name, registry enum name).
+    pub fn constants(&self) -> impl Iterator<Item = (&str, String)> {
+        self.values.iter().map(|value| match value.split_once('=') {
+            Some((name, registry)) => (name.trim(), registry.trim().to_string()),
+            None => (
+                value.as_str(),
+                format!("GL_{}{value}{}", self.prefix, self.suffix),
+            ),
+        })
+    }
+}
+
+#[derive(Deserialize, Debug)]
+#[serde(deny_unknown_fields)]
+pub struct Command {
+    pub name: String,
+    /// Groups related commands under a `//section` comment.
+    pub section: String,
+    /// Summary placed above the `# Safety` section of the wrapper.
+    #[serde(default)]
+    pub doc: Option<String>,
+    /// Overrides the snake case wrapper name derived from the command name.
+    #[serde(default)]
+    pub rust_name: Option<String>,
+    /// Registry parameter name to Rust type. Pointer parameters keep their indirection and
+    /// only have the pointee replaced.
+    #[serde(default)]
+    pub params: HashMap<String, String>,
+    #[serde(default)]
+    pub returns: Option<String>,
+}
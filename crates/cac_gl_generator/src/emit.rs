@@ -0,0 +1,1075 @@
+use std::collections::BTreeMap;
+use std::fmt::Write;
+use std::io::Write as _;
+use std::process::{Command, Stdio};
+
+use crate::allowlist::{self, Allowlist, Family, Module};
+use crate::registry::{self, CType, Registry, Version};
+use crate::Error;
+
+const HEADER: &str = "\
+// This file is generated by `cac_gl_generator` from the Khronos registry, do not edit it by hand.
+// Change `crates/cac_gl_generator/allowlist.toml` and run `cargo run -p cac_gl_generator` instead.
+";
+
+struct Target<'a> {
+    registry: &'a Registry,
+    allowlist: &'a Allowlist,
+    family: &'a Family,
+    version: Version,
+}
+
+impl Target<'_> {
+    fn introduced_in(&self, name: &str) -> Option<Version> {
+        self.registry
+            .introduced_in(&self.family.api, &self.family.profile, self.version, name)
+    }
+}
+
+/// Makes sure every allow-listed command and enum ends up in at least one module, so typos and
+/// entries from the wrong API don't silently disappear.
+pub fn validate(registry: &Registry, allowlist: &Allowlist) -> Result<(), Error> {
+    let available = |name: &str| -> Result<bool, Error> {
+        for module in &allowlist.modules {
+            let family = allowlist.family(&module.family)?;
+            let version = Version::parse(&module.version)?;
+            if registry
+                .introduced_in(&family.api, &family.profile, version, name)
+                .is_some()
+            {
+                return Ok(true);
+            }
+        }
+        Ok(false)
+    };
+
+    let commands = allowlist.commands.iter().map(|c| c.name.clone());
+    let enums = allowlist
+        .enums
+        .iter()
+        .flat_map(|e| e.constants().map(|(_, name)| name));
+
+    for name in commands.chain(enums) {
+        if !available(&name)? {
+            return Err(Error::Allowlist(format!(
+                "{name} is not part of any generated module"
+            )));
+        }
+    }
+
+    Ok(())
+}
+
+/// Renders the handles and enums shared by all modules of `family`.
+pub fn family(
+    registry: &Registry,
+    allowlist: &Allowlist,
+    family: &Family,
+) -> Result<String, Error> {
+    let target = Target {
+        registry,
+        allowlist,
+        family,
+        version: allowlist.family_version(family)?,
+    };
+    let baseline = Version::parse(&family.baseline)?;
+
+    let mut out = String::from(HEADER);
+
+    for handle in &allowlist.handles {
+        newtype(&mut out, &handle.name, "GLuint", true);
+        writeln!(out, "impl {} {{", handle.name).unwrap();
+        writeln!(out, "    pub const NONE: Self = Self(0);").unwrap();
+        writeln!(out, "}}").unwrap();
+    }
+
+    for e in &allowlist.enums {
+        enumeration(&mut out, &target, baseline, e)?;
+    }
+
+    format(&out)
+}
+
+/// Renders the `Api` of `module`, which only binds the commands its base module doesn't.
+pub fn module(
+    registry: &Registry,
+    allowlist: &Allowlist,
+    module: &Module,
+) -> Result<String, Error> {
+    let target = Target {
+        registry,
+        allowlist,
+        family: allowlist.family(&module.family)?,
+        version: Version::parse(&module.version)?,
+    };
+    let base = module
+        .base
+        .as_deref()
+        .map(|base| allowlist.module(base))
+        .transpose()?;
+    let base_version = base.map(|b| Version::parse(&b.version)).transpose()?;
+
+    let commands = allowlist
+        .commands
+        .iter()
+        .filter(|c| {
+            target
+                .introduced_in(&c.name)
+                .is_some_and(|v| base_version.is_none_or(|base| v > base))
+        })
+        .map(|c| Ok((c, Signature::new(&target, c)?)))
+        .collect::<Result<Vec<_>, Error>>()?;
+
+    let mut out = String::from(HEADER);
+    api(&mut out, &target, base, &module.fields, &commands);
+    format(&out)
+}
+
+/// Renders the stubs of the `testing` feature. They take the plain C types, which have the same
+/// ABI as the newtypes of every family, and hand their arguments to `call`.
+pub fn stubs(registry: &Registry, allowlist: &Allowlist) -> Result<String, Error> {
+    let commands = allowlist
+        .commands
+        .iter()
+        .map(|c| registry.command(&c.name))
+        .collect::<Result<Vec<_>, Error>>()?;
+
+    let mut out = String::from(HEADER);
+    writeln!(
+        out,
+        "
+use std::ffi::c_void;
+
+use super::{{call, call_with_data}};
+use crate::types::{{
+    GLbitField, GLboolean, GLdouble, GLenum, GLfloat, GLint, GLintptr, GLsizei, GLsizeiptr, GLuint,
+}};
+
+/// The stub for `symbol`, or null for symbols that aren't part of any generated `Api`.
+#[allow(clippy::too_many_lines)]
+pub fn stub(symbol: &str) -> *const c_void {{
+    match symbol {{"
+    )
+    .unwrap();
+    for command in &commands {
+        writeln!(
+            out,
+            "        \"{0}\" => {0} as *const c_void,",
+            command.name
+        )
+        .unwrap();
+    }
+    writeln!(out, "        _ => std::ptr::null(),").unwrap();
+    writeln!(out, "    }}").unwrap();
+    writeln!(out, "}}").unwrap();
+
+    let names = |filter: fn(&registry::Command) -> bool| {
+        commands
+            .iter()
+            .filter(|c| filter(c))
+            .map(|c| format!("\"{}\"", c.name))
+            .collect::<Vec<_>>()
+            .join(", ")
+    };
+    writeln!(
+        out,
+        "
+/// Commands that write `n` fresh object names to their second parameter, like `glGenBuffers`.
+pub const WRITES_NAMES: &[&str] = &[{}];
+
+/// Commands that return a fresh object name, like `glCreateShader`.
+pub const RETURNS_NAME: &[&str] = &[{}];",
+        names(writes_names),
+        names(returns_name)
+    )
+    .unwrap();
+
+    for command in commands {
+        let params = command
+            .params
+            .iter()
+            .map(|p| Ok(format!("{}: {}", param_name(&p.name), stub_type(&p.ty)?)))
+            .collect::<Result<Vec<_>, Error>>()?
+            .join(", ");
+        let args = command
+            .params
+            .iter()
+            .map(|p| {
+                let name = param_name(&p.name);
+                format!("(\"{name}\", {name}.into())")
+            })
+            .collect::<Vec<_>>()
+            .join(", ");
+
+        let call = if uploads_data(command) {
+            format!(
+                "call_with_data(\"{}\", &[{args}], data, size)",
+                command.name
+            )
+        } else {
+            format!("call(\"{}\", &[{args}])", command.name)
+        };
+
+        writeln!(out).unwrap();
+        if command.ret.is_void() {
+            writeln!(out, "extern \"system\" fn {}({params}) {{", command.name).unwrap();
+            writeln!(out, "    {call};").unwrap();
+        } else {
+            writeln!(
+                out,
+                "extern \"system\" fn {}({params}) -> {} {{",
+                command.name,
+                stub_type(&command.ret)?
+            )
+            .unwrap();
+            writeln!(out, "    {call}.into()").unwrap();
+        }
+        writeln!(out, "}}").unwrap();
+    }
+
+    format(&out)
+}
+
+/// `glGen*` and `glCreate*` commands taking a count and an array of names to fill.
+fn writes_names(command: &registry::Command) -> bool {
+    let [n, names] = command.params.as_slice() else {
+        return false;
+    };
+    (command.name.starts_with("glGen") || command.name.starts_with("glCreate"))
+        && n.ty.base == "GLsizei"
+        && n.ty.pointers.is_empty()
+        && names.ty.base == "GLuint"
+        && !names.ty.base_const
+        && names.ty.pointers.len() == 1
+}
+
+fn returns_name(command: &registry::Command) -> bool {
+    command.name.starts_with("glCreate")
+        && command.ret.base == "GLuint"
+        && command.ret.pointers.is_empty()
+}
+
+/// Commands like `glBufferData` that read `size` bytes from `data`, which the stub records.
+fn uploads_data(command: &registry::Command) -> bool {
+    let param = |name: &str| command.params.iter().find(|p| p.name == name);
+    param("size").is_some_and(|size| size.ty.base == "GLsizeiptr")
+        && param("data").is_some_and(|data| data.ty.base_const && data.ty.pointers.len() == 1)
+}
+
+/// The C type of a stub parameter. Pointers are only recorded as addresses, so their pointee
+/// doesn't matter.
+fn stub_type(ty: &CType) -> Result<String, Error> {
+    if !ty.pointers.is_empty() || ty.base == "GLDEBUGPROC" {
+        return Ok("*const c_void".to_string());
+    }
+    match ty.base.as_str() {
+        "GLbitfield" => Ok("GLbitField".to_string()),
+        base @ ("GLboolean" | "GLdouble" | "GLenum" | "GLfloat" | "GLint" | "GLintptr"
+        | "GLsizei" | "GLsizeiptr" | "GLuint") => Ok(base.to_string()),
+        base => Err(Error::Registry(format!("no stub type for {base}"))),
+    }
+}
+
+/// Declares a newtype. Enums get a `Debug` impl with their constant names from `debug` instead
+/// of the derived one.
+fn newtype(out: &mut String, name: &str, repr: &str, derive_debug: bool) {
+    writeln!(out).unwrap();
+    writeln!(out, "#[repr(transparent)]").unwrap();
+    if derive_debug {
+        writeln!(out, "#[derive(Copy, Clone, Eq, PartialEq, Debug)]").unwrap();
+    } else {
+        writeln!(out, "#[derive(Copy, Clone, Eq, PartialEq)]").unwrap();
+    }
+    writeln!(out, "pub struct {name}({repr});").unwrap();
+}
+
+fn enumeration(
+    out: &mut String,
+    target: &Target,
+    baseline: Version,
+    e: &allowlist::Enum,
+) -> Result<(), Error> {
+    let mut constants = Vec::new();
+    for (name, registry_name) in e.constants() {
+        let Some(version) = target.introduced_in(&registry_name) else {
+            continue;
+        };
+        let value = target
+            .registry
+            .enum_value(&registry_name, &target.family.api)?;
+        constants.push((version.max(baseline), name, value));
+    }
+    constants.sort_by_key(|(version, _, _)| *version);
+
+    if constants.is_empty() {
+        return Ok(());
+    }
+
+    newtype(
+        out,
+        &e.name,
+        if e.bitmask { "GLbitField" } else { "GLenum" },
+        false,
+    );
+    writeln!(out, "impl {} {{", e.name).unwrap();
+
+    let grouped = constants
+        .first()
+        .zip(constants.last())
+        .is_some_and(|(first, last)| first.0 != last.0);
+    let mut group = None;
+
+    for &(version, name, value) in &constants {
+        if grouped && group != Some(version) {
+            if group.is_some() {
+                writeln!(out).unwrap();
+                writeln!(out, "    //since {version}").unwrap();
+            } else {
+                writeln!(out, "    //GL {version}").unwrap();
+            }
+            group = Some(version);
+        }
+        writeln!(
+            out,
+            "    pub const {name}: Self = Self({});",
+            literal(value)
+        )
+        .unwrap();
+    }
+
+    writeln!(out, "}}").unwrap();
+
+    // aliases share a value, only the first name is printed
+    let mut names: Vec<(&str, &str)> = Vec::new();
+    for &(_, name, value) in &constants {
+        if names.iter().all(|&(_, v)| v != value) {
+            names.push((name, value));
+        }
+    }
+    let names: Vec<&str> = names.into_iter().map(|(name, _)| name).collect();
+
+    debug(out, &e.name, &names, e.bitmask);
+    if e.bitmask {
+        bit_ops(out, &e.name);
+    }
+    Ok(())
+}
+
+/// Prints enums as `Primitive::TRIANGLES`, unknown values as `Primitive(0x1234)`, and bitmasks
+/// as their set bits, e.g. `ClearMask::COLOR | ClearMask::DEPTH`.
+fn debug(out: &mut String, name: &str, constants: &[&str], bitmask: bool) {
+    writeln!(out).unwrap();
+    writeln!(out, "impl std::fmt::Debug for {name} {{").unwrap();
+    writeln!(
+        out,
+        "    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {{"
+    )
+    .unwrap();
+
+    if bitmask {
+        // exact matches first, so masks like `ALL` aren't taken apart
+        writeln!(out, "        let constants = [").unwrap();
+        for constant in constants {
+            writeln!(out, "            (\"{constant}\", Self::{constant}),").unwrap();
+        }
+        writeln!(
+            out,
+            "        ];
+        if let Some((name, _)) = constants.iter().find(|(_, c)| *c == *self) {{
+            return write!(f, \"{name}::{{name}}\");
+        }}
+
+        let mut rest = self.0;
+        let mut separator = \"\";
+        for (name, constant) in constants {{
+            if constant.0 != 0 && rest & constant.0 == constant.0 {{
+                write!(f, \"{{separator}}{name}::{{name}}\")?;
+                rest &= !constant.0;
+                separator = \" | \";
+            }}
+        }}
+        if rest != 0 || separator.is_empty() {{
+            write!(f, \"{{separator}}{name}({{rest:#X}})\")?;
+        }}
+        Ok(())"
+        )
+        .unwrap();
+    } else {
+        writeln!(
+            out,
+            "        if let Some(name) = self.name() {{
+            return write!(f, \"{name}::{{name}}\");
+        }}
+        write!(f, \"{name}({{:#06X}})\", self.0)"
+        )
+        .unwrap();
+    }
+
+    writeln!(out, "    }}").unwrap();
+    writeln!(out, "}}").unwrap();
+
+    if !bitmask {
+        name_fn(out, name, constants);
+    }
+}
+
+/// The name of the constant a value is, for `Debug` and the hand-written `Display` impls.
+fn name_fn(out: &mut String, name: &str, constants: &[&str]) {
+    writeln!(out).unwrap();
+    writeln!(out, "impl {name} {{").unwrap();
+    writeln!(
+        out,
+        "    /// The name of the constant, `None` for unknown values."
+    )
+    .unwrap();
+    writeln!(out, "    const fn name(self) -> Option<&'static str> {{").unwrap();
+    writeln!(out, "        Some(match self {{").unwrap();
+    for constant in constants {
+        writeln!(out, "            Self::{constant} => \"{constant}\",").unwrap();
+    }
+    writeln!(out, "            _ => return None,").unwrap();
+    writeln!(out, "        }})").unwrap();
+    writeln!(out, "    }}").unwrap();
+    writeln!(out, "}}").unwrap();
+}
+
+/// Lets bitmask newtypes be combined with `|`.
+fn bit_ops(out: &mut String, name: &str) {
+    writeln!(
+        out,
+        "
+impl std::ops::BitOr for {name} {{
+    type Output = Self;
+
+    fn bitor(self, rhs: Self) -> Self {{
+        Self(self.0 | rhs.0)
+    }}
+}}
+
+impl std::ops::BitOrAssign for {name} {{
+    fn bitor_assign(&mut self, rhs: Self) {{
+        self.0 |= rhs.0;
+    }}
+}}"
+    )
+    .unwrap();
+}
+
+/// The Rust side of a registry command.
+struct Signature {
+    rust_name: String,
+    symbol: String,
+    params: Vec<(String, String)>,
+    ret: Option<String>,
+}
+
+impl Signature {
+    fn new(target: &Target, command: &allowlist::Command) -> Result<Self, Error> {
+        let registry = target.registry.command(&command.name)?;
+
+        for name in command.params.keys() {
+            if !registry.params.iter().any(|p| &p.name == name) {
+                return Err(Error::Allowlist(format!(
+                    "{} has no parameter named {name}",
+                    command.name
+                )));
+            }
+        }
+
+        let params = registry
+            .params
+            .iter()
+            .map(|param| {
+                let handle = target
+                    .allowlist
+                    .handles
+                    .iter()
+                    .find(|h| param.ty.base == "GLuint" && h.params.contains(&param.name))
+                    .map(|h| &h.name);
+                let ty = command.params.get(&param.name).or(handle);
+
+                (
+                    param_name(&param.name),
+                    rust_type(target, &param.ty, ty.map(String::as_str)),
+                )
+            })
+            .collect();
+
+        let ret = match &command.returns {
+            Some(ty) => Some(rust_type(target, &registry.ret, Some(ty))),
+            None if registry.ret.is_void() => None,
+            None => Some(rust_type(target, &registry.ret, None)),
+        };
+
+        let rust_name = command.rust_name.clone().unwrap_or_else(|| {
+            snake_case(command.name.strip_prefix("gl").unwrap_or(&command.name))
+        });
+
+        Ok(Self {
+            rust_name,
+            symbol: command.name.clone(),
+            params,
+            ret,
+        })
+    }
+
+    fn fn_type(&self) -> String {
+        let params = self
+            .params
+            .iter()
+            .map(|(name, ty)| format!("{name}: {ty}"))
+            .collect::<Vec<_>>()
+            .join(", ");
+        let ret = self
+            .ret
+            .as_ref()
+            .map_or_else(String::new, |ty| format!(" -> {ty}"));
+
+        format!("unsafe extern \"system\" fn({params}){ret}")
+    }
+}
+
+type Commands<'a> = [(&'a allowlist::Command, Signature)];
+
+/// Emits one line per command, separated into sections by a `style`d comment.
+fn sections(
+    out: &mut String,
+    commands: &Commands,
+    style: fn(&str) -> String,
+    f: &dyn Fn(&mut String, &Signature),
+) {
+    let mut section = None;
+    for (command, signature) in commands {
+        if section != Some(&command.section) {
+            if section.is_some() {
+                writeln!(out).unwrap();
+            }
+            writeln!(out, "{}", style(&command.section)).unwrap();
+            section = Some(&command.section);
+        }
+        f(out, signature);
+    }
+}
+
+type Fields = BTreeMap<String, String>;
+
+fn api(
+    out: &mut String,
+    target: &Target,
+    base: Option<&Module>,
+    fields: &Fields,
+    commands: &Commands,
+) {
+    let title = format!("{} {}", target.family.title, target.version);
+
+    writeln!(out).unwrap();
+    writeln!(out, "/// Bindings to a curated subset of {title}").unwrap();
+    if let Some(base) = base {
+        writeln!(out, "///").unwrap();
+        writeln!(
+            out,
+            "/// Everything up to {} {} is available through `Deref` to `{}::Api`.",
+            target.family.title, base.version, base.name
+        )
+        .unwrap();
+    }
+    writeln!(out, "#[allow(clippy::struct_field_names)]").unwrap();
+    writeln!(out, "pub struct Api {{").unwrap();
+    if let Some(base) = base {
+        writeln!(out, "    base: super::{}::Api,", base.name).unwrap();
+        writeln!(out).unwrap();
+    }
+    if !fields.is_empty() {
+        for (name, ty) in fields {
+            writeln!(out, "    {name}: {ty},").unwrap();
+        }
+        writeln!(out).unwrap();
+    }
+    sections(
+        out,
+        commands,
+        |s| format!("    //{s}"),
+        &|out, signature| {
+            writeln!(
+                out,
+                "    {}_ptr: Option<{}>,",
+                signature.rust_name,
+                signature.fn_type()
+            )
+            .unwrap();
+        },
+    );
+    writeln!(out, "}}").unwrap();
+
+    if let Some(base) = base {
+        writeln!(
+            out,
+            "
+impl std::ops::Deref for Api {{
+    type Target = super::{0}::Api;
+
+    fn deref(&self) -> &super::{0}::Api {{
+        &self.base
+    }}
+}}",
+            base.name
+        )
+        .unwrap();
+    }
+
+    writeln!(out).unwrap();
+    // wrappers mirror the C signatures one to one
+    writeln!(
+        out,
+        "#[allow(
+            clippy::let_and_return,
+            clippy::semicolon_if_nothing_returned,
+            clippy::similar_names,
+            clippy::too_many_arguments
+        )]"
+    )
+    .unwrap();
+    writeln!(out, "impl Api {{").unwrap();
+    loaders(out, target, base, fields, commands);
+    queries(out, base, commands);
+
+    let mut section = None;
+    for (command, signature) in commands {
+        if section != Some(&command.section) {
+            writeln!(out).unwrap();
+            writeln!(out, "    // {}", command.section.to_uppercase()).unwrap();
+            section = Some(&command.section);
+        }
+        wrapper(out, command, signature);
+    }
+    writeln!(out, "}}").unwrap();
+}
+
+fn loaders(
+    out: &mut String,
+    target: &Target,
+    base: Option<&Module>,
+    fields: &Fields,
+    commands: &Commands,
+) {
+    writeln!(
+        out,
+        "\
+    /// Loads all function pointers using a context function loader,
+    /// also called `ProcAddress`. Works with GLFW, SDL2 any pretty much any {} context.
+    ///
+    /// # Errors
+    /// This function will return an error if any function pointer returns a null pointer.
+    ///
+    /// # Safety
+    /// Unfortunately, some drivers return wrong addresses that are indistinguishable from correct
+    /// ones, instead of being null pointers.
+    ///
+    #[allow(clippy::too_many_lines)]
+    pub unsafe fn with_loader(
+        loader: &impl Fn(&str) -> *const std::ffi::c_void,
+    ) -> Result<Self, Error> {{
+        Ok(Self {{",
+        target.family.title
+    )
+    .unwrap();
+    if let Some(base) = base {
+        writeln!(
+            out,
+            "            base: unsafe {{ super::{}::Api::with_loader(loader)? }},",
+            base.name
+        )
+        .unwrap();
+        writeln!(out).unwrap();
+    }
+    default_fields(out, fields);
+    sections(
+        out,
+        commands,
+        |s| format!("            //{s}"),
+        &|out, signature| {
+            writeln!(
+                out,
+                "            {}_ptr: Some(loader.load(\"{}\")?),",
+                signature.rust_name, signature.symbol
+            )
+            .unwrap();
+        },
+    );
+    writeln!(out, "        }})").unwrap();
+    writeln!(out, "    }}").unwrap();
+
+    writeln!(out).unwrap();
+    writeln!(
+        out,
+        "\
+    /// Like `with_loader`, but keeps going when a function pointer is null, e.g. for entry points
+    /// a driver doesn't expose. Use `is_loaded` or `missing_functions` to find out what is
+    /// available, calling a wrapper whose function is missing panics.
+    ///
+    /// # Safety
+    /// See the safety note in `with_loader`
+    #[must_use]
+    #[allow(clippy::too_many_lines)]
+    pub unsafe fn with_loader_partial(loader: &impl Fn(&str) -> *const std::ffi::c_void) -> Self {{
+        Self {{"
+    )
+    .unwrap();
+    if let Some(base) = base {
+        writeln!(
+            out,
+            "            base: unsafe {{ super::{}::Api::with_loader_partial(loader) }},",
+            base.name
+        )
+        .unwrap();
+        writeln!(out).unwrap();
+    }
+    default_fields(out, fields);
+    sections(
+        out,
+        commands,
+        |s| format!("            //{s}"),
+        &|out, signature| {
+            writeln!(
+                out,
+                "            {}_ptr: loader.load(\"{}\").ok(),",
+                signature.rust_name, signature.symbol
+            )
+            .unwrap();
+        },
+    );
+    writeln!(out, "        }}").unwrap();
+    writeln!(out, "    }}").unwrap();
+}
+
+/// Initializes the hand-written `fields` of a module in its loaders.
+fn default_fields(out: &mut String, fields: &Fields) {
+    if !fields.is_empty() {
+        for (name, ty) in fields {
+            writeln!(out, "            {name}: {ty}::default(),").unwrap();
+        }
+        writeln!(out).unwrap();
+    }
+}
+
+/// `is_loaded` and `missing_functions`, which also cover the commands of the base module.
+fn queries(out: &mut String, base: Option<&Module>, commands: &Commands) {
+    let own_missing = "self.symbols().filter_map(|(symbol, loaded)| (!loaded).then_some(symbol))";
+    let (base_loaded, missing) = if base.is_some() {
+        (
+            " || self.base.is_loaded(symbol)",
+            format!("self.base.missing_functions().chain({own_missing})"),
+        )
+    } else {
+        ("", own_missing.to_string())
+    };
+
+    writeln!(
+        out,
+        "
+    /// Returns true if `symbol`, e.g. `\"glDebugMessageCallback\"`, is part of this `Api` and was
+    /// found by the loader.
+    #[must_use]
+    pub fn is_loaded(&self, symbol: &str) -> bool {{
+        self.symbols().any(|(s, loaded)| s == symbol && loaded){base_loaded}
+    }}
+
+    /// Symbols the loader returned a null pointer for, always empty after `with_loader`.
+    pub fn missing_functions(&self) -> impl Iterator<Item = &'static str> {{
+        {missing}
+    }}
+
+    #[allow(clippy::too_many_lines)]
+    fn symbols(&self) -> impl Iterator<Item = (&'static str, bool)> {{
+        ["
+    )
+    .unwrap();
+    for (_, signature) in commands {
+        writeln!(
+            out,
+            "            (\"{}\", self.{}_ptr.is_some()),",
+            signature.symbol, signature.rust_name
+        )
+        .unwrap();
+    }
+    writeln!(out, "        ]").unwrap();
+    writeln!(out, "        .into_iter()").unwrap();
+    writeln!(out, "    }}").unwrap();
+}
+
+fn wrapper(out: &mut String, command: &allowlist::Command, signature: &Signature) {
+    writeln!(out).unwrap();
+    if let Some(doc) = &command.doc {
+        for line in doc.trim().lines() {
+            writeln!(out, "    /// {line}").unwrap();
+        }
+        writeln!(out, "    ///").unwrap();
+    }
+    writeln!(out, "    /// # Safety").unwrap();
+    writeln!(out, "    /// See the safety note in `with_loader`").unwrap();
+    writeln!(out, "    #[inline]").unwrap();
+    let traced = signature.symbol != "glGetError";
+    if traced {
+        writeln!(out, "    #[cfg_attr(feature = \"trace\", track_caller)]").unwrap();
+    }
+    if signature.ret.is_some() {
+        writeln!(out, "    #[must_use]").unwrap();
+    }
+
+    let params = signature
+        .params
+        .iter()
+        .fold(String::new(), |params, (name, ty)| {
+            params + &format!(", {name}: {ty}")
+        });
+    let args = signature
+        .params
+        .iter()
+        .map(|(name, _)| name.as_str())
+        .collect::<Vec<_>>()
+        .join(", ");
+    let ret = signature
+        .ret
+        .as_ref()
+        .map_or_else(String::new, |ty| format!(" -> {ty}"));
+
+    writeln!(
+        out,
+        "    pub unsafe fn {}(&self{params}){ret} {{",
+        signature.rust_name
+    )
+    .unwrap();
+    // not `f`, which `glDepthRange` uses as a parameter name
+    writeln!(
+        out,
+        "        let function = self.{}_ptr.unwrap_or_else(|| not_loaded(\"{}\"));",
+        signature.rust_name, signature.symbol
+    )
+    .unwrap();
+    if !traced {
+        writeln!(out, "        unsafe {{ function({args}) }}").unwrap();
+        writeln!(out, "    }}").unwrap();
+        return;
+    }
+
+    let logged = signature
+        .params
+        .iter()
+        .map(|(name, _)| format!("{name}: {{{name}:?}}"))
+        .collect::<Vec<_>>()
+        .join(", ");
+    writeln!(out, "        #[cfg(feature = \"trace\")]").unwrap();
+    writeln!(
+        out,
+        "        log::trace!(\"{}({logged})\");",
+        signature.symbol
+    )
+    .unwrap();
+    if signature.ret.is_some() {
+        writeln!(out, "        let result = unsafe {{ function({args}) }};").unwrap();
+    } else {
+        writeln!(out, "        unsafe {{ function({args}) }};").unwrap();
+    }
+    writeln!(out, "        #[cfg(feature = \"trace\")]").unwrap();
+    writeln!(
+        out,
+        "        crate::trace::check(\"{}\", || unsafe {{ self.get_error() }}.error());",
+        signature.symbol
+    )
+    .unwrap();
+    if signature.ret.is_some() {
+        writeln!(out, "        result").unwrap();
+    }
+    writeln!(out, "    }}").unwrap();
+}
+
+/// Normalizes registry values like `0x00004000` to `0x4000`, and keeps longer ones readable.
+fn literal(value: &str) -> String {
+    match value
+        .strip_prefix("0x")
+        .and_then(|hex| u32::from_str_radix(hex, 16).ok())
+    {
+        Some(v) if v > 0xFFFF => format!("0x{:04X}_{:04X}", v >> 16, v & 0xFFFF),
+        Some(v) => format!("0x{v:04X}"),
+        None => value.to_string(),
+    }
+}
+
+/// Maps a registry type to Rust. `replacement` swaps out the pointee, unless it is a complete
+/// type on its own, e.g. `*const *const GLchar`.
+fn rust_type(target: &Target, ty: &CType, replacement: Option<&str>) -> String {
+    if let Some(replacement) = replacement.filter(|r| r.contains(['*', '<'])) {
+        return replacement.to_string();
+    }
+
+    let mut rust = replacement.map_or_else(
+        || match (ty.base.as_str(), target.allowlist.types.get(&ty.base)) {
+            (_, Some(mapped)) => mapped.clone(),
+            ("void", None) => "std::ffi::c_void".to_string(),
+            ("GLbitfield", None) => "GLbitField".to_string(),
+            (base, None) => base.to_string(),
+        },
+        str::to_string,
+    );
+
+    let mut pointee_const = ty.base_const;
+    for &pointer_const in &ty.pointers {
+        rust = format!("*{} {rust}", if pointee_const { "const" } else { "mut" });
+        pointee_const = pointer_const;
+    }
+
+    rust
+}
+
+fn param_name(name: &str) -> String {
+    match snake_case(name).as_str() {
+        "type" => "kind".to_string(),
+        "ref" => "reference".to_string(),
+        other => other.to_string(),
+    }
+}
+
+/// `TexImage2D` to `tex_image_2d`, `UniformMatrix2x3fv` to `uniform_matrix_2x3fv`,
+/// `VertexAttribIPointer` to `vertex_attrib_i_pointer`.
+fn snake_case(name: &str) -> String {
+    let chars: Vec<char> = name.chars().collect();
+    let mut out = String::new();
+
+    for (i, &c) in chars.iter().enumerate() {
+        let prev = i.checked_sub(1).map(|i| chars[i]);
+        let next = chars.get(i + 1).copied();
+
+        let split = match prev {
+            None | Some('_') => false,
+            Some(p) if c.is_ascii_uppercase() => {
+                p.is_ascii_lowercase()
+                    || (p.is_ascii_uppercase() && next.is_some_and(|n| n.is_ascii_lowercase()))
+            }
+            Some(p) if c.is_ascii_digit() => {
+                let matrix = p == 'x' && i >= 2 && chars[i - 2].is_ascii_digit();
+                // parameters like `v0` stay whole, unlike `Uniform4f`
+                let single_letter = i == 1;
+                p.is_ascii_alphabetic() && !matrix && !single_letter
+            }
+            Some(_) => false,
+        };
+
+        if split {
+            out.push('_');
+        }
+        out.push(c.to_ascii_lowercase());
+    }
+
+    out
+}
+
+/// Runs the output through rustfmt so the checked in file doesn't depend on the emitter's
+/// whitespace.
+fn format(source: &str) -> Result<String, Error> {
+    let mut rustfmt = Command::new("rustfmt")
+        .args(["--edition", "2021", "--emit", "stdout"])
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .map_err(Error::Rustfmt)?;
+
+    rustfmt
+        .stdin
+        .take()
+        .expect("stdin is piped")
+        .write_all(source.as_bytes())
+        .map_err(|e| Error::Io("rustfmt".into(), e))?;
+
+    let output = rustfmt
+        .wait_with_output()
+        .map_err(|e| Error::Io("rustfmt".into(), e))?;
+
+    if output.status.success() {
+        String::from_utf8(output.stdout).map_err(|e| Error::Registry(e.to_string()))
+    } else {
+        Err(Error::Registry(format!(
+            "generated code does not parse: {}",
+            String::from_utf8_lossy(&output.stderr)
+        )))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const XML: &str = r#"<registry>
+        <commands>
+            <command>
+                <proto>void <name>glFlush</name></proto>
+            </command>
+        </commands>
+        <feature api="gl" number="3.3">
+            <require><command name="glFlush"/></require>
+        </feature>
+    </registry>"#;
+
+    /// The `gl33` module of the core profile, allowing the single command `command`.
+    fn allowlist(command: &str) -> Allowlist {
+        let toml = format!(
+            r#"
+            registry = "gl.xml"
+            stubs = "stubs.rs"
+
+            [[family]]
+            name = "gl"
+            title = "OpenGL"
+            api = "gl"
+            profile = "core"
+            baseline = "3.3"
+            output = "gl.rs"
+
+            [[module]]
+            name = "gl33"
+            family = "gl"
+            version = "3.3"
+            output = "gl33.rs"
+
+            [[command]]
+            name = "{command}"
+            section = "sync"
+            "#
+        );
+        toml::from_str(&toml).unwrap()
+    }
+
+    #[test]
+    fn validate_accepts_available_commands() {
+        let registry = Registry::parse(XML).unwrap();
+        let allowlist = allowlist("glFlush");
+        assert!(validate(&registry, &allowlist).is_ok());
+    }
+
+    #[test]
+    fn validate_rejects_unknown_entries() {
+        let registry = Registry::parse(XML).unwrap();
+        let allowlist = allowlist("glFlushh");
+        let Err(Error::Allowlist(message)) = validate(&registry, &allowlist) else {
+            panic!("glFlushh was accepted");
+        };
+        assert!(message.contains("glFlushh"), "{message}");
+    }
+
+    #[test]
+    fn snake_case_splits_words_and_numbers() {
+        for (name, expected) in [
+            ("v0", "v0"),
+            ("uniformMatrix4fv", "uniform_matrix_4fv"),
+            ("UniformMatrix2x3fv", "uniform_matrix_2x3fv"),
+            (
+                "drawElementsInstancedBaseVertex",
+                "draw_elements_instanced_base_vertex",
+            ),
+            ("texImage2D", "tex_image_2d"),
+            ("VertexAttribIPointer", "vertex_attrib_i_pointer"),
+        ] {
+            assert_eq!(snake_case(name), expected, "{name}");
+        }
+    }
+}
@@ -0,0 +1,97 @@
+//! Generates the raw `cac_gl_bindings` modules from the Khronos registry.
+//!
+//! The registry is vendored in `registry/gl.xml` and only the parts listed in `allowlist.toml`
+//! are emitted, so the bindings stay a curated subset instead of the whole API.
+//!
+//! Run `cargo run -p cac_gl_generator` after editing the allow-list, or
+//! `cargo run -p cac_gl_generator -- --check` to verify the checked in files are up to date.
+
+mod allowlist;
+mod emit;
+mod registry;
+
+use std::fmt::Display;
+use std::path::{Path, PathBuf};
+
+use allowlist::Allowlist;
+use registry::Registry;
+
+#[derive(Debug)]
+pub enum Error {
+    Io(PathBuf, std::io::Error),
+    Allowlist(String),
+    Registry(String),
+    OutOfDate(PathBuf),
+    /// rustfmt couldn't be started, the generated files are only comparable once formatted.
+    Rustfmt(std::io::Error),
+}
+
+impl std::error::Error for Error {}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Io(path, e) => write!(f, "{}: {e}", path.display()),
+            Self::Allowlist(s) => write!(f, "invalid allow-list: {s}"),
+            Self::Registry(s) => write!(f, "registry: {s}"),
+            Self::Rustfmt(e) => write!(
+                f,
+                "failed to run rustfmt, install it with `rustup component add rustfmt`: {e}"
+            ),
+            Self::OutOfDate(path) => write!(
+                f,
+                "{} is out of date, run `cargo run -p cac_gl_generator`",
+                path.display()
+            ),
+        }
+    }
+}
+
+fn read(path: &Path) -> Result<String, Error> {
+    std::fs::read_to_string(path).map_err(|e| Error::Io(path.to_path_buf(), e))
+}
+
+fn main() -> Result<(), Error> {
+    let check = std::env::args().any(|arg| arg == "--check");
+
+    let crate_dir = Path::new(env!("CARGO_MANIFEST_DIR"));
+    let workspace_dir = crate_dir
+        .parent()
+        .and_then(Path::parent)
+        .expect("generator lives in <workspace>/crates/cac_gl_generator");
+
+    let allowlist: Allowlist = toml::from_str(&read(&crate_dir.join("allowlist.toml"))?)
+        .map_err(|e| Error::Allowlist(e.to_string()))?;
+    let registry = Registry::parse(&read(&crate_dir.join(&allowlist.registry))?)?;
+    emit::validate(&registry, &allowlist)?;
+
+    let families = allowlist.families.iter().map(|family| {
+        let source = emit::family(&registry, &allowlist, family);
+        (family.name.as_str(), family.output.as_str(), source)
+    });
+    let modules = allowlist.modules.iter().map(|module| {
+        let source = emit::module(&registry, &allowlist, module);
+        (module.name.as_str(), module.output.as_str(), source)
+    });
+
+    let stubs = std::iter::once_with(|| {
+        let source = emit::stubs(&registry, &allowlist);
+        ("stubs", allowlist.stubs.as_str(), source)
+    });
+
+    for (name, output, source) in families.chain(modules).chain(stubs) {
+        let source = source?;
+        let path = workspace_dir.join(output);
+
+        if check {
+            if read(&path)? != source {
+                return Err(Error::OutOfDate(path));
+            }
+        } else {
+            std::fs::write(&path, source).map_err(|e| Error::Io(path.clone(), e))?;
+            println!("generated {name} in {}", path.display());
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,328 @@
+use std::collections::HashMap;
+use std::fmt::Display;
+
+use crate::Error;
+
+/// A `major.minor` API version as used by the `number` attribute of `<feature>`.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Ord, PartialOrd)]
+pub struct Version(pub u8, pub u8);
+
+impl Version {
+    pub fn parse(s: &str) -> Result<Self, Error> {
+        let invalid = || Error::Allowlist(format!("invalid version {s:?}"));
+        let (major, minor) = s.split_once('.').ok_or_else(invalid)?;
+        Ok(Self(
+            major.parse().map_err(|_| invalid())?,
+            minor.parse().map_err(|_| invalid())?,
+        ))
+    }
+}
+
+impl Display for Version {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}.{}", self.0, self.1)
+    }
+}
+
+/// A C type as spelled in the registry, e.g. `const GLchar *const*`.
+#[derive(Clone, Debug)]
+pub struct CType {
+    pub base: String,
+    pub base_const: bool,
+    /// One entry per level of indirection, innermost first. `true` if that pointer itself is const.
+    pub pointers: Vec<bool>,
+}
+
+impl CType {
+    fn parse(spelling: &str) -> Result<Self, Error> {
+        let spelling = spelling.replace('*', " * ");
+        let mut base = None;
+        let mut base_const = false;
+        let mut pointers: Vec<bool> = Vec::new();
+
+        for token in spelling.split_whitespace() {
+            match token {
+                "const" => match pointers.last_mut() {
+                    Some(pointer) => *pointer = true,
+                    None => base_const = true,
+                },
+                "*" => pointers.push(false),
+                // arrays in parameter lists decay to pointers
+                array if array.starts_with('[') => pointers.push(false),
+                "struct" => {}
+                ident if base.is_none() => base = Some(ident.to_string()),
+                _ => return Err(Error::Registry(format!("unsupported C type {spelling:?}"))),
+            }
+        }
+
+        Ok(Self {
+            base: base.ok_or_else(|| Error::Registry(format!("missing type in {spelling:?}")))?,
+            base_const,
+            pointers,
+        })
+    }
+
+    pub fn is_void(&self) -> bool {
+        self.base == "void" && self.pointers.is_empty()
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct Param {
+    pub name: String,
+    pub ty: CType,
+}
+
+#[derive(Clone, Debug)]
+pub struct Command {
+    pub name: String,
+    pub ret: CType,
+    pub params: Vec<Param>,
+}
+
+#[derive(Debug)]
+struct Feature {
+    api: String,
+    number: Version,
+    required: Vec<(Option<String>, String)>,
+    removed: Vec<(Option<String>, String)>,
+}
+
+/// The parts of gl.xml the generator cares about: enum values, command signatures and the
+/// `<feature>` blocks that tell which version of which API introduced them.
+pub struct Registry {
+    enums: HashMap<String, Vec<(Option<String>, String)>>,
+    commands: HashMap<String, Command>,
+    features: Vec<Feature>,
+}
+
+impl Registry {
+    pub fn parse(xml: &str) -> Result<Self, Error> {
+        let document =
+            roxmltree::Document::parse(xml).map_err(|e| Error::Registry(e.to_string()))?;
+
+        let mut registry = Self {
+            enums: HashMap::new(),
+            commands: HashMap::new(),
+            features: Vec::new(),
+        };
+
+        for section in document
+            .root_element()
+            .children()
+            .filter(roxmltree::Node::is_element)
+        {
+            match section.tag_name().name() {
+                "enums" => {
+                    for e in section.children().filter(|n| n.has_tag_name("enum")) {
+                        let (Some(name), Some(value)) = (e.attribute("name"), e.attribute("value"))
+                        else {
+                            continue;
+                        };
+                        registry
+                            .enums
+                            .entry(name.to_string())
+                            .or_default()
+                            .push((e.attribute("api").map(str::to_string), value.to_string()));
+                    }
+                }
+                "commands" => {
+                    for c in section.children().filter(|n| n.has_tag_name("command")) {
+                        let command = parse_command(c)?;
+                        registry.commands.insert(command.name.clone(), command);
+                    }
+                }
+                "feature" => registry.features.push(parse_feature(section)?),
+                _ => {}
+            }
+        }
+
+        Ok(registry)
+    }
+
+    pub fn command(&self, name: &str) -> Result<&Command, Error> {
+        self.commands
+            .get(name)
+            .ok_or_else(|| Error::Registry(format!("unknown command {name}")))
+    }
+
+    pub fn enum_value(&self, name: &str, api: &str) -> Result<&str, Error> {
+        let values = self
+            .enums
+            .get(name)
+            .ok_or_else(|| Error::Registry(format!("unknown enum {name}")))?;
+
+        values
+            .iter()
+            .find(|(a, _)| a.as_deref() == Some(api))
+            .or_else(|| values.iter().find(|(a, _)| a.is_none()))
+            .map(|(_, value)| value.as_str())
+            .ok_or_else(|| Error::Registry(format!("enum {name} has no value for {api}")))
+    }
+
+    /// The version of `api` that made `name` available in `profile`, or `None` if it is not part
+    /// of that API at `version` (never required, or removed and not required again since).
+    pub fn introduced_in(
+        &self,
+        api: &str,
+        profile: &str,
+        version: Version,
+        name: &str,
+    ) -> Option<Version> {
+        let applies = |p: &Option<String>| p.as_deref().is_none_or(|p| p == profile);
+        let mut introduced = None;
+
+        for feature in self
+            .features
+            .iter()
+            .filter(|f| f.api == api && f.number <= version)
+        {
+            if introduced.is_none()
+                && feature
+                    .required
+                    .iter()
+                    .any(|(p, n)| n == name && applies(p))
+            {
+                introduced = Some(feature.number);
+            }
+            // removed names can come back, like `GL_VERTEX_ARRAY` for KHR_debug in 4.3
+            if feature.removed.iter().any(|(p, n)| n == name && applies(p)) {
+                introduced = None;
+            }
+        }
+
+        introduced
+    }
+}
+
+fn parse_command(node: roxmltree::Node) -> Result<Command, Error> {
+    let mut name = None;
+    let mut ret = None;
+    let mut params = Vec::new();
+
+    for child in node.children().filter(roxmltree::Node::is_element) {
+        match child.tag_name().name() {
+            "proto" => {
+                let (n, ty) = parse_declaration(child)?;
+                name = Some(n);
+                ret = Some(ty);
+            }
+            "param" => {
+                let (name, ty) = parse_declaration(child)?;
+                params.push(Param { name, ty });
+            }
+            _ => {}
+        }
+    }
+
+    Ok(Command {
+        name: name.ok_or_else(|| Error::Registry("command without a name".to_string()))?,
+        ret: ret.ok_or_else(|| Error::Registry("command without a proto".to_string()))?,
+        params,
+    })
+}
+
+/// Splits `<param>const <ptype>GLuint</ptype> *<name>buffers</name></param>` into the name and
+/// the type spelled around it.
+fn parse_declaration(node: roxmltree::Node) -> Result<(String, CType), Error> {
+    let mut spelling = String::new();
+    let mut name = None;
+
+    for child in node.children() {
+        if child.has_tag_name("name") {
+            name = child.text().map(str::to_string);
+        } else if child.is_text() || child.has_tag_name("ptype") {
+            spelling.push_str(child.text().unwrap_or_default());
+            spelling.push(' ');
+        }
+    }
+
+    let name = name.ok_or_else(|| Error::Registry(format!("unnamed declaration {spelling:?}")))?;
+    Ok((name, CType::parse(&spelling)?))
+}
+
+fn parse_feature(node: roxmltree::Node) -> Result<Feature, Error> {
+    let attribute = |name| {
+        node.attribute(name)
+            .ok_or_else(|| Error::Registry(format!("feature without {name}")))
+    };
+
+    let mut feature = Feature {
+        api: attribute("api")?.to_string(),
+        number: Version::parse(attribute("number")?)?,
+        required: Vec::new(),
+        removed: Vec::new(),
+    };
+
+    for block in node.children().filter(roxmltree::Node::is_element) {
+        let profile = block.attribute("profile").map(str::to_string);
+        let names = block
+            .children()
+            .filter_map(|n| n.attribute("name"))
+            .map(|name| (profile.clone(), name.to_string()));
+
+        match block.tag_name().name() {
+            "require" => feature.required.extend(names),
+            "remove" => feature.removed.extend(names),
+            _ => {}
+        }
+    }
+
+    Ok(feature)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const XML: &str = r#"<registry>
+        <feature api="gl" number="1.0">
+            <require><enum name="GL_VERTEX_ARRAY"/></require>
+        </feature>
+        <feature api="gl" number="3.2">
+            <remove profile="core"><enum name="GL_VERTEX_ARRAY"/></remove>
+        </feature>
+        <feature api="gl" number="4.3">
+            <require><enum name="GL_VERTEX_ARRAY"/></require>
+        </feature>
+        <feature api="gles2" number="2.0">
+            <require><enum name="GL_VERTEX_ARRAY"/></require>
+        </feature>
+    </registry>"#;
+
+    #[test]
+    fn removed_names_are_unavailable_until_required_again() {
+        let registry = Registry::parse(XML).unwrap();
+        let introduced =
+            |profile, version| registry.introduced_in("gl", profile, version, "GL_VERTEX_ARRAY");
+
+        assert_eq!(introduced("core", Version(3, 1)), Some(Version(1, 0)));
+        assert_eq!(introduced("core", Version(3, 3)), None);
+        assert_eq!(introduced("core", Version(4, 3)), Some(Version(4, 3)));
+        assert_eq!(
+            introduced("compatibility", Version(3, 3)),
+            Some(Version(1, 0))
+        );
+        assert_eq!(
+            registry.introduced_in("gles2", "common", Version(3, 0), "GL_VERTEX_ARRAY"),
+            Some(Version(2, 0))
+        );
+    }
+
+    #[test]
+    fn c_types_are_parsed_innermost_pointer_first() {
+        let ty = CType::parse("const GLchar *const*").unwrap();
+        assert_eq!(ty.base, "GLchar");
+        assert!(ty.base_const);
+        assert_eq!(ty.pointers, [true, false]);
+
+        let ty = CType::parse("struct _cl_event *").unwrap();
+        assert_eq!(ty.base, "_cl_event");
+        assert_eq!(ty.pointers, [false]);
+
+        assert_eq!(CType::parse("GLfloat [4]").unwrap().pointers, [false]);
+        assert!(CType::parse("void ").unwrap().is_void());
+        assert!(CType::parse("unsigned int").is_err());
+        assert!(CType::parse("const *").is_err());
+    }
+}